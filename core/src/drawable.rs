@@ -1,5 +1,10 @@
 //! `Drawable` trait and helpers
-use crate::{draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor};
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
 
 /// Marks an object as "drawable". Must be implemented for all graphics objects
 ///
@@ -62,6 +67,46 @@ use crate::{draw_target::DrawTarget, geometry::Point, pixelcolor::PixelColor};
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
 ///
+/// # Composite drawables
+///
+/// `Drawable` is also implemented for tuples with up to 8 elements, arrays, slices and `Option`,
+/// which allows multiple objects to be drawn as a single drawable. The elements are drawn in
+/// order. Tuples return a tuple of the individual outputs, `Option` returns `None` if it
+/// contains no drawable, and arrays and slices return `()`.
+///
+/// Tuples, arrays and slices also implement [`Dimensions`] if their elements implement it. The
+/// returned bounding box is the envelope of the bounding boxes of all elements.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle, Rectangle},
+///     text::Text,
+/// };
+///
+/// let badge = (
+///     Circle::new(Point::new(2, 2), 16).into_styled(PrimitiveStyle::with_fill(Rgb565::RED)),
+///     Text::new("Alert", Point::new(22, 13), MonoTextStyle::new(&FONT_6X9, Rgb565::WHITE)),
+/// );
+///
+/// let bars = [10, 20, 15].map(|height| {
+///     Rectangle::new(Point::new(0, 40 - height), Size::new(4, height as u32))
+///         .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+/// });
+///
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+/// let next_point = badge.draw(&mut display)?.1;
+/// bars.draw(&mut display)?;
+///
+/// assert_eq!(next_point, Point::new(52, 13));
+/// assert_eq!(bars.bounding_box(), Rectangle::new(Point::new(0, 20), Size::new(4, 20)));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
 /// [`DrawTarget`]: crate::draw_target::DrawTarget
 /// [`draw_iter`]: crate::draw_target::DrawTarget::draw_iter
 pub trait Drawable {
@@ -158,11 +203,137 @@ where
     }
 }
 
+/// Returns the envelope of two bounding boxes, ignoring zero sized boxes.
+fn envelope_non_empty(a: Rectangle, b: Rectangle) -> Rectangle {
+    if a.is_zero_sized() {
+        b
+    } else if b.is_zero_sized() {
+        a
+    } else {
+        a.envelope(&b)
+    }
+}
+
+macro_rules! impl_tuple {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first, $($rest),*> Drawable for ($first, $($rest,)*)
+        where
+            $first: Drawable,
+            $($rest: Drawable<Color = $first::Color>,)*
+        {
+            type Color = $first::Color;
+            type Output = ($first::Output, $($rest::Output,)*);
+
+            fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+            where
+                D: DrawTarget<Color = Self::Color>,
+            {
+                #[allow(non_snake_case)]
+                let ($first, $($rest,)*) = self;
+
+                Ok(($first.draw(target)?, $($rest.draw(target)?,)*))
+            }
+        }
+
+        impl<$first, $($rest),*> Dimensions for ($first, $($rest,)*)
+        where
+            $first: Dimensions,
+            $($rest: Dimensions,)*
+        {
+            fn bounding_box(&self) -> Rectangle {
+                #[allow(non_snake_case)]
+                let ($first, $($rest,)*) = self;
+
+                let bounding_box = $first.bounding_box();
+                $(let bounding_box = envelope_non_empty(bounding_box, $rest.bounding_box());)*
+
+                bounding_box
+            }
+        }
+    };
+}
+
+impl_tuple!(T1, T2);
+impl_tuple!(T1, T2, T3);
+impl_tuple!(T1, T2, T3, T4);
+impl_tuple!(T1, T2, T3, T4, T5);
+impl_tuple!(T1, T2, T3, T4, T5, T6);
+impl_tuple!(T1, T2, T3, T4, T5, T6, T7);
+impl_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
+
+impl<T: Drawable> Drawable for [T] {
+    type Color = T::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        for item in self {
+            item.draw(target)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Dimensions> Dimensions for [T] {
+    /// Returns the envelope of the bounding boxes of all elements.
+    ///
+    /// Zero sized bounding boxes are ignored. A zero sized rectangle at the origin is returned
+    /// for empty slices.
+    fn bounding_box(&self) -> Rectangle {
+        self.iter()
+            .map(Dimensions::bounding_box)
+            .filter(|bounding_box| !bounding_box.is_zero_sized())
+            .reduce(|a, b| a.envelope(&b))
+            .unwrap_or_else(Rectangle::zero)
+    }
+}
+
+impl<T: Drawable, const N: usize> Drawable for [T; N] {
+    type Color = T::Color;
+    type Output = ();
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.as_slice().draw(target)
+    }
+}
+
+impl<T: Dimensions, const N: usize> Dimensions for [T; N] {
+    /// Returns the envelope of the bounding boxes of all elements.
+    ///
+    /// Zero sized bounding boxes are ignored. A zero sized rectangle at the origin is returned
+    /// for empty arrays.
+    fn bounding_box(&self) -> Rectangle {
+        self.as_slice().bounding_box()
+    }
+}
+
+impl<T: Drawable> Drawable for Option<T> {
+    type Color = T::Color;
+    type Output = Option<T::Output>;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.as_ref().map(|item| item.draw(target)).transpose()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // NOTE: `crate` cannot be used here due to circular dependency resolution behavior.
     use embedded_graphics::{
-        geometry::Point, mock_display::MockDisplay, pixelcolor::BinaryColor, Drawable, Pixel,
+        geometry::{Dimensions, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Circle, Primitive, PrimitiveStyle, Rectangle},
+        Drawable, Pixel,
     };
 
     #[test]
@@ -184,4 +355,121 @@ mod tests {
             " # ", //
         ]);
     }
+
+    #[test]
+    fn draw_tuple() {
+        let mut display = MockDisplay::new();
+        let output = (
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Rectangle::new(Point::new(1, 1), Size::new(2, 1))
+                .into_styled(PrimitiveStyle::with_fill(BinaryColor::Off)),
+            Pixel(Point::new(3, 2), BinaryColor::On),
+        )
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(output, ((), (), ()));
+        display.assert_pattern(&[
+            "#   ", //
+            " .. ", //
+            "   #", //
+        ]);
+    }
+
+    #[test]
+    fn draw_array_and_slice() {
+        let pixels = [
+            Pixel(Point::new(0, 0), BinaryColor::On),
+            Pixel(Point::new(1, 1), BinaryColor::Off),
+            Pixel(Point::new(2, 0), BinaryColor::On),
+        ];
+
+        let mut display = MockDisplay::new();
+        pixels.draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "# #", //
+            " . ", //
+        ]);
+
+        let mut display = MockDisplay::new();
+        pixels[1..].draw(&mut display).unwrap();
+        display.assert_pattern(&[
+            "  #", //
+            " . ", //
+        ]);
+    }
+
+    #[test]
+    fn draw_option() {
+        let mut display = MockDisplay::new();
+
+        assert_eq!(
+            Some(Pixel(Point::new(1, 0), BinaryColor::On))
+                .draw(&mut display)
+                .unwrap(),
+            Some(())
+        );
+        assert_eq!(None::<Pixel<BinaryColor>>.draw(&mut display).unwrap(), None);
+
+        display.assert_pattern(&[
+            " #", //
+        ]);
+    }
+
+    #[test]
+    fn bounding_box_envelope() {
+        let a = Rectangle::new(Point::new(2, 3), Size::new(4, 5));
+        let b = Rectangle::new(Point::new(10, 1), Size::new(2, 2));
+        let c = Rectangle::new(Point::new(-1, 4), Size::new(1, 1));
+        let expected = Rectangle::with_corners(Point::new(-1, 1), Point::new(11, 7));
+
+        assert_eq!((a, b, c).bounding_box(), expected);
+        assert_eq!([a, b, c].bounding_box(), expected);
+        assert_eq!([a, b, c][..1].bounding_box(), a);
+        assert_eq!(
+            <[Rectangle; 0]>::default().bounding_box(),
+            Rectangle::zero()
+        );
+    }
+
+    #[test]
+    fn bounding_box_ignores_empty_elements() {
+        let circle = Circle::new(Point::new(10, 10), 5);
+        let expected = Rectangle::new(Point::new(10, 10), Size::new(5, 5));
+
+        assert_eq!(
+            (circle, <[Rectangle; 0]>::default()).bounding_box(),
+            expected
+        );
+        assert_eq!(
+            (<[Rectangle; 0]>::default(), circle).bounding_box(),
+            expected
+        );
+        assert_eq!(
+            (circle, Rectangle::new(Point::new(-5, -5), Size::zero())).bounding_box(),
+            expected
+        );
+        assert_eq!([Rectangle::zero(); 2].bounding_box(), Rectangle::zero());
+        assert!((Rectangle::zero(), Rectangle::zero())
+            .bounding_box()
+            .is_zero_sized());
+    }
+
+    #[test]
+    fn bounding_box_ignores_nested_empty_elements() {
+        let a = Rectangle::new(Point::new(2, 3), Size::new(4, 5));
+        let b = Rectangle::new(Point::new(10, 1), Size::new(2, 2));
+        let empty = <[Rectangle; 0]>::default();
+
+        assert_eq!(
+            [[a, b], [Rectangle::zero(); 2]].bounding_box(),
+            a.envelope(&b)
+        );
+        assert_eq!([(a, empty), (b, empty)].bounding_box(), a.envelope(&b));
+        assert_eq!(
+            ((empty, [empty; 3]), a, [[Rectangle::zero()]]).bounding_box(),
+            a
+        );
+        assert_eq!([[empty; 2]; 2].bounding_box(), Rectangle::zero());
+    }
 }
@@ -540,7 +540,7 @@ mod tests {
 
     #[test]
     fn convert_i32_max_to_u32_tuple() {
-        let p = Point::new(i32::max_value(), i32::max_value());
+        let p = Point::new(i32::MAX, i32::MAX);
 
         let tuple: (u32, u32) = p.try_into().unwrap();
         let array: [u32; 2] = p.try_into().unwrap();
@@ -563,7 +563,7 @@ mod tests {
 
     #[test]
     fn convert_i32_min_to_u32_tuple() {
-        let p = Point::new(i32::min_value(), i32::min_value());
+        let p = Point::new(i32::MIN, i32::MIN);
 
         let tuple: Result<(u32, u32), _> = p.try_into();
         let array: Result<[u32; 2], _> = p.try_into();
@@ -654,7 +654,7 @@ mod tests {
     #[cfg(debug_assertions)]
     fn too_large_width_can_not_be_added() {
         let p = Point::zero();
-        let _ = p + Size::new(u32::max_value(), 0);
+        let _ = p + Size::new(u32::MAX, 0);
     }
 
     #[test]
@@ -662,7 +662,7 @@ mod tests {
     #[cfg(debug_assertions)]
     fn too_large_width_can_not_be_add_assigned() {
         let mut p = Point::zero();
-        p += Size::new(u32::max_value(), 0);
+        p += Size::new(u32::MAX, 0);
     }
 
     #[test]
@@ -686,7 +686,7 @@ mod tests {
     #[cfg(debug_assertions)]
     fn too_large_width_can_not_be_subtracted() {
         let p = Point::zero();
-        let _ = p - Size::new(u32::max_value(), 0);
+        let _ = p - Size::new(u32::MAX, 0);
     }
 
     #[test]
@@ -694,7 +694,7 @@ mod tests {
     #[cfg(debug_assertions)]
     fn too_large_width_can_not_be_sub_assigned() {
         let mut p = Point::zero();
-        p -= Size::new(u32::max_value(), 0);
+        p -= Size::new(u32::MAX, 0);
     }
 
     #[test]
@@ -0,0 +1,251 @@
+//! Color arithmetic.
+
+use crate::pixelcolor::{gray_color::*, rgb_color::*, PixelColor};
+
+/// Color arithmetic.
+///
+/// This trait provides basic arithmetic operations, which can be used to darken, lighten, invert
+/// or mix colors. It is implemented for all RGB and grayscale color types.
+///
+/// All operations are applied to each color channel separately and use the native channel values
+/// of the color type, without converting the color into another type first. Fractions are
+/// specified as 8 bit values, with `255` representing 100%, and the results are rounded to the
+/// nearest channel value. This makes the results independent of the platform and ensures that
+/// `0` and `255` map exactly to the original colors.
+///
+/// The operations work on the gamma encoded channel values. The [`lerp`] method of the RGB and
+/// grayscale color types can be used instead of [`mix`] if a gamma correct interpolation is
+/// required.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::{ColorMath, Rgb565},
+///     prelude::*,
+/// };
+///
+/// let color = Rgb565::new(30, 40, 10);
+///
+/// // Darken the color to 50% brightness for a pressed button.
+/// assert_eq!(color.scale(128), Rgb565::new(15, 20, 5));
+///
+/// // Mix two colors.
+/// assert_eq!(Rgb565::RED.mix(Rgb565::BLUE, 255), Rgb565::BLUE);
+///
+/// // Draw a 25% opaque white highlight over the color.
+/// assert_eq!(Rgb565::WHITE.blend(color, 64), Rgb565::new(30, 46, 15));
+/// ```
+///
+/// [`lerp`]: super::Rgb888::lerp
+/// [`mix`]: ColorMath::mix
+pub trait ColorMath: PixelColor {
+    /// Scales all channels by `fraction / 255`.
+    ///
+    /// A fraction of `0` returns black and a fraction of `255` returns the unchanged color.
+    fn scale(self, fraction: u8) -> Self;
+
+    /// Adds the channel values of two colors.
+    ///
+    /// Channels which would exceed their maximum value are set to the maximum value.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtracts the channel values of another color from this color.
+    ///
+    /// Channels which would become negative are set to `0`.
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Inverts all channels.
+    fn invert(self) -> Self;
+
+    /// Mixes this color with another color.
+    ///
+    /// `t` specifies the position between `self` (`0`) and `other` (`255`).
+    fn mix(self, other: Self, t: u8) -> Self;
+
+    /// Blends this color over an opaque background color.
+    ///
+    /// `alpha` specifies the opacity of this color, with `0` returning the background color and
+    /// `255` returning this color.
+    fn blend(self, background: Self, alpha: u8) -> Self {
+        background.mix(self, alpha)
+    }
+}
+
+/// Scales a channel value by `fraction / 255`.
+const fn scale_channel(value: u8, fraction: u8) -> u8 {
+    ((value as u16 * fraction as u16 + 127) / 255) as u8
+}
+
+/// Mixes two channel values.
+const fn mix_channel(a: u8, b: u8, t: u8) -> u8 {
+    ((a as u16 * (255 - t as u16) + b as u16 * t as u16 + 127) / 255) as u8
+}
+
+/// Adds two channel values and limits the result to the maximum channel value.
+fn add_channel(a: u8, b: u8, max: u8) -> u8 {
+    a.saturating_add(b).min(max)
+}
+
+macro_rules! impl_rgb_color_math {
+    ($($type:ident),+) => {
+        $(impl ColorMath for $type {
+            fn scale(self, fraction: u8) -> Self {
+                Self::new(
+                    scale_channel(self.r(), fraction),
+                    scale_channel(self.g(), fraction),
+                    scale_channel(self.b(), fraction),
+                )
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                Self::new(
+                    add_channel(self.r(), other.r(), Self::MAX_R),
+                    add_channel(self.g(), other.g(), Self::MAX_G),
+                    add_channel(self.b(), other.b(), Self::MAX_B),
+                )
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                Self::new(
+                    self.r().saturating_sub(other.r()),
+                    self.g().saturating_sub(other.g()),
+                    self.b().saturating_sub(other.b()),
+                )
+            }
+
+            fn invert(self) -> Self {
+                Self::new(
+                    Self::MAX_R - self.r(),
+                    Self::MAX_G - self.g(),
+                    Self::MAX_B - self.b(),
+                )
+            }
+
+            fn mix(self, other: Self, t: u8) -> Self {
+                Self::new(
+                    mix_channel(self.r(), other.r(), t),
+                    mix_channel(self.g(), other.g(), t),
+                    mix_channel(self.b(), other.b(), t),
+                )
+            }
+        })+
+    };
+}
+
+impl_rgb_color_math!(
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888
+);
+
+macro_rules! impl_gray_color_math {
+    ($($type:ident),+) => {
+        $(impl ColorMath for $type {
+            fn scale(self, fraction: u8) -> Self {
+                Self::new(scale_channel(self.luma(), fraction))
+            }
+
+            fn saturating_add(self, other: Self) -> Self {
+                Self::new(add_channel(self.luma(), other.luma(), Self::MAX_LUMA))
+            }
+
+            fn saturating_sub(self, other: Self) -> Self {
+                Self::new(self.luma().saturating_sub(other.luma()))
+            }
+
+            fn invert(self) -> Self {
+                Self::new(Self::MAX_LUMA - self.luma())
+            }
+
+            fn mix(self, other: Self, t: u8) -> Self {
+                Self::new(mix_channel(self.luma(), other.luma(), t))
+            }
+        })+
+    };
+}
+
+impl_gray_color_math!(Gray1, Gray2, Gray4, Gray8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale() {
+        let color = Rgb565::new(31, 63, 10);
+
+        assert_eq!(color.scale(0), Rgb565::BLACK);
+        assert_eq!(color.scale(255), color);
+        assert_eq!(color.scale(128), Rgb565::new(16, 32, 5));
+
+        assert_eq!(Gray4::new(15).scale(85), Gray4::new(5));
+        assert_eq!(Gray2::WHITE.scale(128), Gray2::new(2));
+    }
+
+    #[test]
+    fn scale_is_exact_for_all_channel_values() {
+        for value in 0..=255u8 {
+            let color = Rgb888::new(value, value, value);
+            assert_eq!(color.scale(255), color);
+            assert_eq!(color.scale(0), Rgb888::BLACK);
+
+            let color = Gray8::new(value);
+            assert_eq!(color.scale(255), color);
+            assert_eq!(color.scale(0), Gray8::BLACK);
+        }
+    }
+
+    #[test]
+    fn saturating_add_sub() {
+        let a = Rgb565::new(20, 40, 10);
+        let b = Rgb565::new(20, 10, 5);
+
+        assert_eq!(a.saturating_add(b), Rgb565::new(31, 50, 15));
+        assert_eq!(a.saturating_sub(b), Rgb565::new(0, 30, 5));
+        assert_eq!(b.saturating_sub(a), Rgb565::new(0, 0, 0));
+
+        assert_eq!(Gray2::new(2).saturating_add(Gray2::new(2)), Gray2::WHITE);
+        assert_eq!(
+            Gray8::new(200).saturating_add(Gray8::new(100)),
+            Gray8::WHITE
+        );
+        assert_eq!(
+            Gray8::new(100).saturating_sub(Gray8::new(200)),
+            Gray8::BLACK
+        );
+    }
+
+    #[test]
+    fn invert() {
+        assert_eq!(Rgb332::BLACK.invert(), Rgb332::WHITE);
+        assert_eq!(Rgb565::RED.invert(), Rgb565::CYAN);
+        assert_eq!(Bgr565::new(1, 2, 3).invert(), Bgr565::new(30, 61, 28));
+        assert_eq!(Gray4::new(3).invert(), Gray4::new(12));
+    }
+
+    #[test]
+    fn mix() {
+        let a = Rgb444::new(0, 15, 4);
+        let b = Rgb444::new(15, 0, 8);
+
+        assert_eq!(a.mix(b, 0), a);
+        assert_eq!(a.mix(b, 255), b);
+        assert_eq!(a.mix(b, 128), Rgb444::new(8, 7, 6));
+
+        assert_eq!(Gray8::new(10).mix(Gray8::new(20), 128), Gray8::new(15));
+    }
+
+    #[test]
+    fn blend() {
+        let background = Rgb888::new(0, 100, 200);
+
+        assert_eq!(Rgb888::WHITE.blend(background, 0), background);
+        assert_eq!(Rgb888::WHITE.blend(background, 255), Rgb888::WHITE);
+        assert_eq!(
+            Rgb888::WHITE.blend(background, 51),
+            Rgb888::new(51, 131, 211)
+        );
+
+        assert_eq!(Gray4::WHITE.blend(Gray4::BLACK, 128), Gray4::new(8));
+    }
+}
@@ -0,0 +1,581 @@
+//! Color spaces and color space conversions.
+//!
+//! This module contains the [`Hsv`] and [`Hsl`] color representations, which can be converted to
+//! and from all RGB color types, and [`LumaWeights`], which is used to configure the conversion
+//! from RGB to grayscale colors. All conversions use integer arithmetic and, except for the generic
+//! [`LumaWeights::to_gray`] method, can be used in `const` contexts.
+//!
+//! RGB and grayscale color types also provide a gamma correct `lerp` method to interpolate between
+//! two colors.
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_graphics::pixelcolor::{Hsv, Rgb565, Rgb888};
+//!
+//! const ORANGE: Rgb565 = Rgb565::from_hsv(Hsv::new(30, 255, 255));
+//! assert_eq!(ORANGE, Rgb565::from_rgb888(Rgb888::new(255, 128, 0)));
+//!
+//! let hsv = Rgb888::new(0, 0, 255).to_hsv();
+//! assert_eq!(hsv, Hsv::new(240, 255, 255));
+//!
+//! // Fade from black to white.
+//! let gray = Rgb888::BLACK.lerp(Rgb888::WHITE, 128);
+//! assert_eq!(gray, Rgb888::new(188, 188, 188));
+//! # use embedded_graphics::prelude::*;
+//! ```
+
+use crate::pixelcolor::{conversion::ConstColor, gray_color::*, rgb_color::*};
+
+/// HSV (hue, saturation, value) color.
+///
+/// The hue is specified in degrees and values outside the range `0..360` are wrapped into this
+/// range when the color is converted to RGB. The saturation and value are stored as 8 bit values,
+/// with `255` representing 100%.
+///
+/// `Hsv` isn't a [`PixelColor`](super::PixelColor) and can't be used for drawing directly. Use the
+/// `from_hsv` and `to_hsv` methods of the RGB color types to convert between `Hsv` and RGB colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Hsv {
+    /// Hue in degrees.
+    pub hue: u16,
+
+    /// Saturation.
+    pub saturation: u8,
+
+    /// Value.
+    pub value: u8,
+}
+
+impl Hsv {
+    /// Creates a new HSV color.
+    pub const fn new(hue: u16, saturation: u8, value: u8) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+        }
+    }
+
+    /// Converts 8 bit RGB channel values into a HSV color.
+    pub(crate) const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let (max, min) = max_min(r, g, b);
+        let chroma = max - min;
+
+        let saturation = if max == 0 {
+            0
+        } else {
+            div_round(chroma as u32 * 255, max as u32) as u8
+        };
+
+        Self::new(hue(r, g, b), saturation, max)
+    }
+
+    /// Converts the HSV color into 8 bit RGB channel values.
+    pub(crate) const fn to_rgb(self) -> (u8, u8, u8) {
+        let v = self.value as u32;
+        let s = self.saturation as u32;
+
+        let hue = (self.hue % 360) as u32;
+        let sector = hue / 60;
+        let remainder = hue % 60;
+
+        let p = div_round(v * (255 - s), 255) as u8;
+        let q = div_round(v * (255 * 60 - s * remainder), 255 * 60) as u8;
+        let t = div_round(v * (255 * 60 - s * (60 - remainder)), 255 * 60) as u8;
+        let v = self.value;
+
+        match sector {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        }
+    }
+}
+
+/// HSL (hue, saturation, lightness) color.
+///
+/// The hue is specified in degrees and values outside the range `0..360` are wrapped into this
+/// range when the color is converted to RGB. The saturation and lightness are stored as 8 bit
+/// values, with `255` representing 100%.
+///
+/// `Hsl` isn't a [`PixelColor`](super::PixelColor) and can't be used for drawing directly. Use the
+/// `from_hsl` and `to_hsl` methods of the RGB color types to convert between `Hsl` and RGB colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Hsl {
+    /// Hue in degrees.
+    pub hue: u16,
+
+    /// Saturation.
+    pub saturation: u8,
+
+    /// Lightness.
+    pub lightness: u8,
+}
+
+impl Hsl {
+    /// Creates a new HSL color.
+    pub const fn new(hue: u16, saturation: u8, lightness: u8) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+
+    /// Converts 8 bit RGB channel values into a HSL color.
+    pub(crate) const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let (max, min) = max_min(r, g, b);
+        let chroma = (max - min) as u32;
+        let sum = max as u32 + min as u32;
+
+        let saturation = if chroma == 0 {
+            0
+        } else {
+            div_round(chroma * 255, 255 - sum.abs_diff(255)) as u8
+        };
+
+        Self::new(hue(r, g, b), saturation, div_round(sum, 2) as u8)
+    }
+
+    /// Converts the HSL color into 8 bit RGB channel values.
+    pub(crate) const fn to_rgb(self) -> (u8, u8, u8) {
+        let l = self.lightness as u32;
+        let hue = (self.hue % 360) as u32;
+
+        // All values are scaled by 255.
+        let chroma = (255 - (2 * l).abs_diff(255)) * self.saturation as u32;
+        let x = chroma * (60 - (hue % 120).abs_diff(60)) / 60;
+        let m = 2 * l * 255 - chroma;
+
+        let c = div_round(2 * chroma + m, 510) as u8;
+        let x = div_round(2 * x + m, 510) as u8;
+        let m = div_round(m, 510) as u8;
+
+        match hue / 60 {
+            0 => (c, x, m),
+            1 => (x, c, m),
+            2 => (m, c, x),
+            3 => (m, x, c),
+            4 => (x, m, c),
+            _ => (c, m, x),
+        }
+    }
+}
+
+/// Weights used to calculate the luma of an RGB color.
+///
+/// The weights are fixed point values with 8 fractional bits and the sum of all weights must be
+/// `256`. The default weights, which are also used by the `From` implementations that convert RGB
+/// colors into grayscale colors, are based on ITU-R BT.601.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{Gray8, LumaWeights, Rgb565, Rgb888};
+///
+/// let color = Rgb888::new(0, 255, 0);
+/// assert_eq!(LumaWeights::BT601.luma(color), 149);
+/// assert_eq!(LumaWeights::BT709.luma(color), 182);
+///
+/// let gray: Gray8 = LumaWeights::AVERAGE.to_gray(Rgb565::new(0, 63, 0));
+/// assert_eq!(gray, Gray8::new(86));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct LumaWeights {
+    r: u16,
+    g: u16,
+    b: u16,
+}
+
+impl LumaWeights {
+    /// Weights based on ITU-R BT.601.
+    pub const BT601: Self = Self::new(77, 150, 29);
+
+    /// Weights based on ITU-R BT.709.
+    pub const BT709: Self = Self::new(54, 183, 19);
+
+    /// Equal weights for all channels.
+    pub const AVERAGE: Self = Self::new(85, 86, 85);
+
+    /// Creates new luma weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum of the weights isn't `256`.
+    pub const fn new(r: u16, g: u16, b: u16) -> Self {
+        assert!(
+            r as u32 + g as u32 + b as u32 == 256,
+            "the sum of the luma weights must be 256"
+        );
+
+        Self { r, g, b }
+    }
+
+    /// Calculates the luma value of a color.
+    pub const fn luma(self, color: Rgb888) -> u8 {
+        let (r, g, b) = color.channels();
+
+        ((r as u16 * self.r + g as u16 * self.g + b as u16 * self.b + 128) / 256) as u8
+    }
+
+    /// Converts an RGB color into a grayscale color using these weights.
+    pub fn to_gray<C, G>(self, color: C) -> G
+    where
+        C: RgbColor,
+        Rgb888: From<C>,
+        G: GrayColor + From<Gray8>,
+    {
+        Gray8::new(self.luma(Rgb888::from(color))).into()
+    }
+}
+
+impl Default for LumaWeights {
+    fn default() -> Self {
+        Self::BT601
+    }
+}
+
+/// Returns the maximum and minimum channel value.
+const fn max_min(r: u8, g: u8, b: u8) -> (u8, u8) {
+    let mut max = r;
+    let mut min = r;
+
+    if g > max {
+        max = g;
+    }
+    if b > max {
+        max = b;
+    }
+    if g < min {
+        min = g;
+    }
+    if b < min {
+        min = b;
+    }
+
+    (max, min)
+}
+
+/// Calculates the hue in degrees.
+const fn hue(r: u8, g: u8, b: u8) -> u16 {
+    let (max, min) = max_min(r, g, b);
+    let chroma = (max - min) as i32;
+
+    if chroma == 0 {
+        return 0;
+    }
+
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    let (offset, difference) = if max as i32 == r {
+        (0, g - b)
+    } else if max as i32 == g {
+        (120, b - r)
+    } else {
+        (240, r - g)
+    };
+
+    // Rounded division of `60 * difference / chroma`.
+    let angle = (120 * difference + difference.signum() * chroma) / (2 * chroma);
+
+    (offset + angle).rem_euclid(360) as u16
+}
+
+/// Divides and rounds the result to the nearest integer.
+const fn div_round(dividend: u32, divisor: u32) -> u32 {
+    (dividend + divisor / 2) / divisor
+}
+
+/// Lookup table to convert 8 bit sRGB values into 16 bit linear values.
+const SRGB_TO_LINEAR: [u16; 256] = [
+    0, 20, 40, 60, 80, 99, 119, 139, 159, 179, 199, 219, 241, 264, 288, 313, 340, 367, 396, 427,
+    458, 491, 526, 562, 599, 637, 677, 718, 761, 805, 851, 898, 947, 997, 1048, 1101, 1156, 1212,
+    1270, 1330, 1391, 1453, 1517, 1583, 1651, 1720, 1790, 1863, 1937, 2013, 2090, 2170, 2250, 2333,
+    2418, 2504, 2592, 2681, 2773, 2866, 2961, 3058, 3157, 3258, 3360, 3464, 3570, 3678, 3788, 3900,
+    4014, 4129, 4247, 4366, 4488, 4611, 4736, 4864, 4993, 5124, 5257, 5392, 5530, 5669, 5810, 5953,
+    6099, 6246, 6395, 6547, 6700, 6856, 7014, 7174, 7335, 7500, 7666, 7834, 8004, 8177, 8352, 8528,
+    8708, 8889, 9072, 9258, 9445, 9635, 9828, 10022, 10219, 10417, 10619, 10822, 11028, 11235,
+    11446, 11658, 11873, 12090, 12309, 12530, 12754, 12980, 13209, 13440, 13673, 13909, 14146,
+    14387, 14629, 14874, 15122, 15371, 15623, 15878, 16135, 16394, 16656, 16920, 17187, 17456,
+    17727, 18001, 18277, 18556, 18837, 19121, 19407, 19696, 19987, 20281, 20577, 20876, 21177,
+    21481, 21787, 22096, 22407, 22721, 23038, 23357, 23678, 24002, 24329, 24658, 24990, 25325,
+    25662, 26001, 26344, 26688, 27036, 27386, 27739, 28094, 28452, 28813, 29176, 29542, 29911,
+    30282, 30656, 31033, 31412, 31794, 32179, 32567, 32957, 33350, 33745, 34143, 34544, 34948,
+    35355, 35764, 36176, 36591, 37008, 37429, 37852, 38278, 38706, 39138, 39572, 40009, 40449,
+    40891, 41337, 41785, 42236, 42690, 43147, 43606, 44069, 44534, 45002, 45473, 45947, 46423,
+    46903, 47385, 47871, 48359, 48850, 49344, 49841, 50341, 50844, 51349, 51858, 52369, 52884,
+    53401, 53921, 54445, 54971, 55500, 56032, 56567, 57105, 57646, 58190, 58737, 59287, 59840,
+    60396, 60955, 61517, 62082, 62650, 63221, 63795, 64372, 64952, 65535,
+];
+
+/// Converts a 16 bit linear value into the nearest 8 bit sRGB value.
+const fn linear_to_srgb(value: u16) -> u8 {
+    // Binary search for the first entry that is greater than or equal to the value.
+    let mut low = 0;
+    let mut high = SRGB_TO_LINEAR.len() - 1;
+    while low < high {
+        let mid = (low + high) / 2;
+        if SRGB_TO_LINEAR[mid] < value {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    if low > 0 && value - SRGB_TO_LINEAR[low - 1] < SRGB_TO_LINEAR[low].saturating_sub(value) {
+        low -= 1;
+    }
+
+    low as u8
+}
+
+/// Interpolates between two 8 bit sRGB values in linear light.
+///
+/// `t` specifies the position between `a` (`0`) and `b` (`255`).
+const fn lerp_channel(a: u8, b: u8, t: u8) -> u8 {
+    let a = SRGB_TO_LINEAR[a as usize] as i32;
+    let b = SRGB_TO_LINEAR[b as usize] as i32;
+
+    let difference = (b - a) * t as i32;
+    let value = a + (2 * difference + difference.signum() * 255) / 510;
+
+    linear_to_srgb(value as u16)
+}
+
+/// Macro to implement the color space methods for RGB color types.
+macro_rules! impl_rgb_color_space {
+    ($($type:ident),+) => {
+        $(impl $type {
+            /// Creates a new color from a HSV color.
+            pub const fn from_hsv(hsv: Hsv) -> Self {
+                let (r, g, b) = hsv.to_rgb();
+
+                Self::from_const_color(ConstColor::rgb888(r, g, b))
+            }
+
+            /// Converts this color into a HSV color.
+            pub const fn to_hsv(self) -> Hsv {
+                let (r, g, b) = Rgb888::from_const_color(self.to_const_color()).channels();
+
+                Hsv::from_rgb(r, g, b)
+            }
+
+            /// Creates a new color from a HSL color.
+            pub const fn from_hsl(hsl: Hsl) -> Self {
+                let (r, g, b) = hsl.to_rgb();
+
+                Self::from_const_color(ConstColor::rgb888(r, g, b))
+            }
+
+            /// Converts this color into a HSL color.
+            pub const fn to_hsl(self) -> Hsl {
+                let (r, g, b) = Rgb888::from_const_color(self.to_const_color()).channels();
+
+                Hsl::from_rgb(r, g, b)
+            }
+
+            /// Interpolates between this color and another color.
+            ///
+            /// `t` specifies the position between `self` (`0`) and `other` (`255`). The
+            /// interpolation is done in linear light, which avoids the dark transitions that are
+            /// caused by interpolating gamma encoded sRGB values directly.
+            pub const fn lerp(self, other: Self, t: u8) -> Self {
+                let (r1, g1, b1) = Rgb888::from_const_color(self.to_const_color()).channels();
+                let (r2, g2, b2) = Rgb888::from_const_color(other.to_const_color()).channels();
+
+                Self::from_const_color(ConstColor::rgb888(
+                    lerp_channel(r1, r2, t),
+                    lerp_channel(g1, g2, t),
+                    lerp_channel(b1, b2, t),
+                ))
+            }
+        })+
+    };
+}
+
+impl_rgb_color_space!(
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888
+);
+
+/// Macro to implement the color space methods for grayscale color types.
+macro_rules! impl_gray_color_space {
+    ($($type:ident),+) => {
+        $(impl $type {
+            /// Interpolates between this color and another color.
+            ///
+            /// `t` specifies the position between `self` (`0`) and `other` (`255`). The
+            /// interpolation is done in linear light, which avoids the dark transitions that are
+            /// caused by interpolating gamma encoded values directly.
+            pub const fn lerp(self, other: Self, t: u8) -> Self {
+                let a = Gray8::from_const_color(self.to_const_color()).luma_const();
+                let b = Gray8::from_const_color(other.to_const_color()).luma_const();
+
+                Self::from_const_color(ConstColor::gray8(lerp_channel(a, b, t)))
+            }
+        })+
+    };
+}
+
+impl_gray_color_space!(Gray1, Gray2, Gray4, Gray8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsv_primary_colors() {
+        assert_eq!(Rgb888::BLACK.to_hsv(), Hsv::new(0, 0, 0));
+        assert_eq!(Rgb888::WHITE.to_hsv(), Hsv::new(0, 0, 255));
+        assert_eq!(Rgb888::RED.to_hsv(), Hsv::new(0, 255, 255));
+        assert_eq!(Rgb888::YELLOW.to_hsv(), Hsv::new(60, 255, 255));
+        assert_eq!(Rgb888::GREEN.to_hsv(), Hsv::new(120, 255, 255));
+        assert_eq!(Rgb888::CYAN.to_hsv(), Hsv::new(180, 255, 255));
+        assert_eq!(Rgb888::BLUE.to_hsv(), Hsv::new(240, 255, 255));
+        assert_eq!(Rgb888::MAGENTA.to_hsv(), Hsv::new(300, 255, 255));
+
+        for color in [
+            Rgb565::BLACK,
+            Rgb565::WHITE,
+            Rgb565::RED,
+            Rgb565::YELLOW,
+            Rgb565::GREEN,
+            Rgb565::CYAN,
+            Rgb565::BLUE,
+            Rgb565::MAGENTA,
+        ] {
+            assert_eq!(Rgb565::from_hsv(color.to_hsv()), color);
+            assert_eq!(Rgb565::from_hsl(color.to_hsl()), color);
+        }
+    }
+
+    #[test]
+    fn hsl_primary_colors() {
+        assert_eq!(Rgb888::BLACK.to_hsl(), Hsl::new(0, 0, 0));
+        assert_eq!(Rgb888::WHITE.to_hsl(), Hsl::new(0, 0, 255));
+        assert_eq!(Rgb888::RED.to_hsl(), Hsl::new(0, 255, 128));
+        assert_eq!(Rgb888::GREEN.to_hsl(), Hsl::new(120, 255, 128));
+        assert_eq!(Rgb888::BLUE.to_hsl(), Hsl::new(240, 255, 128));
+        assert_eq!(Rgb888::new(128, 128, 128).to_hsl(), Hsl::new(0, 0, 128));
+    }
+
+    #[test]
+    fn hue_wraps_around() {
+        assert_eq!(
+            Rgb888::from_hsv(Hsv::new(360 + 120, 255, 255)),
+            Rgb888::GREEN
+        );
+        assert_eq!(
+            Rgb888::from_hsl(Hsl::new(720, 255, 128)),
+            Rgb888::from_hsl(Hsl::new(0, 255, 128))
+        );
+    }
+
+    #[test]
+    fn rgb_hsv_rgb_round_trip() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let color = Rgb888::new(r, g, b);
+
+                    // The hue is stored in whole degrees, which limits the precision of the round
+                    // trip for saturated colors.
+                    for converted in [
+                        Rgb888::from_hsv(color.to_hsv()),
+                        Rgb888::from_hsl(color.to_hsl()),
+                    ] {
+                        assert!(
+                            converted.r().abs_diff(r) <= 3
+                                && converted.g().abs_diff(g) <= 3
+                                && converted.b().abs_diff(b) <= 3,
+                            "{:?} != {:?}",
+                            converted,
+                            color
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn luma_weights() {
+        assert_eq!(LumaWeights::default(), LumaWeights::BT601);
+
+        for weights in [LumaWeights::BT601, LumaWeights::BT709, LumaWeights::AVERAGE] {
+            assert_eq!(weights.luma(Rgb888::BLACK), 0);
+            assert_eq!(weights.luma(Rgb888::WHITE), 255);
+        }
+
+        assert_eq!(LumaWeights::BT601.luma(Rgb888::RED), 77);
+        assert_eq!(LumaWeights::BT709.luma(Rgb888::RED), 54);
+        assert_eq!(LumaWeights::AVERAGE.luma(Rgb888::RED), 85);
+
+        let gray: Gray4 = LumaWeights::BT709.to_gray(Rgb888::new(0, 0, 255));
+        assert_eq!(gray, Gray4::new(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 256")]
+    fn invalid_luma_weights() {
+        LumaWeights::new(100, 100, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 256")]
+    fn overflowing_luma_weights() {
+        LumaWeights::new(65535, 257, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be 256")]
+    fn maximum_luma_weights() {
+        LumaWeights::new(u16::MAX, u16::MAX, u16::MAX);
+    }
+
+    #[test]
+    fn linear_to_srgb_is_inverse() {
+        for value in 0..=255u8 {
+            assert_eq!(linear_to_srgb(SRGB_TO_LINEAR[value as usize]), value);
+        }
+    }
+
+    #[test]
+    fn lerp_end_points() {
+        let a = Rgb565::new(3, 40, 20);
+        let b = Rgb565::new(30, 2, 7);
+
+        assert_eq!(a.lerp(b, 0), a);
+        assert_eq!(a.lerp(b, 255), b);
+
+        assert_eq!(Gray4::new(3).lerp(Gray4::new(12), 0), Gray4::new(3));
+        assert_eq!(Gray4::new(3).lerp(Gray4::new(12), 255), Gray4::new(12));
+    }
+
+    #[test]
+    fn lerp_is_gamma_correct() {
+        // 50% linear light is brighter than the 50% sRGB value.
+        assert_eq!(Gray8::BLACK.lerp(Gray8::WHITE, 128), Gray8::new(188));
+        assert_eq!(Gray8::WHITE.lerp(Gray8::BLACK, 127), Gray8::new(188));
+
+        let color = Rgb888::RED.lerp(Rgb888::GREEN, 128);
+        assert_eq!(color, Rgb888::new(187, 188, 0));
+    }
+
+    #[test]
+    fn const_usage() {
+        const COLOR: Rgb565 = Rgb565::from_hsv(Hsv::new(240, 255, 255));
+        const HSL: Hsl = Rgb888::RED.to_hsl();
+        const FADE: Gray8 = Gray8::BLACK.lerp(Gray8::WHITE, 255);
+
+        assert_eq!(COLOR, Rgb565::BLUE);
+        assert_eq!(HSL, Hsl::new(0, 255, 128));
+        assert_eq!(FADE, Gray8::WHITE);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::pixelcolor::{binary_color::*, gray_color::*, rgb_color::*};
+use crate::pixelcolor::{binary_color::*, color_space::LumaWeights, gray_color::*, rgb_color::*};
 
 /// Convert color channel values from one bit depth to another.
 ///
@@ -20,13 +20,8 @@ const fn convert_channel<const FROM_MAX: u8, const TO_MAX: u8>(value: u8) -> u8
 }
 
 /// Calculates the luma value based on ITU-R BT.601.
-fn luma(color: Rgb888) -> u8 {
-    let r = u16::from(color.r());
-    let g = u16::from(color.g());
-    let b = u16::from(color.b());
-
-    // Original formula: 0.299 * R + 0.587 * G + 0.144 * B
-    ((r * 77 + g * 150 + b * 29 + 128) / 256) as u8
+const fn luma(color: Rgb888) -> u8 {
+    LumaWeights::BT601.luma(color)
 }
 
 /// Macro to implement conversion between RGB color types.
@@ -55,16 +50,20 @@ macro_rules! impl_rgb_conversion {
     };
 }
 
-impl_rgb_conversion!(Rgb332 => Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Rgb444 => Rgb332, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Rgb555 => Rgb332, Rgb444, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Bgr555 => Rgb332, Rgb444, Rgb555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Rgb565 => Rgb332, Rgb444, Rgb555, Bgr555, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Bgr565 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Rgb888, Bgr888);
-impl_rgb_conversion!(Rgb666 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr666, Bgr565, Bgr888, Rgb888);
-impl_rgb_conversion!(Bgr666 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr565, Bgr888, Rgb888);
-impl_rgb_conversion!(Rgb888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Bgr565, Bgr888);
-impl_rgb_conversion!(Bgr888 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Bgr565, Rgb888);
+impl_rgb_conversion!(Rgb332 => Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr332 => Rgb332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Rgb444 => Rgb332, Bgr332, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr444 => Rgb332, Bgr332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Rgb555 => Rgb332, Bgr332, Rgb444, Bgr444, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr555 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Rgb565 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr565 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Rgb666 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr666 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Rgb888 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Bgr888 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Xrgb8888, Xbgr8888);
+impl_rgb_conversion!(Xrgb8888 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xbgr8888);
+impl_rgb_conversion!(Xbgr8888 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888);
 
 /// Macro to implement conversion between grayscale color types.
 macro_rules! impl_gray_conversion {
@@ -77,9 +76,10 @@ macro_rules! impl_gray_conversion {
     };
 }
 
-impl_gray_conversion!(Gray2 => Gray4, Gray8);
-impl_gray_conversion!(Gray4 => Gray2, Gray8);
-impl_gray_conversion!(Gray8 => Gray2, Gray4);
+impl_gray_conversion!(Gray1 => Gray2, Gray4, Gray8);
+impl_gray_conversion!(Gray2 => Gray1, Gray4, Gray8);
+impl_gray_conversion!(Gray4 => Gray1, Gray2, Gray8);
+impl_gray_conversion!(Gray8 => Gray1, Gray2, Gray4);
 
 /// Macro to implement conversions between grayscale and RGB color types.
 macro_rules! impl_rgb_to_and_from_gray {
@@ -108,7 +108,7 @@ macro_rules! impl_rgb_to_and_from_gray {
     }
 }
 
-impl_rgb_to_and_from_gray!(Gray2, Gray4, Gray8 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+impl_rgb_to_and_from_gray!(Gray1, Gray2, Gray4, Gray8 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
 
 /// Macro to implement conversion from `BinaryColor` to RGB and grayscale types.
 macro_rules! impl_from_binary {
@@ -122,8 +122,8 @@ macro_rules! impl_from_binary {
 }
 
 impl_from_binary!(
-    Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Gray2, Gray4,
-    Gray8
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888, Gray1, Gray2, Gray4, Gray8
 );
 
 /// Macro to implement conversion from grayscale types to `BinaryColor`.
@@ -137,7 +137,7 @@ macro_rules! impl_gray_to_binary {
     };
 }
 
-impl_gray_to_binary!(Gray2, Gray4, Gray8);
+impl_gray_to_binary!(Gray1, Gray2, Gray4, Gray8);
 
 /// Macro to implement conversion from RGB types to `BinaryColor`.
 macro_rules! impl_rgb_to_binary {
@@ -150,9 +150,444 @@ macro_rules! impl_rgb_to_binary {
     };
 }
 
-impl_rgb_to_binary!(Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+impl_rgb_to_binary!(
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888
+);
+
+/// Convert channel values with up to 16 bits from one bit depth to another.
+///
+/// Unlike [`convert_channel`] this function takes the maximum values as regular arguments, which
+/// is required for the const conversion methods. Both functions return the same results for 8 bit
+/// channel values.
+const fn convert_channel_u16(value: u16, from_max: u16, to_max: u16) -> u16 {
+    ((value as u32 * to_max as u32 + from_max as u32 / 2) / from_max as u32) as u16
+}
+
+/// Macro to implement conversions between `Rgb101010` and other RGB color types.
+macro_rules! impl_rgb101010_conversion {
+    ($($type:ident),+) => {
+        $(impl From<$type> for Rgb101010 {
+            fn from(other: $type) -> Self {
+                Self::new(
+                    convert_channel_u16(other.r().into(), $type::MAX_R.into(), Self::MAX),
+                    convert_channel_u16(other.g().into(), $type::MAX_G.into(), Self::MAX),
+                    convert_channel_u16(other.b().into(), $type::MAX_B.into(), Self::MAX),
+                )
+            }
+        }
+
+        impl From<Rgb101010> for $type {
+            fn from(other: Rgb101010) -> Self {
+                Self::new(
+                    convert_channel_u16(other.r(), Rgb101010::MAX, Self::MAX_R.into()) as u8,
+                    convert_channel_u16(other.g(), Rgb101010::MAX, Self::MAX_G.into()) as u8,
+                    convert_channel_u16(other.b(), Rgb101010::MAX, Self::MAX_B.into()) as u8,
+                )
+            }
+        })+
+    };
+}
+
+impl_rgb101010_conversion!(
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888
+);
+
+/// Macro to implement conversions between `Rgb101010` and grayscale color types.
+macro_rules! impl_rgb101010_gray_conversion {
+    ($($type:ident),+) => {
+        $(impl From<$type> for Rgb101010 {
+            fn from(other: $type) -> Self {
+                let luma = convert_channel_u16(other.luma().into(), $type::MAX_LUMA.into(), Self::MAX);
+
+                Self::new(luma, luma, luma)
+            }
+        }
+
+        impl From<Rgb101010> for $type {
+            fn from(other: Rgb101010) -> Self {
+                Gray8::new(luma(Rgb888::from(other))).into()
+            }
+        })+
+    };
+}
+
+impl_rgb101010_gray_conversion!(Gray1, Gray2, Gray4, Gray8);
+
+impl From<BinaryColor> for Rgb101010 {
+    fn from(color: BinaryColor) -> Self {
+        color.map_color(Self::BLACK, Self::WHITE)
+    }
+}
+
+impl From<Rgb101010> for BinaryColor {
+    fn from(color: Rgb101010) -> Self {
+        (luma(Rgb888::from(color)) >= 128).into()
+    }
+}
+
+impl Rgb101010 {
+    /// Converts this color into [`Rgb888`].
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts.
+    pub const fn to_rgb888(self) -> Rgb888 {
+        Rgb888::from_const_color(self.to_const_color())
+    }
+
+    /// Converts an [`Rgb888`] color into this color type.
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts.
+    pub const fn from_rgb888(color: Rgb888) -> Self {
+        Self::from_const_color(color.to_const_color())
+    }
+
+    pub(crate) const fn to_const_color(self) -> ConstColor {
+        ConstColor::Rgb {
+            r: self.r(),
+            g: self.g(),
+            b: self.b(),
+            max_r: Self::MAX,
+            max_g: Self::MAX,
+            max_b: Self::MAX,
+        }
+    }
+
+    pub(crate) const fn from_const_color(color: ConstColor) -> Self {
+        let (r, g, b) = color.to_rgb(Self::MAX, Self::MAX, Self::MAX);
+
+        Self::new(r, g, b)
+    }
+}
+
+/// Macro to implement conversions between `Gray16` and other grayscale color types.
+macro_rules! impl_gray16_conversion {
+    ($($type:ident),+) => {
+        $(impl From<$type> for Gray16 {
+            fn from(other: $type) -> Self {
+                Self::new(convert_channel_u16(other.luma().into(), $type::MAX_LUMA.into(), 0xFFFF))
+            }
+        }
+
+        impl From<Gray16> for $type {
+            fn from(other: Gray16) -> Self {
+                Self::new(convert_channel_u16(other.luma(), 0xFFFF, $type::MAX_LUMA.into()) as u8)
+            }
+        })+
+    };
+}
+
+impl_gray16_conversion!(Gray1, Gray2, Gray4, Gray8);
+
+/// Macro to implement conversions between `Gray16` and RGB color types.
+///
+/// The conversions use `Gray8` as an intermediate type.
+macro_rules! impl_gray16_rgb_conversion {
+    ($($type:ident),+) => {
+        $(impl From<$type> for Gray16 {
+            fn from(other: $type) -> Self {
+                Gray8::from(other).into()
+            }
+        }
+
+        impl From<Gray16> for $type {
+            fn from(other: Gray16) -> Self {
+                Gray8::from(other).into()
+            }
+        })+
+    };
+}
+
+impl_gray16_rgb_conversion!(
+    Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888,
+    Xrgb8888, Xbgr8888
+);
+
+impl From<BinaryColor> for Gray16 {
+    fn from(color: BinaryColor) -> Self {
+        color.map_color(Self::BLACK, Self::WHITE)
+    }
+}
+
+impl From<Gray16> for BinaryColor {
+    fn from(color: Gray16) -> Self {
+        (color.luma() >= 0x8000).into()
+    }
+}
+
+impl From<Gray16> for Rgb101010 {
+    fn from(other: Gray16) -> Self {
+        let luma = convert_channel_u16(other.luma(), 0xFFFF, Self::MAX);
+
+        Self::new(luma, luma, luma)
+    }
+}
+
+impl From<Rgb101010> for Gray16 {
+    fn from(other: Rgb101010) -> Self {
+        Gray8::from(other).into()
+    }
+}
+
+impl Gray16 {
+    /// Converts this color into [`Rgb888`].
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts.
+    pub const fn to_rgb888(self) -> Rgb888 {
+        Rgb888::from_const_color(self.to_const_color())
+    }
+
+    /// Converts an [`Rgb888`] color into this color type.
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts.
+    pub const fn from_rgb888(color: Rgb888) -> Self {
+        Self::from_const_color(color.to_const_color())
+    }
+
+    /// Converts this color into [`Rgb101010`].
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts. Unlike chaining [`to_rgb888`](Self::to_rgb888) and
+    /// [`Rgb101010::from_rgb888`] the conversion doesn't reduce the luma value to 8 bits.
+    pub const fn to_rgb101010(self) -> Rgb101010 {
+        Rgb101010::from_const_color(self.to_const_color())
+    }
+
+    /// Converts an [`Rgb101010`] color into this color type.
+    ///
+    /// This method returns the same result as the `From` implementation, but can also be used in
+    /// `const` contexts.
+    pub const fn from_rgb101010(color: Rgb101010) -> Self {
+        Self::from_const_color(color.to_const_color())
+    }
+
+    pub(crate) const fn to_const_color(self) -> ConstColor {
+        ConstColor::Gray {
+            luma: self.luma(),
+            max: 0xFFFF,
+        }
+    }
+
+    pub(crate) const fn from_const_color(color: ConstColor) -> Self {
+        Self::new(color.to_gray(0xFFFF))
+    }
+}
+
+/// Intermediate color representation used by the const conversion methods.
+///
+/// The channel values are stored together with their maximum value to make it possible to apply
+/// the same conversion rules as the `From` implementations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConstColor {
+    Rgb {
+        r: u16,
+        g: u16,
+        b: u16,
+        max_r: u16,
+        max_g: u16,
+        max_b: u16,
+    },
+    Gray {
+        luma: u16,
+        max: u16,
+    },
+    Binary(bool),
+}
+
+impl ConstColor {
+    /// Creates an RGB color with 8 bits per channel.
+    pub(crate) const fn rgb888(r: u8, g: u8, b: u8) -> Self {
+        Self::Rgb {
+            r: r as u16,
+            g: g as u16,
+            b: b as u16,
+            max_r: 255,
+            max_g: 255,
+            max_b: 255,
+        }
+    }
+
+    /// Creates a grayscale color with 8 bits per pixel.
+    pub(crate) const fn gray8(luma: u8) -> Self {
+        Self::Gray {
+            luma: luma as u16,
+            max: 255,
+        }
+    }
+
+    /// Converts the color into RGB channels with the given maximum values.
+    const fn to_rgb(self, to_max_r: u16, to_max_g: u16, to_max_b: u16) -> (u16, u16, u16) {
+        match self {
+            Self::Rgb {
+                r,
+                g,
+                b,
+                max_r,
+                max_g,
+                max_b,
+            } => (
+                convert_channel_u16(r, max_r, to_max_r),
+                convert_channel_u16(g, max_g, to_max_g),
+                convert_channel_u16(b, max_b, to_max_b),
+            ),
+            Self::Gray { luma, max } => (
+                convert_channel_u16(luma, max, to_max_r),
+                convert_channel_u16(luma, max, to_max_g),
+                convert_channel_u16(luma, max, to_max_b),
+            ),
+            Self::Binary(true) => (to_max_r, to_max_g, to_max_b),
+            Self::Binary(false) => (0, 0, 0),
+        }
+    }
+
+    /// Converts the color into a luma value with the given maximum value.
+    const fn to_gray(self, to_max: u16) -> u16 {
+        match self {
+            Self::Rgb { .. } => {
+                let (r, g, b) = self.to_rgb(255, 255, 255);
+                let luma = luma(Rgb888::new(r as u8, g as u8, b as u8));
+
+                convert_channel_u16(luma as u16, 255, to_max)
+            }
+            Self::Gray { luma, max } => convert_channel_u16(luma, max, to_max),
+            Self::Binary(true) => to_max,
+            Self::Binary(false) => 0,
+        }
+    }
+
+    /// Converts the color into a binary color.
+    const fn to_binary(self) -> BinaryColor {
+        let is_on = match self {
+            Self::Rgb { .. } => {
+                let (r, g, b) = self.to_rgb(255, 255, 255);
+                luma(Rgb888::new(r as u8, g as u8, b as u8)) >= 128
+            }
+            Self::Gray { luma, max } => luma > max / 2,
+            Self::Binary(is_on) => is_on,
+        };
+
+        if is_on {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        }
+    }
+}
+
+/// Macro to implement the conversions between color types and `ConstColor`.
+macro_rules! impl_const_color {
+    (rgb: $($type:ident),+) => {
+        $(impl $type {
+            pub(crate) const fn to_const_color(self) -> ConstColor {
+                let (r, g, b) = self.channels();
+
+                ConstColor::Rgb {
+                    r: r as u16,
+                    g: g as u16,
+                    b: b as u16,
+                    max_r: Self::MAX_R as u16,
+                    max_g: Self::MAX_G as u16,
+                    max_b: Self::MAX_B as u16,
+                }
+            }
+
+            pub(crate) const fn from_const_color(color: ConstColor) -> Self {
+                let (r, g, b) = color.to_rgb(
+                    Self::MAX_R as u16,
+                    Self::MAX_G as u16,
+                    Self::MAX_B as u16,
+                );
+
+                Self::new(r as u8, g as u8, b as u8)
+            }
+        })+
+    };
+
+    (gray: $($type:ident),+) => {
+        $(impl $type {
+            pub(crate) const fn to_const_color(self) -> ConstColor {
+                ConstColor::Gray {
+                    luma: self.luma_const() as u16,
+                    max: Self::MAX_LUMA as u16,
+                }
+            }
+
+            pub(crate) const fn from_const_color(color: ConstColor) -> Self {
+                Self::new(color.to_gray(Self::MAX_LUMA as u16) as u8)
+            }
+        })+
+    };
+}
+
+impl_const_color!(rgb: Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+impl_const_color!(gray: Gray1, Gray2, Gray4, Gray8);
+
+impl BinaryColor {
+    pub(crate) const fn to_const_color(self) -> ConstColor {
+        ConstColor::Binary(self.is_on())
+    }
+
+    pub(crate) const fn from_const_color(color: ConstColor) -> Self {
+        color.to_binary()
+    }
+}
+
+/// Macro to implement const conversion methods to and from `Rgb888`.
+///
+/// The `From` implementations can't be used in `const` contexts. These methods provide the same
+/// conversions as `const fn`s. Conversions between other color types can be done by chaining
+/// both methods, e.g. `Rgb565::from_rgb888(color.to_rgb888())`. Chaining reduces the precision of
+/// `Rgb101010` and `Gray16` colors to 8 bits, use `Gray16::to_rgb101010` and
+/// `Gray16::from_rgb101010` to convert between these types instead.
+macro_rules! impl_rgb888_const_conversions {
+    ($($type:ident),+) => {
+        $(impl $type {
+            /// Converts this color into [`Rgb888`].
+            ///
+            /// This method returns the same result as the `From` implementation, but can also be
+            /// used in `const` contexts.
+            pub const fn to_rgb888(self) -> Rgb888 {
+                Rgb888::from_const_color(self.to_const_color())
+            }
+
+            /// Converts an [`Rgb888`] color into this color type.
+            ///
+            /// This method returns the same result as the `From` implementation, but can also be
+            /// used in `const` contexts.
+            pub const fn from_rgb888(color: Rgb888) -> Self {
+                Self::from_const_color(color.to_const_color())
+            }
+        })+
+    };
+}
+
+impl_rgb888_const_conversions!(
+    Rgb332,
+    Bgr332,
+    Rgb444,
+    Bgr444,
+    Rgb555,
+    Bgr555,
+    Rgb565,
+    Bgr565,
+    Rgb666,
+    Bgr666,
+    Bgr888,
+    Xrgb8888,
+    Xbgr8888,
+    Gray1,
+    Gray2,
+    Gray4,
+    Gray8,
+    BinaryColor
+);
 
 #[cfg(test)]
+#[allow(clippy::extra_unused_type_parameters)]
 mod tests {
     use core::fmt::Debug;
 
@@ -275,7 +710,7 @@ mod tests {
             assert_eq!(ToC::from(FromC::WHITE), ToC::WHITE);
         }
 
-        type_matrix!(test_rgb_to_rgb; Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+        type_matrix!(test_rgb_to_rgb; Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
     }
 
     #[test]
@@ -285,7 +720,7 @@ mod tests {
             assert_eq!(ToC::from(FromC::WHITE), ToC::WHITE);
         }
 
-        type_matrix!(test_rgb_to_gray; Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888 => Gray2, Gray4, Gray8);
+        type_matrix!(test_rgb_to_gray; Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888 => Gray1, Gray2, Gray4, Gray8);
     }
 
     #[test]
@@ -298,7 +733,7 @@ mod tests {
             assert_eq!(BinaryColor::from(FromC::WHITE), BinaryColor::On);
         }
 
-        type_matrix!(test_rgb_to_binary; Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888 => BinaryColor);
+        type_matrix!(test_rgb_to_binary; Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888 => BinaryColor);
     }
 
     #[test]
@@ -308,7 +743,7 @@ mod tests {
             assert_eq!(ToC::from(FromC::WHITE), ToC::WHITE);
         }
 
-        type_matrix!(test_gray_to_gray; Gray2, Gray4, Gray8);
+        type_matrix!(test_gray_to_gray; Gray1, Gray2, Gray4, Gray8);
     }
 
     #[test]
@@ -318,7 +753,7 @@ mod tests {
             assert_eq!(ToC::from(FromC::WHITE), ToC::WHITE);
         }
 
-        type_matrix!(test_gray_to_rgb; Gray2, Gray4, Gray8 => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+        type_matrix!(test_gray_to_rgb; Gray1, Gray2, Gray4, Gray8 => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
     }
 
     #[test]
@@ -331,7 +766,7 @@ mod tests {
             assert_eq!(BinaryColor::from(FromC::WHITE), BinaryColor::On);
         }
 
-        type_matrix!(test_gray_to_binary; Gray2, Gray4, Gray8 => BinaryColor);
+        type_matrix!(test_gray_to_binary; Gray1, Gray2, Gray4, Gray8 => BinaryColor);
     }
 
     #[test]
@@ -341,7 +776,7 @@ mod tests {
             assert_eq!(ToC::from(BinaryColor::On), ToC::WHITE);
         }
 
-        type_matrix!(test_binary_to_rgb; BinaryColor => Rgb332, Rgb444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888);
+        type_matrix!(test_binary_to_rgb; BinaryColor => Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
     }
 
     #[test]
@@ -351,7 +786,260 @@ mod tests {
             assert_eq!(ToC::from(BinaryColor::On), ToC::WHITE);
         }
 
-        type_matrix!(test_binary_to_gray; BinaryColor => Gray2, Gray4, Gray8);
+        type_matrix!(test_binary_to_gray; BinaryColor => Gray1, Gray2, Gray4, Gray8);
+    }
+
+    #[test]
+    fn const_conversions_match_from() {
+        macro_rules! test_const_conversions {
+            ($($type:ident),+) => {
+                $(
+                    for color in $type::test_colors() {
+                        assert_eq!(color.to_rgb888(), Rgb888::from(color), "{:?}", color);
+                    }
+
+                    for color in Rgb888::test_colors() {
+                        assert_eq!(
+                            $type::from_rgb888(color),
+                            $type::from(color),
+                            "{:?} into {}",
+                            color,
+                            stringify!($type),
+                        );
+                    }
+                )+
+            };
+        }
+
+        test_const_conversions!(
+            Rgb332,
+            Bgr332,
+            Rgb444,
+            Bgr444,
+            Rgb555,
+            Bgr555,
+            Rgb565,
+            Bgr565,
+            Rgb666,
+            Bgr666,
+            Bgr888,
+            Xrgb8888,
+            Xbgr8888,
+            Gray1,
+            Gray2,
+            Gray4,
+            Gray8,
+            Gray16,
+            BinaryColor,
+            Rgb101010
+        );
+    }
+
+    /// Returns colors that cover all channel values.
+    trait TestColors: Sized {
+        fn test_colors() -> impl Iterator<Item = Self>;
+    }
+
+    macro_rules! impl_test_colors {
+        (rgb: $($type:ident),+) => {
+            $(impl TestColors for $type {
+                fn test_colors() -> impl Iterator<Item = Self> {
+                    (0..=255u8).flat_map(|value| {
+                        [
+                            Self::new(value, 0, 0),
+                            Self::new(0, value, 0),
+                            Self::new(0, 0, value),
+                            Self::new(value, value, value),
+                            Self::new(value, value / 2, 255 - value),
+                        ]
+                    })
+                }
+            })+
+        };
+
+        (gray: $($type:ident),+) => {
+            $(impl TestColors for $type {
+                fn test_colors() -> impl Iterator<Item = Self> {
+                    (0..=255u8).map(Self::new)
+                }
+            })+
+        };
+    }
+
+    impl_test_colors!(rgb: Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Rgb888, Bgr888, Xrgb8888, Xbgr8888);
+    impl_test_colors!(gray: Gray1, Gray2, Gray4, Gray8);
+
+    impl TestColors for Rgb101010 {
+        fn test_colors() -> impl Iterator<Item = Self> {
+            (0..=Self::MAX).flat_map(|value| {
+                [
+                    Self::new(value, 0, 0),
+                    Self::new(0, value, 0),
+                    Self::new(0, 0, value),
+                    Self::new(value, value, value),
+                    Self::new(value, value / 2, Self::MAX - value),
+                ]
+            })
+        }
+    }
+
+    impl TestColors for Gray16 {
+        fn test_colors() -> impl Iterator<Item = Self> {
+            (0..=0xFFFF).map(Self::new)
+        }
+    }
+
+    impl TestColors for BinaryColor {
+        fn test_colors() -> impl Iterator<Item = Self> {
+            [BinaryColor::Off, BinaryColor::On].into_iter()
+        }
+    }
+
+    #[test]
+    fn const_conversions_in_const_context() {
+        const COLOR: Rgb565 = Rgb565::from_rgb888(Rgb888::new(255, 128, 0));
+        const GRAY: Gray4 = Gray4::from_rgb888(COLOR.to_rgb888());
+        const BINARY: BinaryColor = BinaryColor::from_rgb888(GRAY.to_rgb888());
+
+        assert_eq!(COLOR, Rgb565::from(Rgb888::new(255, 128, 0)));
+        assert_eq!(GRAY, Gray4::from(COLOR));
+        assert_eq!(BINARY, BinaryColor::On);
+    }
+
+    #[test]
+    fn rgb101010_conversions() {
+        assert_eq!(Rgb101010::from(Rgb888::WHITE), Rgb101010::WHITE);
+        assert_eq!(Rgb101010::from(Rgb565::RED), Rgb101010::new(1023, 0, 0));
+        assert_eq!(Rgb101010::from(BinaryColor::On), Rgb101010::WHITE);
+        assert_eq!(Rgb565::from(Rgb101010::new(0, 1023, 0)), Rgb565::GREEN);
+
+        for value in 0..=255 {
+            let color = Rgb888::new(value, 255 - value, value / 2);
+            assert_eq!(Rgb888::from(Rgb101010::from(color)), color);
+        }
+
+        for value in 0..=Rgb666::MAX_R {
+            let color = Bgr666::new(value, 0, Bgr666::MAX_B - value);
+            assert_eq!(Bgr666::from(Rgb101010::from(color)), color);
+        }
+
+        assert_eq!(BinaryColor::from(Rgb101010::BLACK), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(Rgb101010::WHITE), BinaryColor::On);
+        assert_eq!(
+            BinaryColor::from(Rgb101010::new(0, 0, 1023)),
+            BinaryColor::Off
+        );
+        assert_eq!(
+            BinaryColor::from(Rgb101010::new(0, 1023, 0)),
+            BinaryColor::On
+        );
+
+        for color in [BinaryColor::Off, BinaryColor::On] {
+            assert_eq!(BinaryColor::from(Rgb101010::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn rgb101010_gray_conversions() {
+        assert_eq!(Rgb101010::from(Gray1::WHITE), Rgb101010::WHITE);
+        assert_eq!(
+            Rgb101010::from(Gray2::new(1)),
+            Rgb101010::new(341, 341, 341)
+        );
+        assert_eq!(Gray4::from(Rgb101010::WHITE), Gray4::WHITE);
+        assert_eq!(Gray8::from(Rgb101010::new(1023, 0, 0)), Gray8::new(77));
+
+        for luma in 0..=Gray1::MAX_LUMA {
+            let color = Gray1::new(luma);
+            assert_eq!(Gray1::from(Rgb101010::from(color)), color);
+        }
+
+        for luma in 0..=Gray2::MAX_LUMA {
+            let color = Gray2::new(luma);
+            assert_eq!(Gray2::from(Rgb101010::from(color)), color);
+        }
+
+        for luma in 0..=Gray4::MAX_LUMA {
+            let color = Gray4::new(luma);
+            assert_eq!(Gray4::from(Rgb101010::from(color)), color);
+        }
+
+        for luma in 0..=Gray8::MAX_LUMA {
+            let color = Gray8::new(luma);
+            assert_eq!(Gray8::from(Rgb101010::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn gray16_rgb101010_conversions() {
+        assert_eq!(Rgb101010::from(Gray16::BLACK), Rgb101010::BLACK);
+        assert_eq!(Rgb101010::from(Gray16::WHITE), Rgb101010::WHITE);
+        assert_eq!(Gray16::from(Rgb101010::BLACK), Gray16::BLACK);
+        assert_eq!(Gray16::from(Rgb101010::WHITE), Gray16::WHITE);
+        assert_eq!(
+            Gray16::from(Rgb101010::new(1023, 0, 0)),
+            Gray16::new(77 * 0x101)
+        );
+
+        for luma in 0..=255 {
+            let color = Gray16::new(luma * 0x101);
+            assert_eq!(Gray16::from(Rgb101010::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn wide_const_conversions_in_const_context() {
+        const COLOR: Rgb101010 = Rgb101010::from_rgb888(Rgb888::new(255, 128, 0));
+        const GRAY: Gray16 = Gray16::from_rgb888(COLOR.to_rgb888());
+
+        assert_eq!(COLOR, Rgb101010::from(Rgb888::new(255, 128, 0)));
+        assert_eq!(GRAY, Gray16::from(COLOR));
+        assert_eq!(GRAY.to_rgb888(), Rgb888::from(GRAY));
+    }
+
+    #[test]
+    fn wide_const_conversions_match_from() {
+        for color in Gray16::test_colors() {
+            assert_eq!(color.to_rgb101010(), Rgb101010::from(color), "{:?}", color);
+        }
+
+        for color in Rgb101010::test_colors() {
+            assert_eq!(
+                Gray16::from_rgb101010(color),
+                Gray16::from(color),
+                "{:?}",
+                color
+            );
+        }
+
+        const GRAY: Gray16 = Gray16::new(0x1234);
+        const COLOR: Rgb101010 = GRAY.to_rgb101010();
+
+        assert_eq!(COLOR, Rgb101010::new(73, 73, 73));
+        assert_ne!(COLOR, Rgb101010::from_rgb888(GRAY.to_rgb888()));
+    }
+
+    #[test]
+    fn gray16_conversions() {
+        assert_eq!(Gray16::from(Gray8::WHITE), Gray16::WHITE);
+        assert_eq!(Gray16::from(Gray1::WHITE), Gray16::WHITE);
+        assert_eq!(Gray16::from(Gray8::new(0x12)), Gray16::new(0x1212));
+        assert_eq!(Gray8::from(Gray16::new(0x1300)), Gray8::new(0x13));
+        assert_eq!(Gray2::from(Gray16::new(0x5555)), Gray2::new(1));
+
+        for luma in 0..=255 {
+            assert_eq!(
+                Gray8::from(Gray16::from(Gray8::new(luma))),
+                Gray8::new(luma)
+            );
+        }
+
+        assert_eq!(Gray16::from(Rgb888::WHITE), Gray16::WHITE);
+        assert_eq!(Rgb565::from(Gray16::BLACK), Rgb565::BLACK);
+
+        assert_eq!(Gray16::from(BinaryColor::On), Gray16::WHITE);
+        assert_eq!(BinaryColor::from(Gray16::new(0x7FFF)), BinaryColor::Off);
+        assert_eq!(BinaryColor::from(Gray16::new(0x8000)), BinaryColor::On);
     }
 
     #[test]
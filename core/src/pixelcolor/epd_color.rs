@@ -0,0 +1,295 @@
+//! Color types for multi-color e-paper displays.
+
+use crate::pixelcolor::{
+    raw::{RawData, RawU2, RawU4},
+    Bgr332, Bgr444, Bgr555, Bgr565, Bgr666, Bgr888, BinaryColor, PixelColor, Rgb332, Rgb444,
+    Rgb555, Rgb565, Rgb666, Rgb888, Xbgr8888, Xrgb8888,
+};
+
+/// Macro to implement the common traits and methods for e-paper color types.
+macro_rules! impl_epd_color {
+    ($type:ident, $raw_type:ident, $default:ident, [$($variant:ident => ($r:expr, $g:expr, $b:expr)),+ $(,)?]) => {
+        impl $type {
+            /// All colors supported by this type, in the order of their raw values.
+            pub const COLORS: [Self; [$(Self::$variant),+].len()] = [$(Self::$variant),+];
+
+            /// Returns the RGB value that is used to represent this color.
+            pub const fn to_rgb888(self) -> Rgb888 {
+                match self {
+                    $(Self::$variant => Rgb888::new($r, $g, $b),)+
+                }
+            }
+
+            /// Returns the color that is the closest match for an RGB color.
+            ///
+            /// The closest color is determined by using a weighted euclidean distance in the RGB
+            /// color space.
+            pub const fn from_rgb888(color: Rgb888) -> Self {
+                let mut closest = Self::COLORS[0];
+                let mut closest_distance = u32::MAX;
+
+                let mut i = 0;
+                while i < Self::COLORS.len() {
+                    let distance = color_distance(color, Self::COLORS[i].to_rgb888());
+                    if distance < closest_distance {
+                        closest = Self::COLORS[i];
+                        closest_distance = distance;
+                    }
+                    i += 1;
+                }
+
+                closest
+            }
+        }
+
+        impl PixelColor for $type {
+            type Raw = $raw_type;
+        }
+
+        impl From<$raw_type> for $type {
+            fn from(data: $raw_type) -> Self {
+                Self::COLORS
+                    .get(usize::from(data.into_inner()))
+                    .copied()
+                    .unwrap_or(Self::$default)
+            }
+        }
+
+        impl From<$type> for $raw_type {
+            fn from(color: $type) -> Self {
+                Self::new(color as u8)
+            }
+        }
+
+        impl From<$type> for Rgb888 {
+            fn from(color: $type) -> Self {
+                color.to_rgb888()
+            }
+        }
+
+        impl From<Rgb888> for $type {
+            fn from(color: Rgb888) -> Self {
+                Self::from_rgb888(color)
+            }
+        }
+
+        impl From<BinaryColor> for $type {
+            fn from(color: BinaryColor) -> Self {
+                color.map_color(Self::Black, Self::White)
+            }
+        }
+    };
+}
+
+/// Returns the weighted squared distance between two colors.
+///
+/// The channel weights approximate the different sensitivity of the human eye to the primary
+/// colors.
+const fn color_distance(a: Rgb888, b: Rgb888) -> u32 {
+    let (r1, g1, b1) = a.channels();
+    let (r2, g2, b2) = b.channels();
+
+    let dr = r1.abs_diff(r2) as u32;
+    let dg = g1.abs_diff(g2) as u32;
+    let db = b1.abs_diff(b2) as u32;
+
+    2 * dr * dr + 4 * dg * dg + 3 * db * db
+}
+
+/// Black, white and red e-paper color.
+///
+/// The colors are stored as 2 bit values. The invalid raw value `3` is interpreted as white.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{BwrColor, Rgb888};
+///
+/// assert_eq!(BwrColor::from(Rgb888::new(200, 30, 40)), BwrColor::Red);
+/// assert_eq!(BwrColor::from(Rgb888::new(40, 40, 40)), BwrColor::Black);
+/// assert_eq!(Rgb888::from(BwrColor::Red), Rgb888::new(255, 0, 0));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum BwrColor {
+    /// Black.
+    Black = 0,
+
+    /// White.
+    #[default]
+    White = 1,
+
+    /// Red.
+    Red = 2,
+}
+
+impl_epd_color!(BwrColor, RawU2, White, [
+    Black => (0, 0, 0),
+    White => (255, 255, 255),
+    Red => (255, 0, 0),
+]);
+
+/// Black, white and yellow e-paper color.
+///
+/// The colors are stored as 2 bit values. The invalid raw value `3` is interpreted as white.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{BwyColor, Rgb888};
+///
+/// assert_eq!(BwyColor::from(Rgb888::new(220, 200, 30)), BwyColor::Yellow);
+/// assert_eq!(BwyColor::from(Rgb888::new(230, 230, 230)), BwyColor::White);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum BwyColor {
+    /// Black.
+    Black = 0,
+
+    /// White.
+    #[default]
+    White = 1,
+
+    /// Yellow.
+    Yellow = 2,
+}
+
+impl_epd_color!(BwyColor, RawU2, White, [
+    Black => (0, 0, 0),
+    White => (255, 255, 255),
+    Yellow => (255, 255, 0),
+]);
+
+/// 7-color ACeP (Advanced Color ePaper) color.
+///
+/// The colors are stored as 4 bit values, using the encoding that is used by common 7-color e-paper
+/// controllers. Invalid raw values are interpreted as white.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{AcepColor, Rgb888};
+///
+/// assert_eq!(AcepColor::from(Rgb888::new(250, 140, 20)), AcepColor::Orange);
+/// assert_eq!(AcepColor::from(Rgb888::new(20, 40, 180)), AcepColor::Blue);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum AcepColor {
+    /// Black.
+    Black = 0,
+
+    /// White.
+    #[default]
+    White = 1,
+
+    /// Green.
+    Green = 2,
+
+    /// Blue.
+    Blue = 3,
+
+    /// Red.
+    Red = 4,
+
+    /// Yellow.
+    Yellow = 5,
+
+    /// Orange.
+    Orange = 6,
+}
+
+impl_epd_color!(AcepColor, RawU4, White, [
+    Black => (0, 0, 0),
+    White => (255, 255, 255),
+    Green => (0, 255, 0),
+    Blue => (0, 0, 255),
+    Red => (255, 0, 0),
+    Yellow => (255, 255, 0),
+    Orange => (255, 128, 0),
+]);
+
+/// Macro to implement nearest color conversions from RGB color types.
+macro_rules! impl_from_rgb {
+    ($($rgb_type:ident),+ => $epd_type:ident) => {
+        $(impl From<$rgb_type> for $epd_type {
+            fn from(color: $rgb_type) -> Self {
+                Self::from_rgb888(Rgb888::from(color))
+            }
+        })+
+    };
+}
+
+impl_from_rgb!(Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888, Xrgb8888, Xbgr8888 => BwrColor);
+impl_from_rgb!(Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888, Xrgb8888, Xbgr8888 => BwyColor);
+impl_from_rgb!(Rgb332, Bgr332, Rgb444, Bgr444, Rgb555, Bgr555, Rgb565, Bgr565, Rgb666, Bgr666, Bgr888, Xrgb8888, Xbgr8888 => AcepColor);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::RgbColor;
+
+    #[test]
+    fn raw_data() {
+        for (i, color) in BwrColor::COLORS.into_iter().enumerate() {
+            assert_eq!(RawU2::from(color), RawU2::new(i as u8));
+            assert_eq!(BwrColor::from(RawU2::new(i as u8)), color);
+        }
+        assert_eq!(BwrColor::from(RawU2::new(3)), BwrColor::White);
+
+        for (i, color) in BwyColor::COLORS.into_iter().enumerate() {
+            assert_eq!(BwyColor::from(RawU2::from(color)), color);
+            assert_eq!(RawU2::from(color), RawU2::new(i as u8));
+        }
+
+        for (i, color) in AcepColor::COLORS.into_iter().enumerate() {
+            assert_eq!(RawU4::from(color), RawU4::new(i as u8));
+            assert_eq!(AcepColor::from(RawU4::new(i as u8)), color);
+        }
+        assert_eq!(AcepColor::from(RawU4::new(7)), AcepColor::White);
+        assert_eq!(AcepColor::from(RawU4::new(15)), AcepColor::White);
+    }
+
+    #[test]
+    fn palette_colors_map_to_themselves() {
+        for color in BwrColor::COLORS {
+            assert_eq!(BwrColor::from(color.to_rgb888()), color);
+        }
+        for color in BwyColor::COLORS {
+            assert_eq!(BwyColor::from(color.to_rgb888()), color);
+        }
+        for color in AcepColor::COLORS {
+            assert_eq!(AcepColor::from(color.to_rgb888()), color);
+        }
+    }
+
+    #[test]
+    fn nearest_color() {
+        assert_eq!(BwrColor::from(Rgb888::new(255, 200, 200)), BwrColor::White);
+        assert_eq!(BwrColor::from(Rgb888::new(140, 0, 0)), BwrColor::Red);
+        assert_eq!(BwrColor::from(Rgb888::new(60, 0, 0)), BwrColor::Black);
+        assert_eq!(BwrColor::from(Rgb565::BLUE), BwrColor::Black);
+
+        assert_eq!(BwyColor::from(Rgb565::YELLOW), BwyColor::Yellow);
+        assert_eq!(BwyColor::from(Rgb565::RED), BwyColor::Black);
+
+        assert_eq!(AcepColor::from(Rgb888::new(255, 100, 0)), AcepColor::Orange);
+        assert_eq!(AcepColor::from(Rgb565::CYAN), AcepColor::White);
+        assert_eq!(AcepColor::from(Bgr888::new(10, 20, 200)), AcepColor::Blue);
+    }
+
+    #[test]
+    fn from_binary_color() {
+        assert_eq!(BwrColor::from(BinaryColor::Off), BwrColor::Black);
+        assert_eq!(BwyColor::from(BinaryColor::On), BwyColor::White);
+        assert_eq!(AcepColor::from(BinaryColor::On), AcepColor::White);
+    }
+
+    #[test]
+    fn const_conversion() {
+        const COLOR: AcepColor = AcepColor::from_rgb888(Rgb888::new(0, 200, 0));
+
+        assert_eq!(COLOR, AcepColor::Green);
+    }
+}
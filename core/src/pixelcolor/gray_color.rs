@@ -1,5 +1,5 @@
 use crate::pixelcolor::{
-    raw::{RawData, RawU2, RawU4, RawU8},
+    raw::{RawData, RawU1, RawU16, RawU2, RawU4, RawU8},
     PixelColor,
 };
 
@@ -34,6 +34,11 @@ macro_rules! gray_color {
             pub const fn new(luma: u8) -> Self {
                 Self($raw_type::new(luma))
             }
+
+            /// Returns the luma channel value in a `const` context.
+            pub(crate) const fn luma_const(self) -> u8 {
+                self.0.into_inner_const()
+            }
         }
 
         impl PixelColor for $type {
@@ -63,10 +68,62 @@ macro_rules! gray_color {
     };
 }
 
+gray_color!(Gray1, RawU1, "1 bit");
 gray_color!(Gray2, RawU2, "2 bit");
 gray_color!(Gray4, RawU4, "4 bit");
 gray_color!(Gray8, RawU8, "8 bit");
 
+/// 16 bit grayscale color.
+///
+/// The luma value doesn't fit into an `u8` and this type therefore doesn't implement the
+/// [`GrayColor`] trait. The luma value can be accessed by using the [`luma`](Gray16::luma) method
+/// and conversions to and from all other built-in color types are provided by `From`
+/// implementations. The [`to_rgb888`](Gray16::to_rgb888) and
+/// [`from_rgb888`](Gray16::from_rgb888) methods provide the same conversions in `const` contexts
+/// and [`to_rgb101010`](Gray16::to_rgb101010) and [`from_rgb101010`](Gray16::from_rgb101010)
+/// convert to and from [`Rgb101010`](super::Rgb101010) without reducing the precision to 8 bits.
+///
+/// The `lerp` method and the [`ColorMath`](super::ColorMath) trait are only implemented for color
+/// types with 8 bit channels and are therefore not available for `Gray16`. Convert the color to
+/// [`Gray8`] first if these operations are required.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Gray16(RawU16);
+
+impl Gray16 {
+    /// Black color (0% luma).
+    pub const BLACK: Self = Self::new(0);
+
+    /// White color (100% luma).
+    pub const WHITE: Self = Self::new(0xFFFF);
+
+    /// Creates a new grayscale color.
+    pub const fn new(luma: u16) -> Self {
+        Self(RawU16::new(luma))
+    }
+
+    /// Returns the luma channel value.
+    pub const fn luma(self) -> u16 {
+        self.0.into_inner_const()
+    }
+}
+
+impl PixelColor for Gray16 {
+    type Raw = RawU16;
+}
+
+impl From<RawU16> for Gray16 {
+    fn from(data: RawU16) -> Self {
+        Self(data)
+    }
+}
+
+impl From<Gray16> for RawU16 {
+    fn from(color: Gray16) -> Self {
+        color.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +131,7 @@ mod tests {
 
     #[test]
     pub fn new_masks_luma() {
+        assert_eq!(Gray1::new(255).luma(), 0x1);
         assert_eq!(Gray2::new(255).luma(), 0x3);
         assert_eq!(Gray4::new(255).luma(), 0xF);
         assert_eq!(Gray8::new(255).luma(), 0xFF);
@@ -108,6 +166,14 @@ mod tests {
         assert_eq!(RawU8::from(Gray8::new(0x7E)), RawU8::new(0x7E));
     }
 
+    #[test]
+    fn gray16() {
+        assert_eq!(Gray16::BLACK.luma(), 0);
+        assert_eq!(Gray16::WHITE.luma(), 0xFFFF);
+        assert_eq!(Gray16::from(RawU16::new(0x1234)), Gray16::new(0x1234));
+        assert_eq!(RawU16::from(Gray16::new(0xABCD)), RawU16::new(0xABCD));
+    }
+
     #[test]
     fn convert_to_raw() {
         let color = Gray8::new(0xAA);
@@ -8,7 +8,9 @@
 //!
 //! Custom color types can be added by implementing the [`PixelColor`] trait.
 //! The following example shows how to implement a new color type for a
-//! 3 color EPD display.
+//! 3 color EPD display. Note that color types for common multi-color EPD
+//! displays are already included in this module: [`BwrColor`], [`BwyColor`]
+//! and [`AcepColor`].
 //!
 //! ```
 //! use embedded_graphics::{
@@ -107,15 +109,23 @@
 //! [`raw` module]: raw
 
 mod binary_color;
+mod color_math;
+mod color_space;
 mod conversion;
+mod epd_color;
 mod gray_color;
+mod quantizer;
 pub mod raw;
 mod rgb_color;
 mod transparent_color;
 mod web_colors;
 
 pub use binary_color::*;
+pub use color_math::ColorMath;
+pub use color_space::{Hsl, Hsv, LumaWeights};
+pub use epd_color::*;
 pub use gray_color::*;
+pub use quantizer::{ColorDistance, Quantizer};
 use raw::RawData;
 pub use rgb_color::*;
 pub use transparent_color::*;
@@ -0,0 +1,295 @@
+//! Nearest palette color quantization.
+
+use crate::pixelcolor::{PixelColor, Rgb888};
+
+/// Color distance metric.
+///
+/// The distance metric is used by [`Quantizer`] to find the palette color that is the closest
+/// match for a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum ColorDistance {
+    /// Squared euclidean distance in the RGB color space.
+    Rgb,
+
+    /// Perceptually weighted distance.
+    ///
+    /// This metric uses the "redmean" approximation, which weights the channel differences
+    /// depending on the mean red channel value of both colors. It is a better approximation of the
+    /// perceived color difference than [`Rgb`](ColorDistance::Rgb), while still being cheap to
+    /// calculate.
+    #[default]
+    Perceptual,
+}
+
+impl ColorDistance {
+    /// Returns the distance between two colors.
+    ///
+    /// The returned value can only be used to compare distances that were calculated with the same
+    /// metric.
+    pub const fn distance(self, a: Rgb888, b: Rgb888) -> u32 {
+        let (r1, g1, b1) = a.channels();
+        let (r2, g2, b2) = b.channels();
+
+        let dr = r1.abs_diff(r2) as u32;
+        let dg = g1.abs_diff(g2) as u32;
+        let db = b1.abs_diff(b2) as u32;
+
+        match self {
+            Self::Rgb => dr * dr + dg * dg + db * db,
+            Self::Perceptual => {
+                let mean_r = (r1 as u32 + r2 as u32) / 2;
+
+                (((512 + mean_r) * dr * dr) >> 8) + 4 * dg * dg + (((767 - mean_r) * db * db) >> 8)
+            }
+        }
+    }
+}
+
+/// Nearest palette color quantizer.
+///
+/// A quantizer maps colors to the closest matching color in a fixed palette. This can be used to
+/// draw full color images or primitives on displays which only support a small number of colors,
+/// like 8 color LCDs or multi-color e-paper displays. The palette colors can be of any color type
+/// that can be converted into [`Rgb888`].
+///
+/// Searching the closest palette color requires comparing the color to all palette entries. To
+/// speed up repeated conversions of the same colors the quantizer can optionally cache the
+/// results of previous conversions. The cache size is set by the `CACHE_SIZE` parameter, which
+/// is `0` by default to disable caching.
+///
+/// Use [`quantized`] to draw to a draw target using a quantizer.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::{BwrColor, ColorDistance, Quantizer, Rgb565, Rgb888};
+///
+/// const PALETTE: [Rgb565; 4] = [
+///     Rgb565::new(0, 0, 0),
+///     Rgb565::new(31, 0, 0),
+///     Rgb565::new(31, 63, 0),
+///     Rgb565::new(31, 63, 31),
+/// ];
+///
+/// let quantizer = Quantizer::new(&PALETTE);
+/// assert_eq!(quantizer.nearest(Rgb888::new(200, 160, 20)), PALETTE[2]);
+///
+/// // Quantizer with plain RGB distance and a cache with 32 entries.
+/// let mut quantizer = Quantizer::new(&BwrColor::COLORS)
+///     .with_distance(ColorDistance::Rgb)
+///     .with_cache::<32>();
+/// assert_eq!(quantizer.quantize(Rgb565::new(25, 5, 3)), BwrColor::Red);
+/// ```
+///
+/// [`quantized`]: https://docs.rs/embedded-graphics/latest/embedded_graphics/draw_target/trait.DrawTargetExt.html#tymethod.quantized
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quantizer<'a, C, const CACHE_SIZE: usize = 0> {
+    palette: &'a [C],
+    distance: ColorDistance,
+    cache: [Option<(Rgb888, C)>; CACHE_SIZE],
+}
+
+impl<'a, C> Quantizer<'a, C>
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    /// Creates a new quantizer.
+    ///
+    /// The quantizer uses [`ColorDistance::Perceptual`] and doesn't cache results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the palette is empty.
+    pub const fn new(palette: &'a [C]) -> Self {
+        assert!(!palette.is_empty(), "palette must not be empty");
+
+        Self {
+            palette,
+            distance: ColorDistance::Perceptual,
+            cache: [],
+        }
+    }
+}
+
+impl<'a, C, const CACHE_SIZE: usize> Quantizer<'a, C, CACHE_SIZE>
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    /// Sets the color distance metric.
+    pub fn with_distance(self, distance: ColorDistance) -> Self {
+        Self { distance, ..self }
+    }
+
+    /// Sets the number of cache entries.
+    ///
+    /// The cache is direct mapped, which means that each color can only be stored in one cache
+    /// entry and colors which map to the same entry replace each other.
+    pub fn with_cache<const N: usize>(self) -> Quantizer<'a, C, N> {
+        Quantizer {
+            palette: self.palette,
+            distance: self.distance,
+            cache: [None; N],
+        }
+    }
+
+    /// Returns the palette.
+    pub const fn palette(&self) -> &'a [C] {
+        self.palette
+    }
+
+    /// Returns the color distance metric.
+    pub const fn distance(&self) -> ColorDistance {
+        self.distance
+    }
+
+    /// Returns the palette color that is the closest match for a color.
+    ///
+    /// This method doesn't use the cache. If multiple palette colors have the same distance the
+    /// first matching color in the palette is returned.
+    pub fn nearest<I>(&self, color: I) -> C
+    where
+        Rgb888: From<I>,
+    {
+        let color = Rgb888::from(color);
+
+        let mut nearest = self.palette[0];
+        let mut nearest_distance = u32::MAX;
+
+        for palette_color in self.palette.iter().copied() {
+            let distance = self.distance.distance(color, palette_color.into());
+            if distance < nearest_distance {
+                nearest = palette_color;
+                nearest_distance = distance;
+
+                if distance == 0 {
+                    break;
+                }
+            }
+        }
+
+        nearest
+    }
+
+    /// Returns the palette color that is the closest match for a color, using the cache.
+    ///
+    /// The result is the same as the result of [`nearest`](Self::nearest).
+    pub fn quantize<I>(&mut self, color: I) -> C
+    where
+        Rgb888: From<I>,
+    {
+        let color = Rgb888::from(color);
+
+        if CACHE_SIZE == 0 {
+            return self.nearest::<Rgb888>(color);
+        }
+
+        let (r, g, b) = color.channels();
+        let hash = (r as usize)
+            .wrapping_mul(31)
+            .wrapping_add(g as usize)
+            .wrapping_mul(31)
+            .wrapping_add(b as usize);
+        let index = hash % CACHE_SIZE;
+
+        match self.cache[index] {
+            Some((cached_color, result)) if cached_color == color => result,
+            _ => {
+                let result = self.nearest::<Rgb888>(color);
+                self.cache[index] = Some((color, result));
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixelcolor::{BwrColor, Gray8, Rgb565, RgbColor};
+
+    const PALETTE: [Rgb888; 8] = [
+        Rgb888::BLACK,
+        Rgb888::RED,
+        Rgb888::GREEN,
+        Rgb888::BLUE,
+        Rgb888::YELLOW,
+        Rgb888::MAGENTA,
+        Rgb888::CYAN,
+        Rgb888::WHITE,
+    ];
+
+    #[test]
+    fn distance() {
+        let a = Rgb888::new(10, 20, 30);
+        let b = Rgb888::new(13, 24, 30);
+
+        assert_eq!(ColorDistance::Rgb.distance(a, b), 25);
+        assert_eq!(ColorDistance::Rgb.distance(a, a), 0);
+        assert_eq!(ColorDistance::Perceptual.distance(a, a), 0);
+        assert_eq!(
+            ColorDistance::Perceptual.distance(Rgb888::BLACK, Rgb888::WHITE),
+            ColorDistance::Perceptual.distance(Rgb888::WHITE, Rgb888::BLACK)
+        );
+    }
+
+    #[test]
+    fn palette_colors_are_unchanged() {
+        for distance in [ColorDistance::Rgb, ColorDistance::Perceptual] {
+            let quantizer = Quantizer::new(&PALETTE).with_distance(distance);
+
+            for color in PALETTE {
+                assert_eq!(quantizer.nearest(color), color);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest() {
+        let quantizer = Quantizer::new(&PALETTE);
+
+        assert_eq!(quantizer.nearest(Rgb888::new(200, 30, 20)), Rgb888::RED);
+        assert_eq!(quantizer.nearest(Rgb565::new(2, 60, 29)), Rgb888::CYAN);
+        assert_eq!(quantizer.nearest(Gray8::new(100)), Rgb888::BLACK);
+        assert_eq!(quantizer.nearest(Gray8::new(160)), Rgb888::WHITE);
+    }
+
+    #[test]
+    fn distance_metric_changes_result() {
+        const PALETTE: [Rgb888; 2] = [Rgb888::RED, Rgb888::BLUE];
+
+        let color = Rgb888::new(0, 0, 16);
+
+        let rgb = Quantizer::new(&PALETTE).with_distance(ColorDistance::Rgb);
+        let perceptual = Quantizer::new(&PALETTE).with_distance(ColorDistance::Perceptual);
+
+        assert_eq!(rgb.nearest(color), Rgb888::BLUE);
+        assert_eq!(perceptual.nearest(color), Rgb888::RED);
+    }
+
+    #[test]
+    fn cache() {
+        let mut quantizer = Quantizer::new(&BwrColor::COLORS).with_cache::<7>();
+        let uncached = Quantizer::new(&BwrColor::COLORS);
+
+        for _ in 0..2 {
+            for value in (0..=255).step_by(5) {
+                for color in [
+                    Rgb888::new(value, 0, 0),
+                    Rgb888::new(value, value, value),
+                    Rgb888::new(255 - value, value / 2, value),
+                ] {
+                    assert_eq!(quantizer.quantize(color), uncached.nearest(color));
+                }
+            }
+        }
+
+        assert!(quantizer.cache.iter().all(Option::is_some));
+    }
+
+    #[test]
+    #[should_panic(expected = "palette must not be empty")]
+    fn empty_palette() {
+        Quantizer::<Rgb888>::new(&[]);
+    }
+}
@@ -192,6 +192,12 @@ macro_rules! impl_raw_data {
             pub(crate) const fn new_unmasked(value: $storage_type) -> Self {
                 Self(value)
             }
+
+            #[inline]
+            #[allow(unused)]
+            pub(crate) const fn into_inner_const(self) -> $storage_type {
+                self.0
+            }
         }
 
         impl RawData for $type {
@@ -312,9 +318,9 @@ mod tests {
 
     #[test]
     fn upper_bits_are_masked() {
-        assert_eq!(RawU1::new(u8::max_value()).0, 0x1);
-        assert_eq!(RawU2::new(u8::max_value()).0, 0x3);
-        assert_eq!(RawU4::new(u8::max_value()).0, 0xF);
-        assert_eq!(RawU24::new(u32::max_value()).0, 0xFFFFFF);
+        assert_eq!(RawU1::new(u8::MAX).0, 0x1);
+        assert_eq!(RawU2::new(u8::MAX).0, 0x3);
+        assert_eq!(RawU4::new(u8::MAX).0, 0xF);
+        assert_eq!(RawU24::new(u32::MAX).0, 0xFFFFFF);
     }
 }
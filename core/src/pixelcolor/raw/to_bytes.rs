@@ -110,6 +110,7 @@ impl<C: PixelColor> ToBytes for C {
 }
 
 #[cfg(test)]
+#[allow(clippy::unusual_byte_groupings)]
 mod tests {
     use super::*;
     use crate::pixelcolor::{
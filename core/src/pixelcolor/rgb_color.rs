@@ -1,5 +1,5 @@
 use crate::pixelcolor::{
-    raw::{RawData, RawU16, RawU24, RawU8},
+    raw::{RawData, RawU16, RawU24, RawU32, RawU8},
     PixelColor,
 };
 use core::fmt;
@@ -123,6 +123,16 @@ macro_rules! impl_rgb_color {
 
                 Self(r_shifted | g_shifted | b_shifted)
             }
+
+            /// Returns the red, green and blue channel values in a `const` context.
+            #[allow(trivial_numeric_casts)]
+            pub(crate) const fn channels(self) -> (u8, u8, u8) {
+                (
+                    (self.0 >> $r_pos) as u8 & Self::MAX_R,
+                    (self.0 >> $g_pos) as u8 & Self::MAX_G,
+                    (self.0 >> $b_pos) as u8 & Self::MAX_B,
+                )
+            }
         }
 
         impl RgbColor for $type {
@@ -230,8 +240,10 @@ macro_rules! rgb_color {
 }
 
 rgb_color!(Rgb332, RawU8, u8, Rgb = (3, 3, 2));
+rgb_color!(Bgr332, RawU8, u8, Bgr = (3, 3, 2));
 
 rgb_color!(Rgb444, RawU16, u16, Rgb = (4, 4, 4));
+rgb_color!(Bgr444, RawU16, u16, Bgr = (4, 4, 4));
 
 rgb_color!(Rgb555, RawU16, u16, Rgb = (5, 5, 5));
 rgb_color!(Bgr555, RawU16, u16, Bgr = (5, 5, 5));
@@ -243,7 +255,118 @@ rgb_color!(Bgr666, RawU24, u32, Bgr = (6, 6, 6));
 rgb_color!(Rgb888, RawU24, u32, Rgb = (8, 8, 8));
 rgb_color!(Bgr888, RawU24, u32, Bgr = (8, 8, 8));
 
+rgb_color!(Xrgb8888, RawU32, u32, Rgb = (8, 8, 8));
+rgb_color!(Xbgr8888, RawU32, u32, Bgr = (8, 8, 8));
+
+/// Rgb101010 color.
+///
+/// `Rgb101010` uses 10 bits per channel, which are stored in the 30 least significant bits of a
+/// 32 bit value. The two most significant bits are unused.
+///
+/// The channel values don't fit into an `u8` and this type therefore doesn't implement the
+/// [`RgbColor`] trait. The channel values can be accessed by using the [`r`], [`g`] and [`b`]
+/// methods and conversions to and from all other built-in color types are provided by `From`
+/// implementations. The [`to_rgb888`] and [`from_rgb888`] methods provide the same conversions in
+/// `const` contexts. Conversions to and from [`Gray16`] are available as `const` methods of
+/// `Gray16`, which don't reduce the precision to 8 bits.
+///
+/// The HSV, HSL and `lerp` methods and the [`ColorMath`] trait are only implemented for color types
+/// with 8 bit channels and are therefore not available for `Rgb101010`. Convert the color to
+/// [`Rgb888`] first if these operations are required.
+///
+/// [`ColorMath`]: super::ColorMath
+/// [`Gray16`]: super::Gray16
+/// [`r`]: Rgb101010::r
+/// [`g`]: Rgb101010::g
+/// [`b`]: Rgb101010::b
+/// [`to_rgb888`]: Rgb101010::to_rgb888
+/// [`from_rgb888`]: Rgb101010::from_rgb888
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Rgb101010(u32);
+
+impl Rgb101010 {
+    /// The maximum value of a channel.
+    pub const MAX: u16 = 0x3FF;
+
+    /// Black color (R: 0%, G: 0%, B: 0%)
+    pub const BLACK: Self = Self::new(0, 0, 0);
+
+    /// White color (R: 100%, G: 100%, B: 100%)
+    pub const WHITE: Self = Self::new(Self::MAX, Self::MAX, Self::MAX);
+
+    const RGB_MASK: u32 = 0x3FFF_FFFF;
+
+    /// Creates a new Rgb101010 color.
+    ///
+    /// Too large channel values will be limited by setting the unused most significant bits to
+    /// zero.
+    pub const fn new(r: u16, g: u16, b: u16) -> Self {
+        let r = (r & Self::MAX) as u32;
+        let g = (g & Self::MAX) as u32;
+        let b = (b & Self::MAX) as u32;
+
+        Self(r << 20 | g << 10 | b)
+    }
+
+    /// Returns the red channel value.
+    pub const fn r(self) -> u16 {
+        (self.0 >> 20) as u16 & Self::MAX
+    }
+
+    /// Returns the green channel value.
+    pub const fn g(self) -> u16 {
+        (self.0 >> 10) as u16 & Self::MAX
+    }
+
+    /// Returns the blue channel value.
+    pub const fn b(self) -> u16 {
+        self.0 as u16 & Self::MAX
+    }
+}
+
+impl fmt::Debug for Rgb101010 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Rgb101010(r: {}, g: {}, b: {})",
+            self.r(),
+            self.g(),
+            self.b()
+        )
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl ::defmt::Format for Rgb101010 {
+    fn format(&self, f: ::defmt::Formatter) {
+        ::defmt::write!(
+            f,
+            "Rgb101010(r: {=u16}, g: {=u16}, b: {=u16})",
+            self.r(),
+            self.g(),
+            self.b()
+        )
+    }
+}
+
+impl PixelColor for Rgb101010 {
+    type Raw = RawU32;
+}
+
+impl From<RawU32> for Rgb101010 {
+    fn from(data: RawU32) -> Self {
+        Self(data.into_inner() & Self::RGB_MASK)
+    }
+}
+
+impl From<Rgb101010> for RawU32 {
+    fn from(color: Rgb101010) -> Self {
+        Self::new(color.0)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::identity_op)]
 mod tests {
     use super::*;
     use crate::pixelcolor::IntoStorage;
@@ -283,21 +406,80 @@ mod tests {
 
     #[test]
     pub fn bit_positions_rgb332() {
-        test_bpp8(Rgb332::new(0b101, 0, 0), 0b101 << 2 + 3);
+        test_bpp8(Rgb332::new(0b101, 0, 0), 0b101 << (2 + 3));
         test_bpp8(Rgb332::new(0, 0b101, 0), 0b101 << 2);
         test_bpp8(Rgb332::new(0, 0, 0b11), 0b11 << 0);
     }
 
+    /// Convert color to integer and back again to test bit positions
+    fn test_bpp32<C>(color: C, value: u32)
+    where
+        C: PixelColor<Raw = RawU32> + fmt::Debug,
+    {
+        let value = RawU32::new(value);
+
+        assert_eq!(color.into(), value);
+        assert_eq!(C::from(value), color);
+    }
+
+    #[test]
+    pub fn bit_positions_bgr332() {
+        test_bpp8(Bgr332::new(0b101, 0, 0), 0b101 << 0);
+        test_bpp8(Bgr332::new(0, 0b101, 0), 0b101 << 3);
+        test_bpp8(Bgr332::new(0, 0, 0b11), 0b11 << (3 + 3));
+    }
+
+    #[test]
+    pub fn bit_positions_bgr444() {
+        test_bpp16(Bgr444::new(0b1001, 0, 0), 0b1001 << 0);
+        test_bpp16(Bgr444::new(0, 0b1001, 0), 0b1001 << 4);
+        test_bpp16(Bgr444::new(0, 0, 0b1001), 0b1001 << (4 + 4));
+    }
+
+    #[test]
+    pub fn bit_positions_xrgb8888() {
+        test_bpp32(Xrgb8888::new(0b10000001, 0, 0), 0b10000001 << (8 + 8));
+        test_bpp32(Xrgb8888::new(0, 0b10000001, 0), 0b10000001 << 8);
+        test_bpp32(Xrgb8888::new(0, 0, 0b10000001), 0b10000001 << 0);
+    }
+
+    #[test]
+    pub fn bit_positions_xbgr8888() {
+        test_bpp32(Xbgr8888::new(0b10000001, 0, 0), 0b10000001 << 0);
+        test_bpp32(Xbgr8888::new(0, 0b10000001, 0), 0b10000001 << 8);
+        test_bpp32(Xbgr8888::new(0, 0, 0b10000001), 0b10000001 << (8 + 8));
+    }
+
+    #[test]
+    pub fn bit_positions_rgb101010() {
+        test_bpp32(
+            Rgb101010::new(0b1000000001, 0, 0),
+            0b1000000001 << (10 + 10),
+        );
+        test_bpp32(Rgb101010::new(0, 0b1000000001, 0), 0b1000000001 << 10);
+        test_bpp32(Rgb101010::new(0, 0, 0b1000000001), 0b1000000001 << 0);
+    }
+
+    #[test]
+    pub fn rgb101010_channels() {
+        let color = Rgb101010::new(0x3FF, 0x155, 0xFFFF);
+
+        assert_eq!(color.r(), 0x3FF);
+        assert_eq!(color.g(), 0x155);
+        assert_eq!(color.b(), 0x3FF);
+        assert_eq!(Rgb101010::WHITE, Rgb101010::new(1023, 1023, 1023));
+    }
+
     #[test]
     pub fn bit_positions_rgb444() {
-        test_bpp16(Rgb444::new(0b1001, 0, 0), 0b1001 << 4 + 4);
+        test_bpp16(Rgb444::new(0b1001, 0, 0), 0b1001 << (4 + 4));
         test_bpp16(Rgb444::new(0, 0b1001, 0), 0b1001 << 4);
         test_bpp16(Rgb444::new(0, 0, 0b1001), 0b1001 << 0);
     }
 
     #[test]
     pub fn bit_positions_rgb555() {
-        test_bpp16(Rgb555::new(0b10001, 0, 0), 0b10001 << 5 + 5);
+        test_bpp16(Rgb555::new(0b10001, 0, 0), 0b10001 << (5 + 5));
         test_bpp16(Rgb555::new(0, 0b10001, 0), 0b10001 << 5);
         test_bpp16(Rgb555::new(0, 0, 0b10001), 0b10001 << 0);
     }
@@ -306,12 +488,12 @@ mod tests {
     pub fn bit_positions_bgr555() {
         test_bpp16(Bgr555::new(0b10001, 0, 0), 0b10001 << 0);
         test_bpp16(Bgr555::new(0, 0b10001, 0), 0b10001 << 5);
-        test_bpp16(Bgr555::new(0, 0, 0b10001), 0b10001 << 5 + 5);
+        test_bpp16(Bgr555::new(0, 0, 0b10001), 0b10001 << (5 + 5));
     }
 
     #[test]
     pub fn bit_positions_rgb565() {
-        test_bpp16(Rgb565::new(0b10001, 0, 0), 0b10001 << 5 + 6);
+        test_bpp16(Rgb565::new(0b10001, 0, 0), 0b10001 << (5 + 6));
         test_bpp16(Rgb565::new(0, 0b100001, 0), 0b100001 << 5);
         test_bpp16(Rgb565::new(0, 0, 0b10001), 0b10001 << 0);
     }
@@ -320,12 +502,12 @@ mod tests {
     pub fn bit_positions_bgr565() {
         test_bpp16(Bgr565::new(0b10001, 0, 0), 0b10001 << 0);
         test_bpp16(Bgr565::new(0, 0b100001, 0), 0b100001 << 5);
-        test_bpp16(Bgr565::new(0, 0, 0b10001), 0b10001 << 5 + 6);
+        test_bpp16(Bgr565::new(0, 0, 0b10001), 0b10001 << (5 + 6));
     }
 
     #[test]
     pub fn bit_positions_rgb666() {
-        test_bpp24(Rgb666::new(0b100001, 0, 0), 0b100001 << 6 + 6);
+        test_bpp24(Rgb666::new(0b100001, 0, 0), 0b100001 << (6 + 6));
         test_bpp24(Rgb666::new(0, 0b100001, 0), 0b100001 << 6);
         test_bpp24(Rgb666::new(0, 0, 0b100001), 0b100001 << 0);
     }
@@ -334,12 +516,12 @@ mod tests {
     pub fn bit_positions_bgr666() {
         test_bpp24(Bgr666::new(0b100001, 0, 0), 0b100001 << 0);
         test_bpp24(Bgr666::new(0, 0b100001, 0), 0b100001 << 6);
-        test_bpp24(Bgr666::new(0, 0, 0b100001), 0b100001 << 6 + 6);
+        test_bpp24(Bgr666::new(0, 0, 0b100001), 0b100001 << (6 + 6));
     }
 
     #[test]
     pub fn bit_positions_rgb888() {
-        test_bpp24(Rgb888::new(0b10000001, 0, 0), 0b10000001 << 8 + 8);
+        test_bpp24(Rgb888::new(0b10000001, 0, 0), 0b10000001 << (8 + 8));
         test_bpp24(Rgb888::new(0, 0b10000001, 0), 0b10000001 << 8);
         test_bpp24(Rgb888::new(0, 0, 0b10000001), 0b10000001 << 0);
     }
@@ -348,7 +530,7 @@ mod tests {
     pub fn bit_positions_bgr888() {
         test_bpp24(Bgr888::new(0b10000001, 0, 0), 0b10000001 << 0);
         test_bpp24(Bgr888::new(0, 0b10000001, 0), 0b10000001 << 8);
-        test_bpp24(Bgr888::new(0, 0, 0b10000001), 0b10000001 << 8 + 8);
+        test_bpp24(Bgr888::new(0, 0, 0b10000001), 0b10000001 << (8 + 8));
     }
 
     #[test]
@@ -361,6 +543,12 @@ mod tests {
 
         let color: Bgr555 = RawU16::from(0xFFFF).into();
         assert_eq!(RawU16::from(color).into_inner(), 0x7FFF);
+
+        let color: Xrgb8888 = RawU32::from(0xFFFFFFFF).into();
+        assert_eq!(RawU32::from(color).into_inner(), 0xFFFFFF);
+
+        let color: Rgb101010 = RawU32::from(0xFFFFFFFF).into();
+        assert_eq!(RawU32::from(color).into_inner(), 0x3FFFFFFF);
     }
 
     #[test]
@@ -1,7 +1,8 @@
 //! Named colors as defined in the CSS specification.
 
 use crate::pixelcolor::{
-    Bgr555, Bgr565, Bgr666, Bgr888, PixelColor, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor,
+    Bgr555, Bgr565, Bgr666, Bgr888, PixelColor, Rgb555, Rgb565, Rgb666, Rgb888, RgbColor, Xbgr8888,
+    Xrgb8888,
 };
 
 macro_rules! color_doc {
@@ -68,7 +69,7 @@ macro_rules! web_colors {
 
 #[rustfmt::skip]
 web_colors!(
-    (Rgb555, Rgb565, Rgb666, Rgb888, Bgr555, Bgr565, Bgr666, Bgr888),
+    (Rgb555, Rgb565, Rgb666, Rgb888, Bgr555, Bgr565, Bgr666, Bgr888, Xrgb8888, Xbgr8888),
     [
         (CSS_ALICE_BLUE, "Alice Blue", (240, 248, 255)),
         (CSS_ANTIQUE_WHITE, "Antique White", (250, 235, 215)),
@@ -0,0 +1,623 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::GetPixel,
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+};
+
+/// Default number of entries in the stack used by [`FloodFill::fill`].
+pub const DEFAULT_FLOOD_FILL_STACK_SIZE: usize = 64;
+
+/// Pixel connectivity used by [`FloodFill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Connectivity {
+    /// Pixels are connected to their horizontal and vertical neighbors.
+    #[default]
+    Four,
+
+    /// Pixels are connected to their horizontal, vertical and diagonal neighbors.
+    Eight,
+}
+
+/// Error returned by [`FloodFill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum FloodFillError<E> {
+    /// The draw target returned an error.
+    DrawTarget(E),
+
+    /// The stack was too small to fill the entire region.
+    ///
+    /// The region is only partially filled if this error is returned.
+    StackOverflow,
+}
+
+impl<E> From<E> for FloodFillError<E> {
+    fn from(error: E) -> Self {
+        Self::DrawTarget(error)
+    }
+}
+
+/// Entry in the stack used by [`FloodFill`].
+///
+/// Each entry stores a horizontal range of pixels which still needs to be checked. Stacks can be
+/// created by using [`Default`], e.g. `[FloodFillSpan::default(); 32]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct FloodFillSpan {
+    y: i32,
+    start_x: i32,
+    end_x: i32,
+
+    /// Direction from the parent span to this span.
+    dy: i32,
+}
+
+/// Region that is filled by [`FloodFill`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum Region<C> {
+    /// All connected pixels with the same color as the seed point.
+    Seed,
+
+    /// All connected pixels which don't have the boundary color.
+    Boundary(C),
+}
+
+/// Flood fill operation.
+///
+/// A flood fill replaces the color of a connected region of pixels, which starts at a seed point.
+/// By default, all connected pixels with the same color as the seed point are filled (seed fill).
+/// [`with_boundary`] changes the operation to fill all connected pixels up to a boundary color
+/// (boundary fill).
+///
+/// The region is filled one horizontal span at a time and the pixel colors are read back by using
+/// [`GetPixel`], which makes flood fills usable with targets like [`Framebuffer`]. No heap
+/// allocations are required: the spans which still need to be checked are stored in a stack
+/// with a fixed size. [`fill`] uses a stack with [`DEFAULT_FLOOD_FILL_STACK_SIZE`] entries and
+/// [`fill_with_stack`] uses a stack which is provided by the caller. Complex regions might require
+/// a larger stack, and [`FloodFillError::StackOverflow`] is returned if the stack wasn't large
+/// enough.
+///
+/// # Examples
+///
+/// ## Fill the inside of a circle
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::FloodFill,
+///     framebuffer::{buffer_size, Framebuffer},
+///     image::GetPixel,
+///     pixelcolor::{raw::LittleEndianMsb0, BinaryColor},
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+/// };
+///
+/// let mut fb = Framebuffer::<
+///     BinaryColor,
+///     _,
+///     LittleEndianMsb0,
+///     64,
+///     64,
+///     { buffer_size::<BinaryColor>(64, 64) },
+/// >::new();
+///
+/// Circle::new(Point::new(2, 2), 40)
+///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+///     .draw(&mut fb)?;
+///
+/// FloodFill::new(Point::new(22, 22), BinaryColor::On).fill(&mut fb)?;
+///
+/// assert_eq!(fb.pixel(Point::new(22, 22)), Some(BinaryColor::On));
+/// assert_eq!(fb.pixel(Point::new(50, 50)), Some(BinaryColor::Off));
+/// # Ok::<(), embedded_graphics::draw_target::FloodFillError<core::convert::Infallible>>(())
+/// ```
+///
+/// ## Boundary fill with a custom stack
+///
+/// ```
+/// use embedded_graphics::{
+///     draw_target::{Connectivity, FloodFill, FloodFillSpan},
+///     framebuffer::{buffer_size, Framebuffer},
+///     image::GetPixel,
+///     pixelcolor::{raw::LittleEndianMsb0, Rgb565},
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Triangle},
+/// };
+///
+/// let mut fb = Framebuffer::<
+///     Rgb565,
+///     _,
+///     LittleEndianMsb0,
+///     64,
+///     64,
+///     { buffer_size::<Rgb565>(64, 64) },
+/// >::new();
+///
+/// Triangle::new(Point::new(5, 5), Point::new(60, 10), Point::new(20, 60))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut fb)?;
+///
+/// let mut stack = [FloodFillSpan::default(); 16];
+///
+/// FloodFill::new(Point::new(20, 20), Rgb565::BLUE)
+///     .with_boundary(Rgb565::WHITE)
+///     .with_connectivity(Connectivity::Four)
+///     .fill_with_stack(&mut fb, &mut stack)?;
+///
+/// assert_eq!(fb.pixel(Point::new(20, 20)), Some(Rgb565::BLUE));
+/// # Ok::<(), embedded_graphics::draw_target::FloodFillError<core::convert::Infallible>>(())
+/// ```
+///
+/// [`with_boundary`]: FloodFill::with_boundary
+/// [`fill`]: FloodFill::fill
+/// [`fill_with_stack`]: FloodFill::fill_with_stack
+/// [`Framebuffer`]: crate::framebuffer::Framebuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct FloodFill<C> {
+    seed: Point,
+    color: C,
+    region: Region<C>,
+    connectivity: Connectivity,
+}
+
+impl<C: PixelColor> FloodFill<C> {
+    /// Creates a new seed fill, which starts at `seed` and uses `color` to fill the region.
+    pub const fn new(seed: Point, color: C) -> Self {
+        Self {
+            seed,
+            color,
+            region: Region::Seed,
+            connectivity: Connectivity::Four,
+        }
+    }
+
+    /// Fills the region up to the boundary color, instead of the region with the seed color.
+    pub const fn with_boundary(mut self, boundary: C) -> Self {
+        self.region = Region::Boundary(boundary);
+
+        self
+    }
+
+    /// Sets the pixel connectivity.
+    ///
+    /// The default connectivity is [`Connectivity::Four`].
+    pub const fn with_connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+
+        self
+    }
+
+    /// Fills the region by using a stack with [`DEFAULT_FLOOD_FILL_STACK_SIZE`] entries.
+    pub fn fill<D>(&self, target: &mut D) -> Result<(), FloodFillError<D::Error>>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        let mut stack = [FloodFillSpan::default(); DEFAULT_FLOOD_FILL_STACK_SIZE];
+
+        self.fill_with_stack(target, &mut stack)
+    }
+
+    /// Fills the region by using a stack which is provided by the caller.
+    ///
+    /// If the stack is too small the fill continues with the remaining spans and
+    /// [`FloodFillError::StackOverflow`] is returned afterwards.
+    pub fn fill_with_stack<D>(
+        &self,
+        target: &mut D,
+        stack: &mut [FloodFillSpan],
+    ) -> Result<(), FloodFillError<D::Error>>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        let Some(seed_color) = target.pixel(self.seed) else {
+            return Ok(());
+        };
+
+        // Filled pixels must be distinguishable from unfilled pixels to ensure that the fill
+        // terminates.
+        if !self.is_inside(seed_color, seed_color) || seed_color == self.color {
+            return Ok(());
+        }
+
+        let bounding_box = target.bounding_box();
+        let Some(bottom_right) = bounding_box.bottom_right() else {
+            return Ok(());
+        };
+
+        let diagonal = match self.connectivity {
+            Connectivity::Four => 0,
+            Connectivity::Eight => 1,
+        };
+
+        let mut stack = Stack::new(stack, bounding_box.top_left, bottom_right);
+
+        let (start_x, end_x) = self.fill_span(target, self.seed, seed_color)?;
+        let (start_x, end_x) = (start_x - diagonal, end_x + diagonal);
+        stack.push(self.seed.y + 1, start_x, end_x, 1);
+        stack.push(self.seed.y - 1, start_x, end_x, -1);
+
+        while let Some(span) = stack.pop() {
+            let FloodFillSpan {
+                y,
+                start_x,
+                end_x,
+                dy,
+            } = span;
+
+            let mut x = start_x;
+            while x <= end_x {
+                let point = Point::new(x, y);
+                if !target
+                    .pixel(point)
+                    .is_some_and(|color| self.is_inside(color, seed_color))
+                {
+                    x += 1;
+                    continue;
+                }
+
+                let (fill_start_x, fill_end_x) = self.fill_span(target, point, seed_color)?;
+
+                stack.push(y + dy, fill_start_x - diagonal, fill_end_x + diagonal, dy);
+
+                // The part of the previous row above or below the parent span has already been
+                // filled and only the parts that extend past it need to be checked.
+                stack.push(y - dy, fill_start_x - diagonal, start_x + diagonal - 1, -dy);
+                stack.push(y - dy, end_x - diagonal + 1, fill_end_x + diagonal, -dy);
+
+                // The pixel after the end of the span isn't inside the region.
+                x = fill_end_x + 2;
+            }
+        }
+
+        if stack.overflow {
+            Err(FloodFillError::StackOverflow)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns `true` if a pixel with the given color is part of the region.
+    fn is_inside(&self, color: C, seed_color: C) -> bool {
+        match self.region {
+            Region::Seed => color == seed_color,
+            Region::Boundary(boundary) => color != boundary && color != self.color,
+        }
+    }
+
+    /// Fills the horizontal span of the region which contains `point`.
+    ///
+    /// Returns the start and end X coordinates of the filled span.
+    fn fill_span<D>(
+        &self,
+        target: &mut D,
+        point: Point,
+        seed_color: C,
+    ) -> Result<(i32, i32), D::Error>
+    where
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        let contains = |target: &D, x: i32| {
+            target
+                .pixel(Point::new(x, point.y))
+                .is_some_and(|color| self.is_inside(color, seed_color))
+        };
+
+        let mut start_x = point.x;
+        while contains(target, start_x - 1) {
+            start_x -= 1;
+        }
+
+        let mut end_x = point.x;
+        while contains(target, end_x + 1) {
+            end_x += 1;
+        }
+
+        target.fill_solid(
+            &Rectangle::new(
+                Point::new(start_x, point.y),
+                Size::new((end_x - start_x + 1) as u32, 1),
+            ),
+            self.color,
+        )?;
+
+        Ok((start_x, end_x))
+    }
+}
+
+/// Fixed size stack of spans.
+struct Stack<'a> {
+    spans: &'a mut [FloodFillSpan],
+    len: usize,
+    overflow: bool,
+    top_left: Point,
+    bottom_right: Point,
+}
+
+impl<'a> Stack<'a> {
+    fn new(spans: &'a mut [FloodFillSpan], top_left: Point, bottom_right: Point) -> Self {
+        Self {
+            spans,
+            len: 0,
+            overflow: false,
+            top_left,
+            bottom_right,
+        }
+    }
+
+    /// Pushes a span onto the stack.
+    ///
+    /// Spans are clipped to the bounding box of the target and spans outside the bounding box are
+    /// discarded.
+    fn push(&mut self, y: i32, start_x: i32, end_x: i32, dy: i32) {
+        let start_x = start_x.max(self.top_left.x);
+        let end_x = end_x.min(self.bottom_right.x);
+
+        if y < self.top_left.y || y > self.bottom_right.y || start_x > end_x {
+            return;
+        }
+
+        if let Some(span) = self.spans.get_mut(self.len) {
+            *span = FloodFillSpan {
+                y,
+                start_x,
+                end_x,
+                dy,
+            };
+            self.len += 1;
+        } else {
+            self.overflow = true;
+        }
+    }
+
+    fn pop(&mut self) -> Option<FloodFillSpan> {
+        self.len = self.len.checked_sub(1)?;
+
+        Some(self.spans[self.len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        framebuffer::{buffer_size, Framebuffer},
+        image::Image,
+        mock_display::MockDisplay,
+        pixelcolor::{raw::LittleEndianMsb0, Gray2},
+        Drawable,
+    };
+
+    type TestFramebuffer = Framebuffer<
+        Gray2,
+        <Gray2 as PixelColor>::Raw,
+        LittleEndianMsb0,
+        8,
+        6,
+        { buffer_size::<Gray2>(8, 6) },
+    >;
+
+    fn framebuffer(pattern: &[&str]) -> TestFramebuffer {
+        let mut fb = TestFramebuffer::new();
+
+        for (y, row) in pattern.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                let luma = c.to_digit(10).unwrap() as u8;
+                fb.set_pixel(Point::new(x as i32, y as i32), Gray2::new(luma));
+            }
+        }
+
+        fb
+    }
+
+    fn assert_pattern(fb: &TestFramebuffer, pattern: &[&str]) {
+        let mut display = MockDisplay::new();
+        Image::new(&fb.as_image(), Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(pattern);
+    }
+
+    const BOX: [&str; 6] = [
+        "00000000", //
+        "01111100", //
+        "01000100", //
+        "01000100", //
+        "01111100", //
+        "00000000", //
+    ];
+
+    #[test]
+    fn seed_fill_inside() {
+        let mut fb = framebuffer(&BOX);
+
+        FloodFill::new(Point::new(2, 2), Gray2::new(2))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "00000000", //
+                "01111100", //
+                "01222100", //
+                "01222100", //
+                "01111100", //
+                "00000000", //
+            ],
+        );
+    }
+
+    #[test]
+    fn seed_fill_outside() {
+        let mut fb = framebuffer(&BOX);
+
+        FloodFill::new(Point::new(7, 5), Gray2::new(3))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "33333333", //
+                "31111133", //
+                "31000133", //
+                "31000133", //
+                "31111133", //
+                "33333333", //
+            ],
+        );
+    }
+
+    #[test]
+    fn boundary_fill() {
+        let mut fb = framebuffer(&[
+            "00000000", //
+            "01111100", //
+            "01020100", //
+            "01303100", //
+            "01111100", //
+            "00000000", //
+        ]);
+
+        FloodFill::new(Point::new(2, 2), Gray2::new(2))
+            .with_boundary(Gray2::new(1))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "00000000", //
+                "01111100", //
+                "01222100", //
+                "01222100", //
+                "01111100", //
+                "00000000", //
+            ],
+        );
+    }
+
+    #[test]
+    fn concave_region() {
+        let mut fb = framebuffer(&[
+            "00000000", //
+            "01010100", //
+            "01010100", //
+            "01010100", //
+            "01111100", //
+            "00000000", //
+        ]);
+
+        let mut stack = [FloodFillSpan::default(); 8];
+        FloodFill::new(Point::new(2, 3), Gray2::new(2))
+            .fill_with_stack(&mut fb, &mut stack)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "22222222", //
+                "21212122", //
+                "21212122", //
+                "21212122", //
+                "21111122", //
+                "22222222", //
+            ],
+        );
+    }
+
+    #[test]
+    fn connectivity() {
+        let pattern = [
+            "00000000", //
+            "01111100", //
+            "01000100", //
+            "01001100", //
+            "01110000", //
+            "00000000", //
+        ];
+
+        let mut fb = framebuffer(&pattern);
+        FloodFill::new(Point::new(2, 2), Gray2::new(2))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "00000000", //
+                "01111100", //
+                "01222100", //
+                "01221100", //
+                "01110000", //
+                "00000000", //
+            ],
+        );
+
+        let mut fb = framebuffer(&pattern);
+        FloodFill::new(Point::new(2, 2), Gray2::new(2))
+            .with_connectivity(Connectivity::Eight)
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(
+            &fb,
+            &[
+                "22222222", //
+                "21111122", //
+                "21222122", //
+                "21221122", //
+                "21112222", //
+                "22222222", //
+            ],
+        );
+    }
+
+    #[test]
+    fn seed_with_fill_color() {
+        let mut fb = framebuffer(&BOX);
+
+        FloodFill::new(Point::new(2, 2), Gray2::new(0))
+            .fill(&mut fb)
+            .unwrap();
+        FloodFill::new(Point::new(1, 1), Gray2::new(2))
+            .with_boundary(Gray2::new(1))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(&fb, &BOX);
+    }
+
+    #[test]
+    fn seed_outside_target() {
+        let mut fb = framebuffer(&BOX);
+
+        FloodFill::new(Point::new(-1, 2), Gray2::new(2))
+            .fill(&mut fb)
+            .unwrap();
+
+        assert_pattern(&fb, &BOX);
+    }
+
+    #[test]
+    fn stack_overflow() {
+        let mut fb = framebuffer(&BOX);
+
+        let mut stack = [FloodFillSpan::default(); 1];
+        assert_eq!(
+            FloodFill::new(Point::new(7, 5), Gray2::new(3)).fill_with_stack(&mut fb, &mut stack),
+            Err(FloodFillError::StackOverflow)
+        );
+
+        let mut fb = framebuffer(&BOX);
+        assert_eq!(
+            FloodFill::new(Point::new(2, 2), Gray2::new(3)).fill_with_stack(&mut fb, &mut []),
+            Err(FloodFillError::StackOverflow)
+        );
+    }
+}
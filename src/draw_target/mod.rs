@@ -3,13 +3,25 @@
 mod clipped;
 mod color_converted;
 mod cropped;
+mod flood_fill;
+mod quantized;
+mod rotated;
 mod translated;
 
-use crate::{geometry::Point, pixelcolor::PixelColor, primitives::Rectangle};
+use crate::{
+    geometry::Point,
+    pixelcolor::{PixelColor, Quantizer, Rgb888},
+    primitives::Rectangle,
+};
 
 pub use clipped::Clipped;
 pub use color_converted::ColorConverted;
 pub use cropped::Cropped;
+pub use flood_fill::{
+    Connectivity, FloodFill, FloodFillError, FloodFillSpan, DEFAULT_FLOOD_FILL_STACK_SIZE,
+};
+pub use quantized::Quantized;
+pub use rotated::{Rotated, Rotation};
 pub use translated::Translated;
 
 pub use embedded_graphics_core::draw_target::DrawTarget;
@@ -149,6 +161,69 @@ pub trait DrawTargetExt: DrawTarget + Sized {
     /// ```
     fn clipped(&mut self, area: &Rectangle) -> Clipped<'_, Self>;
 
+    /// Creates a rotated draw target based on this draw target.
+    ///
+    /// All drawing operations are rotated clockwise by `rotation` around the `center` point,
+    /// before being passed to the parent draw target. This can be used to draw vertical text, like
+    /// axis labels in charts, or to use a display in portrait orientation.
+    ///
+    /// No additional memory is required to draw rotated drawables. But drawing to a rotated draw
+    /// target might be slower than drawing to the parent draw target, because `fill_contiguous`
+    /// calls are converted into `draw_iter` calls if `rotation` isn't `Rotation::Deg0`.
+    ///
+    /// The area covered by a drawable in the parent coordinate system can be calculated by
+    /// rotating its bounding box with [`Rotation::rotate_rectangle`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::Rotation,
+    ///     mock_display::MockDisplay,
+    ///     mono_font::{ascii::FONT_6X9, MonoTextStyle},
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     text::Text,
+    /// };
+    ///
+    /// let mut display = MockDisplay::new();
+    ///
+    /// let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+    ///
+    /// // Draws text which reads from bottom to top, starting at position (10, 50).
+    /// let position = Point::new(10, 50);
+    /// let mut rotated_display = display.rotated(Rotation::Deg270, position);
+    /// Text::new("Label", position, style).draw(&mut rotated_display)?;
+    /// #
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// The bounding box of a rotated draw target is the parent bounding box rotated in the opposite
+    /// direction. This can be used to layout drawables on a display in portrait orientation:
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     draw_target::Rotation,
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::<BinaryColor>::new();
+    ///
+    /// let mut rotated_display = display.rotated(Rotation::Deg90, Point::zero());
+    ///
+    /// // Draw a frame around the edges of the rotated display.
+    /// rotated_display
+    ///     .bounding_box()
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut rotated_display)?;
+    /// #
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    fn rotated(&mut self, rotation: Rotation, center: Point) -> Rotated<'_, Self>;
+
     /// Creates a color conversion draw target.
     ///
     /// A color conversion draw target is used to draw drawables with a different color type to a
@@ -210,6 +285,66 @@ pub trait DrawTargetExt: DrawTarget + Sized {
     fn color_converted<C>(&mut self) -> ColorConverted<'_, Self, C>
     where
         C: PixelColor + Into<Self::Color>;
+
+    /// Creates a color quantization draw target.
+    ///
+    /// A color quantization draw target converts all colors to the closest matching color in the
+    /// palette of a [`Quantizer`]. This can be used to draw full color drawables to displays with
+    /// a limited, fixed palette, like 8 color LCDs or multi-color e-paper displays. Unlike
+    /// [`color_converted`], which relies on the `From` implementations and might truncate the
+    /// color channels, the closest palette color is always selected.
+    ///
+    /// Both the drawable color type and the draw target color type must be convertible into
+    /// [`Rgb888`]. The draw target colors are only used as palette entries, so no conversion from
+    /// [`Rgb888`] is required.
+    ///
+    /// # Performance
+    ///
+    /// Finding the closest palette color requires comparing each color to all palette entries.
+    /// Enabling the cache of the quantizer by using [`Quantizer::with_cache`] can speed up drawing
+    /// if the same colors are used repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// This example draws an `Rgb888` rectangle to a black, white and red e-paper display.
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     mock_display::MockDisplay,
+    ///     pixelcolor::{BwrColor, Quantizer, Rgb888},
+    ///     prelude::*,
+    ///     primitives::{PrimitiveStyle, Rectangle},
+    /// };
+    ///
+    /// let mut display = MockDisplay::<BwrColor>::new();
+    ///
+    /// let quantizer = Quantizer::new(&BwrColor::COLORS).with_cache::<16>();
+    /// let mut quantized_display = display.quantized(quantizer);
+    ///
+    /// Rectangle::new(Point::zero(), Size::new(3, 2))
+    ///     .into_styled(PrimitiveStyle::with_fill(Rgb888::CSS_CRIMSON))
+    ///     .draw(&mut quantized_display)?;
+    /// #
+    /// # display.assert_pattern(&[
+    /// #     "RRR", //
+    /// #     "RRR", //
+    /// # ]);
+    /// #
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`color_converted`]: DrawTargetExt::color_converted
+    /// [`Quantizer`]: crate::pixelcolor::Quantizer
+    /// [`Quantizer::with_cache`]: crate::pixelcolor::Quantizer::with_cache
+    /// [`Rgb888`]: crate::pixelcolor::Rgb888
+    fn quantized<'p, C, const CACHE_SIZE: usize>(
+        &mut self,
+        quantizer: Quantizer<'p, Self::Color, CACHE_SIZE>,
+    ) -> Quantized<'_, 'p, Self, C, CACHE_SIZE>
+    where
+        Self::Color: Into<Rgb888>,
+        C: PixelColor,
+        Rgb888: From<C>;
 }
 
 impl<T> DrawTargetExt for T
@@ -228,12 +363,28 @@ where
         Clipped::new(self, area)
     }
 
+    fn rotated(&mut self, rotation: Rotation, center: Point) -> Rotated<'_, Self> {
+        Rotated::new(self, rotation, center)
+    }
+
     fn color_converted<C>(&mut self) -> ColorConverted<'_, Self, C>
     where
         C: PixelColor + Into<Self::Color>,
     {
         ColorConverted::new(self)
     }
+
+    fn quantized<'p, C, const CACHE_SIZE: usize>(
+        &mut self,
+        quantizer: Quantizer<'p, Self::Color, CACHE_SIZE>,
+    ) -> Quantized<'_, 'p, Self, C, CACHE_SIZE>
+    where
+        Self::Color: Into<Rgb888>,
+        C: PixelColor,
+        Rgb888: From<C>,
+    {
+        Quantized::new(self, quantizer)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,205 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::{PixelColor, Quantizer, Rgb888},
+    primitives::Rectangle,
+    Pixel,
+};
+use core::marker::PhantomData;
+
+/// Color quantization draw target.
+///
+/// Created by calling [`quantized`] on any [`DrawTarget`].
+/// See the [`quantized`] method documentation for more information.
+///
+/// [`quantized`]: crate::draw_target::DrawTargetExt::quantized
+#[derive(Debug)]
+pub struct Quantized<'a, 'p, T, C, const CACHE_SIZE: usize>
+where
+    T: DrawTarget,
+{
+    /// The parent draw target.
+    parent: &'a mut T,
+
+    /// The quantizer.
+    quantizer: Quantizer<'p, T::Color, CACHE_SIZE>,
+
+    /// The input color type.
+    color_type: PhantomData<C>,
+}
+
+impl<'a, 'p, T, C, const CACHE_SIZE: usize> Quantized<'a, 'p, T, C, CACHE_SIZE>
+where
+    T: DrawTarget,
+    T::Color: Into<Rgb888>,
+    C: PixelColor,
+    Rgb888: From<C>,
+{
+    pub(super) fn new(parent: &'a mut T, quantizer: Quantizer<'p, T::Color, CACHE_SIZE>) -> Self {
+        Self {
+            parent,
+            quantizer,
+            color_type: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the quantizer.
+    pub fn quantizer(&self) -> &Quantizer<'p, T::Color, CACHE_SIZE> {
+        &self.quantizer
+    }
+}
+
+impl<T, C, const CACHE_SIZE: usize> DrawTarget for Quantized<'_, '_, T, C, CACHE_SIZE>
+where
+    T: DrawTarget,
+    T::Color: Into<Rgb888>,
+    C: PixelColor,
+    Rgb888: From<C>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let quantizer = &mut self.quantizer;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, c)| Pixel(p, quantizer.quantize(c))),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let quantizer = &mut self.quantizer;
+
+        self.parent
+            .fill_contiguous(area, colors.into_iter().map(|c| quantizer.quantize(c)))
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let color = self.quantizer.quantize(color);
+
+        self.parent.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let color = self.quantizer.quantize(color);
+
+        self.parent.clear(color)
+    }
+}
+
+impl<T, C, const CACHE_SIZE: usize> Dimensions for Quantized<'_, '_, T, C, CACHE_SIZE>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        draw_target::{DrawTarget, DrawTargetExt},
+        geometry::{Dimensions, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::{BwrColor, ColorDistance, Quantizer, Rgb565, Rgb888, RgbColor},
+        primitives::{Primitive, PrimitiveStyle, Rectangle},
+        Drawable, Pixel,
+    };
+
+    const PALETTE: [Rgb888; 3] = [Rgb888::BLACK, Rgb888::RED, Rgb888::WHITE];
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        let mut quantized = display.quantized(Quantizer::new(&PALETTE));
+        quantized
+            .draw_iter([
+                Pixel(Point::new(0, 0), Rgb565::new(25, 10, 3)),
+                Pixel(Point::new(1, 0), Rgb565::new(2, 5, 3)),
+                Pixel(Point::new(2, 0), Rgb565::new(28, 60, 28)),
+            ])
+            .unwrap();
+
+        display.assert_pattern(&["RKW"]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let mut display = MockDisplay::<BwrColor>::new();
+
+        display
+            .quantized(Quantizer::new(&BwrColor::COLORS).with_cache::<4>())
+            .fill_contiguous(
+                &Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+                [
+                    Rgb888::new(200, 0, 0),
+                    Rgb888::new(20, 20, 20),
+                    Rgb888::new(230, 230, 230),
+                    Rgb888::new(200, 0, 0),
+                ],
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   ", //
+            " RK", //
+            " WR", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid() {
+        let mut display = MockDisplay::<BwrColor>::new();
+
+        Rectangle::new(Point::new(1, 0), Size::new(2, 2))
+            .into_styled(PrimitiveStyle::with_fill(Rgb888::new(160, 40, 30)))
+            .draw(
+                &mut display
+                    .quantized(Quantizer::new(&BwrColor::COLORS).with_distance(ColorDistance::Rgb)),
+            )
+            .unwrap();
+
+        display.assert_pattern(&[
+            " RR", //
+            " RR", //
+        ]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        display
+            .quantized(Quantizer::new(&PALETTE))
+            .clear(Rgb565::new(1, 1, 1))
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected.clear(Rgb888::BLACK).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display = MockDisplay::<Rgb888>::new();
+        let expected = display.bounding_box();
+
+        assert_eq!(
+            display
+                .quantized::<Rgb565, 0>(Quantizer::new(&PALETTE))
+                .bounding_box(),
+            expected
+        );
+    }
+}
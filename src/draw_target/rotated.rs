@@ -0,0 +1,338 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// Rotation in 90° steps.
+///
+/// All rotations are clockwise in the screen coordinate system, where the Y axis points
+/// downwards.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    Deg0,
+    /// Rotation by 90°.
+    Deg90,
+    /// Rotation by 180°.
+    Deg180,
+    /// Rotation by 270°.
+    Deg270,
+}
+
+impl Rotation {
+    /// Returns the inverse rotation.
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Deg0 => Self::Deg0,
+            Self::Deg90 => Self::Deg270,
+            Self::Deg180 => Self::Deg180,
+            Self::Deg270 => Self::Deg90,
+        }
+    }
+
+    /// Returns `true` if the rotation swaps the X and Y axes.
+    pub const fn is_transposed(self) -> bool {
+        matches!(self, Self::Deg90 | Self::Deg270)
+    }
+
+    /// Rotates a point around the given center point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{draw_target::Rotation, geometry::Point};
+    ///
+    /// let point = Point::new(15, 10);
+    /// let center = Point::new(10, 10);
+    ///
+    /// assert_eq!(Rotation::Deg90.rotate_point(point, center), Point::new(10, 15));
+    /// ```
+    pub const fn rotate_point(self, point: Point, center: Point) -> Point {
+        let dx = point.x - center.x;
+        let dy = point.y - center.y;
+
+        let (dx, dy) = match self {
+            Self::Deg0 => (dx, dy),
+            Self::Deg90 => (-dy, dx),
+            Self::Deg180 => (-dx, -dy),
+            Self::Deg270 => (dy, -dx),
+        };
+
+        Point::new(center.x + dx, center.y + dy)
+    }
+
+    /// Rotates a rectangle around the given center point.
+    pub fn rotate_rectangle(self, rectangle: &Rectangle, center: Point) -> Rectangle {
+        if let Some(bottom_right) = rectangle.bottom_right() {
+            Rectangle::with_corners(
+                self.rotate_point(rectangle.top_left, center),
+                self.rotate_point(bottom_right, center),
+            )
+        } else {
+            let size = if self.is_transposed() {
+                rectangle.size.swap_xy()
+            } else {
+                rectangle.size
+            };
+
+            Rectangle::new(self.rotate_point(rectangle.top_left, center), size)
+        }
+    }
+}
+
+/// Rotated draw target.
+///
+/// Created by calling [`rotated`] on any [`DrawTarget`].
+/// See the [`rotated`] method documentation for more.
+///
+/// [`rotated`]: crate::draw_target::DrawTargetExt::rotated
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    parent: &'a mut T,
+    rotation: Rotation,
+    center: Point,
+}
+
+impl<'a, T> Rotated<'a, T>
+where
+    T: DrawTarget,
+{
+    pub(super) fn new(parent: &'a mut T, rotation: Rotation, center: Point) -> Self {
+        Self {
+            parent,
+            rotation,
+            center,
+        }
+    }
+}
+
+impl<T> DrawTarget for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let rotation = self.rotation;
+        let center = self.center;
+
+        self.parent.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, c)| Pixel(rotation.rotate_point(p, center), c)),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.rotation == Rotation::Deg0 {
+            return self.parent.fill_contiguous(area, colors);
+        }
+
+        // The pixel order changes if the area is rotated, which requires the use of `draw_iter`.
+        self.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(position, color)| Pixel(position, color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.rotation.rotate_rectangle(area, self.center);
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.parent.clear(color)
+    }
+}
+
+impl<T> Dimensions for Rotated<'_, T>
+where
+    T: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.rotation
+            .inverse()
+            .rotate_rectangle(&self.parent.bounding_box(), self.center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::Size,
+        mock_display::MockDisplay,
+        mono_font::{ascii::FONT_6X9, MonoTextStyle},
+        pixelcolor::BinaryColor,
+        text::{Baseline, Text},
+        Drawable,
+    };
+
+    #[test]
+    fn rotate_point() {
+        let center = Point::new(3, 4);
+        let point = Point::new(5, 5);
+
+        assert_eq!(Rotation::Deg0.rotate_point(point, center), point);
+        assert_eq!(
+            Rotation::Deg90.rotate_point(point, center),
+            Point::new(2, 6)
+        );
+        assert_eq!(
+            Rotation::Deg180.rotate_point(point, center),
+            Point::new(1, 3)
+        );
+        assert_eq!(
+            Rotation::Deg270.rotate_point(point, center),
+            Point::new(4, 2)
+        );
+
+        for rotation in [
+            Rotation::Deg0,
+            Rotation::Deg90,
+            Rotation::Deg180,
+            Rotation::Deg270,
+        ] {
+            assert_eq!(
+                rotation
+                    .inverse()
+                    .rotate_point(rotation.rotate_point(point, center), center),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn draw_iter() {
+        let mut display = MockDisplay::new();
+
+        let mut rotated = display.rotated(Rotation::Deg90, Point::new(2, 2));
+
+        let pixels = [
+            Pixel(Point::new(2, 2), BinaryColor::On),
+            Pixel(Point::new(3, 2), BinaryColor::Off),
+            Pixel(Point::new(4, 2), BinaryColor::Off),
+            Pixel(Point::new(2, 3), BinaryColor::On),
+        ];
+        rotated.draw_iter(pixels.iter().copied()).unwrap();
+
+        display.assert_pattern(&[
+            "    ", //
+            "    ", //
+            " ## ", //
+            "  . ", //
+            "  . ", //
+        ]);
+    }
+
+    #[test]
+    fn fill_contiguous() {
+        let mut display = MockDisplay::new();
+
+        let mut rotated = display.rotated(Rotation::Deg180, Point::new(3, 3));
+
+        let colors = [
+            1, 1, 1, //
+            0, 0, 1, //
+        ];
+        let area = Rectangle::new(Point::new(2, 2), Size::new(3, 2));
+        rotated
+            .fill_contiguous(&area, colors.iter().map(|c| BinaryColor::from(*c != 0)))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "     ", //
+            "     ", //
+            "  #..", //
+            "  ###", //
+        ]);
+    }
+
+    #[test]
+    fn fill_solid() {
+        let mut display = MockDisplay::new();
+
+        let mut rotated = display.rotated(Rotation::Deg270, Point::new(1, 5));
+
+        let area = Rectangle::new(Point::new(2, 4), Size::new(3, 2));
+        rotated.fill_solid(&area, BinaryColor::On).unwrap();
+
+        display.assert_pattern(&[
+            "     ", //
+            "     ", //
+            "##   ", //
+            "##   ", //
+            "##   ", //
+        ]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut display = MockDisplay::new();
+        let mut rotated = display.rotated(Rotation::Deg90, Point::new(1, 3));
+        rotated.clear(BinaryColor::On).unwrap();
+
+        let mut expected = MockDisplay::new();
+        expected.clear(BinaryColor::On).unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        let display_bb = display.bounding_box();
+
+        let rotated = display.rotated(Rotation::Deg90, Point::zero());
+
+        assert_eq!(
+            rotated.bounding_box(),
+            Rectangle::new(
+                Point::new(0, -(display_bb.size.width as i32 - 1)),
+                display_bb.size.swap_xy()
+            )
+        );
+    }
+
+    #[test]
+    fn rotated_text() {
+        let style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("AB", Point::new(8, 0), style, Baseline::Top)
+            .draw(&mut display.rotated(Rotation::Deg90, Point::new(8, 0)))
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  ####  ", //
+            "    # # ", //
+            "    #  #", //
+            "    # # ", //
+            "  ####  ", //
+            "        ", //
+            "  ######", //
+            "  #  # #", //
+            "  #  # #", //
+            "  #  # #", //
+            "   ## # ", //
+        ]);
+    }
+}
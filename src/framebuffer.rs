@@ -310,7 +310,10 @@ mod tests {
         geometry::Point,
         image::Image,
         mock_display::MockDisplay,
-        pixelcolor::{BinaryColor, Gray2, Gray4, Gray8, Rgb565, Rgb888, RgbColor},
+        pixelcolor::{
+            Bgr332, BinaryColor, Gray1, Gray16, Gray2, Gray4, Gray8, Rgb101010, Rgb565, Rgb888,
+            RgbColor, Xrgb8888,
+        },
         primitives::{Primitive, PrimitiveStyle},
         Drawable,
     };
@@ -730,6 +733,34 @@ mod tests {
         <framebuffer!(U32Color, 10, 10)>::new().set_pixel(Point::zero(), U32Color(0));
     }
 
+    #[test]
+    fn additional_color_formats() {
+        let mut fb = <framebuffer!(Rgb101010, 2, 1)>::new();
+        fb.set_pixel(Point::new(1, 0), Rgb101010::new(0x3FF, 0x001, 0x100));
+        assert_eq!(fb.data(), &[0, 0, 0, 0, 0x00, 0x05, 0xF0, 0x3F]);
+        assert_eq!(
+            fb.pixel(Point::new(1, 0)),
+            Some(Rgb101010::new(0x3FF, 0x001, 0x100))
+        );
+
+        let mut fb = <framebuffer!(Xrgb8888, BigEndianLsb0, 1, 1)>::new();
+        fb.set_pixel(Point::zero(), Xrgb8888::new(0x12, 0x34, 0x56));
+        assert_eq!(fb.data(), &[0x00, 0x12, 0x34, 0x56]);
+
+        let mut fb = <framebuffer!(Gray16, BigEndianLsb0, 1, 1)>::new();
+        fb.set_pixel(Point::zero(), Gray16::new(0xABCD));
+        assert_eq!(fb.data(), &[0xAB, 0xCD]);
+        assert_eq!(fb.pixel(Point::zero()), Some(Gray16::new(0xABCD)));
+
+        let mut fb = <framebuffer!(Gray1, 8, 1)>::new();
+        fb.set_pixel(Point::new(1, 0), Gray1::WHITE);
+        assert_eq!(fb.data(), &[0b0100_0000]);
+
+        let mut fb = <framebuffer!(Bgr332, 1, 1)>::new();
+        fb.set_pixel(Point::zero(), Bgr332::BLUE);
+        assert_eq!(fb.data(), &[0b1100_0000]);
+    }
+
     #[test]
     fn oversized_buffer() {
         let fb = Framebuffer::<
@@ -49,6 +49,11 @@ impl Angle {
         Angle(angle.into())
     }
 
+    /// Creates an angle defined in radians from a real number.
+    pub(crate) const fn from_radians_real(angle: Real) -> Self {
+        Angle(angle)
+    }
+
     /// Creates a zero degree angle.
     pub fn zero() -> Self {
         Angle(0.into())
@@ -219,4 +219,130 @@ impl Real {
     pub(crate) fn round(self) -> Self {
         Self(self.0.round())
     }
+
+    /// Rounds to the nearest integer, saturating at the numeric bounds instead of overflowing.
+    #[cfg(not(feature = "fixed_point"))]
+    pub(crate) fn saturating_round(self) -> Self {
+        self.round()
+    }
+
+    /// Rounds to the nearest integer, saturating at the numeric bounds instead of overflowing.
+    #[cfg(feature = "fixed_point")]
+    pub(crate) fn saturating_round(self) -> Self {
+        Self(self.0.saturating_round())
+    }
+
+    /// Adds two numbers, saturating at the numeric bounds instead of overflowing.
+    #[cfg(not(feature = "fixed_point"))]
+    pub(crate) fn saturating_add(self, other: Real) -> Self {
+        self + other
+    }
+
+    /// Adds two numbers, saturating at the numeric bounds instead of overflowing.
+    #[cfg(feature = "fixed_point")]
+    pub(crate) fn saturating_add(self, other: Real) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+
+    /// Multiplies two numbers, saturating at the numeric bounds instead of overflowing.
+    #[cfg(not(feature = "fixed_point"))]
+    pub(crate) fn saturating_mul(self, other: Real) -> Self {
+        self * other
+    }
+
+    /// Multiplies two numbers, saturating at the numeric bounds instead of overflowing.
+    #[cfg(feature = "fixed_point")]
+    pub(crate) fn saturating_mul(self, other: Real) -> Self {
+        Self(self.0.saturating_mul(other.0))
+    }
+
+    /// Returns the square root.
+    ///
+    /// Negative values return zero.
+    #[cfg(not(feature = "fixed_point"))]
+    pub(crate) fn sqrt(self) -> Self {
+        Self(self.0.max(0.0).sqrt())
+    }
+
+    /// Returns the square root.
+    ///
+    /// Negative values return zero.
+    #[cfg(feature = "fixed_point")]
+    pub(crate) fn sqrt(self) -> Self {
+        use fixed::types::I16F16;
+        use integer_sqrt::IntegerSquareRoot;
+
+        let bits = self.0.to_bits().max(0) as u64;
+
+        Self(I16F16::from_bits((bits << 16).integer_sqrt() as i32))
+    }
+
+    /// Returns the length of the hypotenuse of a right triangle with the legs `self` and `other`.
+    ///
+    /// Both legs are scaled by the larger leg before they are squared, to prevent the squares from
+    /// overflowing for fixed point numbers.
+    pub(crate) fn hypot(self, other: Real) -> Self {
+        let max = if self.abs() > other.abs() {
+            self.abs()
+        } else {
+            other.abs()
+        };
+
+        if max == Real::from(0) {
+            return max;
+        }
+
+        let x = self / max;
+        let y = other / max;
+
+        (x * x + y * y).sqrt() * max
+    }
+
+    /// Returns the four quadrant arctangent of `self` and `x` in radians.
+    #[cfg(not(feature = "fixed_point"))]
+    pub(crate) fn atan2(self, x: Real) -> Self {
+        Self(self.0.atan2(x.0))
+    }
+
+    /// Returns the four quadrant arctangent of `self` and `x` in radians.
+    ///
+    /// The arctangent is approximated by a polynomial with a maximum error of about 0.0015
+    /// radians. Zero is returned if both values are zero.
+    #[cfg(feature = "fixed_point")]
+    pub(crate) fn atan2(self, x: Real) -> Self {
+        use fixed::types::I16F16;
+
+        let frac_pi_4 = Real(I16F16::from_bits(51472));
+        let c1 = Real(I16F16::from_bits(16037));
+        let c2 = Real(I16F16::from_bits(4345));
+        let one = Real::from(1);
+
+        let y_abs = self.abs();
+        let x_abs = x.abs();
+
+        if x_abs == Real::from(0) && y_abs == Real::from(0) {
+            return Real::from(0);
+        }
+
+        // Arctangent of the ratio between the smaller and the larger value, which is in the range
+        // from 0 to 1.
+        let z = if y_abs <= x_abs {
+            y_abs / x_abs
+        } else {
+            x_abs / y_abs
+        };
+        let mut angle = frac_pi_4 * z - z * (z - one) * (c1 + c2 * z);
+
+        if y_abs > x_abs {
+            angle = FRAC_PI_2 - angle;
+        }
+        if x < Real::from(0) {
+            angle = PI - angle;
+        }
+        if self < Real::from(0) {
+            angle = -angle;
+        }
+
+        angle
+    }
 }
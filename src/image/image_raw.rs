@@ -125,7 +125,7 @@ where
     data: &'a [u8],
 
     /// Image size in pixels
-    size: Size,
+    pub(crate) size: Size,
 
     pixel_type: PhantomData<C>,
     data_order: PhantomData<O>,
@@ -176,6 +176,11 @@ where
         }
     }
 
+    /// Returns the raw image data.
+    pub const fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
     /// Returns the actual row width in pixels.
     ///
     /// For images with less than 8 bits per pixel each row is padded to contain an integer number
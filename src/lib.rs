@@ -18,7 +18,19 @@
 //!     * [Sectors]
 //!     * [Triangles]
 //!     * [Polylines]
+//!     * [Polygons]
 //!     * [Rounded rectangles]
+//!     * [Quadratic Bézier curves]
+//!     * [Cubic Bézier curves]
+//!     * [Paths]
+//!     * [Elliptical arcs]
+//!     * [Elliptical sectors]
+//!     * [Ring sectors]
+//!     * [Regular polygons]
+//!     * [Stars]
+//!     * [Rotated rectangles]
+//!     * [Rotated rounded rectangles]
+//!     * [Rotated ellipses]
 //! * [Text]
 //! * [Monospaced fonts]
 //!
@@ -256,7 +268,19 @@
 //! [Sectors]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/sector/struct.Sector.html
 //! [Triangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/triangle/struct.Triangle.html
 //! [Polylines]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/polyline/struct.Polyline.html
+//! [Polygons]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/polygon/struct.Polygon.html
 //! [Rounded rectangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rounded_rectangle/struct.RoundedRectangle.html
+//! [Quadratic Bézier curves]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/quadratic_bezier/struct.QuadraticBezier.html
+//! [Cubic Bézier curves]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/cubic_bezier/struct.CubicBezier.html
+//! [Paths]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/path/struct.Path.html
+//! [Elliptical arcs]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/elliptical_arc/struct.EllipticalArc.html
+//! [Elliptical sectors]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/elliptical_sector/struct.EllipticalSector.html
+//! [Ring sectors]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/ring_sector/struct.RingSector.html
+//! [Regular polygons]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/regular_polygon/struct.RegularPolygon.html
+//! [Stars]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/star/struct.Star.html
+//! [Rotated rectangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rotated_rectangle/struct.RotatedRectangle.html
+//! [Rotated rounded rectangles]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rotated_rounded_rectangle/struct.RotatedRoundedRectangle.html
+//! [Rotated ellipses]: https://docs.rs/embedded-graphics/latest/embedded_graphics/primitives/rotated_ellipse/struct.RotatedEllipse.html
 //! [Text]: https://docs.rs/embedded-graphics/latest/embedded_graphics/text/index.html
 //! [Monospaced fonts]: https://docs.rs/embedded-graphics/latest/embedded_graphics/mono_font/index.html
 //! [examples]: https://docs.rs/embedded-graphics/latest/embedded_graphics/examples/index.html
@@ -276,7 +300,19 @@
 //! [Sectors]: primitives::sector::Sector
 //! [Triangles]: primitives::triangle::Triangle
 //! [Polylines]: primitives::polyline::Polyline
+//! [Polygons]: primitives::polygon::Polygon
 //! [Rounded rectangles]: primitives::rounded_rectangle::RoundedRectangle
+//! [Quadratic Bézier curves]: primitives::quadratic_bezier::QuadraticBezier
+//! [Cubic Bézier curves]: primitives::cubic_bezier::CubicBezier
+//! [Paths]: primitives::path::Path
+//! [Elliptical arcs]: primitives::elliptical_arc::EllipticalArc
+//! [Elliptical sectors]: primitives::elliptical_sector::EllipticalSector
+//! [Ring sectors]: primitives::ring_sector::RingSector
+//! [Regular polygons]: primitives::regular_polygon::RegularPolygon
+//! [Stars]: primitives::star::Star
+//! [Rotated rectangles]: primitives::rotated_rectangle::RotatedRectangle
+//! [Rotated rounded rectangles]: primitives::rotated_rounded_rectangle::RotatedRoundedRectangle
+//! [Rotated ellipses]: primitives::rotated_ellipse::RotatedEllipse
 //! [Text]: text
 //! [Monospaced fonts]: mono_font
 //! [`DrawTarget`]: https://docs.rs/embedded-graphics-core/latest/embedded_graphics_core/draw_target/trait.DrawTarget.html
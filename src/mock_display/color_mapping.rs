@@ -1,6 +1,7 @@
 use embedded_graphics_core::pixelcolor::{
-    Bgr555, Bgr565, Bgr888, BinaryColor, Gray2, Gray4, Gray8, GrayColor, Rgb332, Rgb444, Rgb555,
-    Rgb565, Rgb888, RgbColor, WebColors,
+    AcepColor, Bgr332, Bgr444, Bgr555, Bgr565, Bgr888, BinaryColor, BwrColor, BwyColor, Gray1,
+    Gray2, Gray4, Gray8, GrayColor, Rgb332, Rgb444, Rgb555, Rgb565, Rgb888, RgbColor, WebColors,
+    Xbgr8888, Xrgb8888,
 };
 
 /// Mapping between `char`s and colors.
@@ -59,6 +60,7 @@ macro_rules! impl_gray_color_mapping {
     };
 }
 
+impl_gray_color_mapping!(Gray1, 2);
 impl_gray_color_mapping!(Gray2, 4);
 impl_gray_color_mapping!(Gray4, 16);
 
@@ -123,13 +125,48 @@ macro_rules! impl_rgb_color_mapping {
 }
 
 impl_rgb_color_mapping!(Rgb332);
+impl_rgb_color_mapping!(Bgr332);
 impl_rgb_color_mapping!(Rgb444);
+impl_rgb_color_mapping!(Bgr444);
 impl_rgb_color_mapping!(Rgb555);
 impl_rgb_color_mapping!(Bgr555);
 impl_rgb_color_mapping!(Rgb565);
 impl_rgb_color_mapping!(Bgr565);
 impl_rgb_color_mapping!(Rgb888);
 impl_rgb_color_mapping!(Bgr888);
+impl_rgb_color_mapping!(Xrgb8888);
+impl_rgb_color_mapping!(Xbgr8888);
+
+macro_rules! impl_epd_color_mapping {
+    ($type:ident, [$($char:expr => $variant:ident),+]) => {
+        impl ColorMapping for $type {
+            fn char_to_color(c: char) -> Self {
+                match c {
+                    $($char => Self::$variant,)+
+                    _ => panic!("Invalid char in pattern: '{}'", c),
+                }
+            }
+
+            fn color_to_char(color: Self) -> char {
+                match color {
+                    $(Self::$variant => $char,)+
+                }
+            }
+        }
+    };
+}
+
+impl_epd_color_mapping!(BwrColor, ['K' => Black, 'W' => White, 'R' => Red]);
+impl_epd_color_mapping!(BwyColor, ['K' => Black, 'W' => White, 'Y' => Yellow]);
+impl_epd_color_mapping!(AcepColor, [
+    'K' => Black,
+    'W' => White,
+    'G' => Green,
+    'B' => Blue,
+    'R' => Red,
+    'Y' => Yellow,
+    'O' => Orange
+]);
 
 #[cfg(test)]
 mod tests {
@@ -185,4 +222,17 @@ mod tests {
     fn invalid_gray8_char_g() {
         Gray8::char_to_color('G');
     }
+
+    #[test]
+    fn epd_color_mappings() {
+        for color in AcepColor::COLORS {
+            assert_eq!(
+                color,
+                AcepColor::char_to_color(AcepColor::color_to_char(color))
+            );
+        }
+
+        assert_eq!(BwrColor::char_to_color('R'), BwrColor::Red);
+        assert_eq!(BwyColor::color_to_char(BwyColor::Yellow), 'Y');
+    }
 }
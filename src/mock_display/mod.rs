@@ -49,6 +49,16 @@
 //! | `'.'`     | `Some(BinaryColor::Off)` | Pixel was changed to `BinaryColor::Off` |
 //! | `'#'`     | `Some(BinaryColor::On)`  | Pixel was changed to `BinaryColor::On`  |
 //!
+//! # Characters used in [`Gray1`] patterns
+//!
+//! The following mappings are available for [`Gray1`]:
+//!
+//! | Character | Color                    | Description                             |
+//! |-----------|--------------------------|-----------------------------------------|
+//! | `' '`     | `None`                   | No drawing operation changed the pixel  |
+//! | `'0'`     | `Some(Gray1::new(0x0))`  | Pixel was changed to `Gray1::new(0x0)`  |
+//! | `'1'`     | `Some(Gray1::new(0x1))`  | Pixel was changed to `Gray1::new(0x1)`  |
+//!
 //! # Characters used in [`Gray2`] patterns
 //!
 //! The following mappings are available for [`Gray2`]:
@@ -110,6 +120,23 @@
 //!
 //! Note: The table used `C` as a placeholder for the actual color type, like `Rgb565::BLACK`.
 //!
+//! # Characters used in e-paper color patterns
+//!
+//! The following mappings are available for [`BwrColor`], [`BwyColor`] and [`AcepColor`]:
+//!
+//! | Character | Color                    | Description                             |
+//! |-----------|--------------------------|-----------------------------------------|
+//! | `' '`     | `None`                   | No drawing operation changed the pixel  |
+//! | `'K'`     | `Some(C::Black)`         | Pixel was changed to `C::Black`         |
+//! | `'W'`     | `Some(C::White)`         | Pixel was changed to `C::White`         |
+//! | `'R'`     | `Some(C::Red)`           | Pixel was changed to `C::Red`           |
+//! | `'Y'`     | `Some(C::Yellow)`        | Pixel was changed to `C::Yellow`        |
+//! | `'G'`     | `Some(C::Green)`         | Pixel was changed to `C::Green`         |
+//! | `'B'`     | `Some(C::Blue)`          | Pixel was changed to `C::Blue`          |
+//! | `'O'`     | `Some(C::Orange)`        | Pixel was changed to `C::Orange`        |
+//!
+//! Note: Only the characters for colors that are supported by the color type can be used.
+//!
 //! # Examples
 //!
 //! ## Assert that a modified display matches the expected value
@@ -168,6 +195,10 @@
 //!
 //! [`pixelcolor`]: super::pixelcolor#structs
 //! [`BinaryColor`]: super::pixelcolor::BinaryColor
+//! [`BwrColor`]: super::pixelcolor::BwrColor
+//! [`BwyColor`]: super::pixelcolor::BwyColor
+//! [`AcepColor`]: super::pixelcolor::AcepColor
+//! [`Gray1`]: super::pixelcolor::Gray1
 //! [`Gray2`]: super::pixelcolor::Gray2
 //! [`Gray4`]: super::pixelcolor::Gray4
 //! [`Gray8`]: super::pixelcolor::Gray8
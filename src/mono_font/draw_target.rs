@@ -1,6 +1,10 @@
 use crate::{
-    draw_target::DrawTarget, geometry::Dimensions, iterator::ContiguousIteratorExt,
-    pixelcolor::BinaryColor, primitives::Rectangle, Pixel,
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    iterator::ContiguousIteratorExt,
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+    Pixel,
 };
 
 pub struct MonoFontDrawTarget<'a, T, C> {
@@ -138,3 +142,115 @@ impl<T: DrawTarget, C> Dimensions for MonoFontDrawTarget<'_, T, C> {
 pub struct Foreground<C>(pub C);
 pub struct Background<C>(pub C);
 pub struct Both<C>(pub C, pub C);
+
+/// Draw target which scales all drawing operations by an integer factor.
+///
+/// Each pixel is drawn as a block of `scale` pixels, relative to the `origin` point.
+pub struct Scaled<'a, T> {
+    parent: &'a mut T,
+    origin: Point,
+    scale: Size,
+}
+
+impl<'a, T: DrawTarget> Scaled<'a, T> {
+    pub fn new(parent: &'a mut T, origin: Point, scale: Size) -> Self {
+        Self {
+            parent,
+            origin,
+            scale,
+        }
+    }
+
+    /// Returns the scaled position of a point.
+    pub fn scale_point(&self, point: Point) -> Point {
+        self.origin
+            + (point - self.origin).component_mul(Point::new(
+                self.scale.width as i32,
+                self.scale.height as i32,
+            ))
+    }
+
+    /// Returns the scaled area of a rectangle.
+    fn scale_rectangle(&self, area: &Rectangle) -> Rectangle {
+        Rectangle::new(
+            self.scale_point(area.top_left),
+            area.size.component_mul(self.scale),
+        )
+    }
+
+    /// Fills a horizontal run of `length` pixels, starting at `start`.
+    fn fill_run(&mut self, start: Point, length: u32, color: T::Color) -> Result<(), T::Error> {
+        let area = Rectangle::new(start, Size::new(length, 1));
+        let area = self.scale_rectangle(&area);
+
+        self.parent.fill_solid(&area, color)
+    }
+}
+
+impl<T: DrawTarget> DrawTarget for Scaled<'_, T> {
+    type Color = T::Color;
+    type Error = T::Error;
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.scale == Size::new(1, 1) {
+            return self.parent.fill_contiguous(area, colors);
+        }
+
+        // Consecutive pixels with the same color on a single row are merged into a single
+        // `fill_solid` call.
+        let mut run: Option<(Point, u32, T::Color)> = None;
+
+        for (point, color) in area.points().zip(colors) {
+            match &mut run {
+                Some((start, length, run_color)) if start.y == point.y && *run_color == color => {
+                    *length += 1;
+                }
+                _ => {
+                    if let Some((start, length, run_color)) = run {
+                        self.fill_run(start, length, run_color)?;
+                    }
+                    run = Some((point, 1, color));
+                }
+            }
+        }
+
+        if let Some((start, length, run_color)) = run {
+            self.fill_run(start, length, run_color)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        if self.scale == Size::new(1, 1) {
+            return self.parent.draw_iter(pixels);
+        }
+
+        for Pixel(point, color) in pixels {
+            self.fill_run(point, 1, color)?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.scale_rectangle(area);
+        self.parent.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, _color: Self::Color) -> Result<(), Self::Error> {
+        unreachable!()
+    }
+}
+
+impl<T: DrawTarget> Dimensions for Scaled<'_, T> {
+    fn bounding_box(&self) -> Rectangle {
+        self.parent.bounding_box()
+    }
+}
@@ -51,6 +51,17 @@ pub trait GlyphMapping: Sync {
     ///
     /// If `c` isn't included in the font the index of a suitable replacement glyph is returned.
     fn index(&self, c: char) -> usize;
+
+    /// Returns `true` if the mapping contains a glyph for the given character.
+    ///
+    /// This is used to select a fallback font for characters that aren't included in a font. The
+    /// default implementation returns `true` for all characters, because a mapping that is only
+    /// defined by [`index`](Self::index) has no way to tell a replacement glyph apart from a
+    /// regular glyph.
+    fn contains(&self, c: char) -> bool {
+        let _ = c;
+        true
+    }
 }
 
 impl<F> GlyphMapping for F
@@ -130,9 +141,52 @@ impl<'a> StrGlyphMapping<'a> {
         .flatten()
     }
 
+    /// Maps a character to a glyph index in a const context.
+    ///
+    /// Returns the same result as [`GlyphMapping::index`].
+    pub(crate) const fn const_index(&self, c: char) -> usize {
+        let bytes = self.data.as_bytes();
+        let c = c as u32;
+
+        let mut i = 0;
+        let mut index = 0;
+        while i < bytes.len() {
+            let (first, len) = decode_char(bytes, i);
+            i += len;
+
+            if first == '\0' as u32 {
+                if i >= bytes.len() {
+                    break;
+                }
+                let (start, len) = decode_char(bytes, i);
+                i += len;
+
+                if i >= bytes.len() {
+                    break;
+                }
+                let (end, len) = decode_char(bytes, i);
+                i += len;
+
+                if c >= start && c <= end {
+                    return index + (c - start) as usize;
+                }
+
+                index += (end - start) as usize + 1;
+            } else {
+                if c == first {
+                    return index;
+                }
+
+                index += 1;
+            }
+        }
+
+        self.replacement_index
+    }
+
     /// Returns if the mapping contains the given char.
     pub fn contains(&self, c: char) -> bool {
-        self.chars().any(|v| v == c)
+        self.ranges().any(|(_, range)| range.contains(&c))
     }
 }
 
@@ -143,6 +197,39 @@ impl GlyphMapping for StrGlyphMapping<'_> {
             .map(|(index, range)| index + (c as usize - *range.start() as usize))
             .unwrap_or(self.replacement_index)
     }
+
+    fn contains(&self, c: char) -> bool {
+        StrGlyphMapping::contains(self, c)
+    }
+}
+
+/// Decodes the UTF-8 encoded character that starts at byte index `i`.
+///
+/// Returns the code point and the length of the encoded character in bytes. The input must be
+/// valid UTF-8, which is guaranteed if the bytes are taken from a `str`.
+pub(crate) const fn decode_char(bytes: &[u8], i: usize) -> (u32, usize) {
+    let first = bytes[i] as u32;
+
+    if first < 0x80 {
+        (first, 1)
+    } else if first < 0xE0 {
+        (((first & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if first < 0xF0 {
+        (
+            ((first & 0x0F) << 12)
+                | ((bytes[i + 1] as u32 & 0x3F) << 6)
+                | (bytes[i + 2] as u32 & 0x3F),
+            3,
+        )
+    } else {
+        (
+            ((first & 0x07) << 18)
+                | ((bytes[i + 1] as u32 & 0x3F) << 12)
+                | ((bytes[i + 2] as u32 & 0x3F) << 6)
+                | (bytes[i + 3] as u32 & 0x3F),
+            4,
+        )
+    }
 }
 
 macro_rules! impl_mapping {
@@ -235,6 +322,17 @@ impl_mapping!(
     (JisX0201, JIS_X0201, "\0\u{20}\u{7f}\0\u{ff60}\u{ff9f}"),
 );
 
+/// Symbols and icons.
+///
+/// Arrows, media controls and check marks use their Unicode code points. Icons without a Unicode
+/// equivalent, like battery, Wi-Fi and Bluetooth icons, are placed in the Private Use Area
+/// starting at `U+E000`. See the [`symbols`](super::symbols) module for constants for all
+/// characters in this mapping.
+pub const SYMBOLS: StrGlyphMapping = StrGlyphMapping::new(
+    "\0\u{2190}\u{2193}\u{23ed}\u{23ee}\u{23f8}\u{23f9}\u{25b6}\u{2713}\u{2717}\0\u{e000}\u{e009}\u{fffd}",
+    21,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,6 +405,18 @@ mod tests {
         assert_eq!(mapping.index('$'), 2);
     }
 
+    #[test]
+    fn const_index() {
+        for mapping in Mapping::iter()
+            .map(Mapping::glyph_mapping)
+            .chain([&SYMBOLS])
+        {
+            for c in ('\0'..'\u{3000}').chain('\u{e000}'..='\u{ffff}') {
+                assert_eq!(mapping.const_index(c), mapping.index(c), "{:?}", c);
+            }
+        }
+    }
+
     #[test]
     fn incomplete_range() {
         let mapping = StrGlyphMapping::new("\x00a", 0);
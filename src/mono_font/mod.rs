@@ -47,6 +47,8 @@ mod draw_target;
 mod generated;
 pub mod mapping;
 mod mono_text_style;
+pub mod subset;
+pub mod symbols;
 
 use core::fmt;
 
@@ -234,7 +236,10 @@ pub(crate) mod tests {
         geometry::{Dimensions, Point},
         image::{GetPixel, Image},
         mock_display::MockDisplay,
-        mono_font::{mapping::Mapping, MonoTextStyleBuilder},
+        mono_font::{
+            mapping::{Mapping, StrGlyphMapping},
+            MonoTextStyleBuilder,
+        },
         pixelcolor::{
             raw::{LittleEndianMsb0, RawU1},
             BinaryColor,
@@ -361,40 +366,84 @@ pub(crate) mod tests {
         ];
 
         for (mapping, font) in fonts {
-            let mut expected = new_framebuffer();
-            Image::new(&font.image, Point::zero())
-                .draw(&mut expected)
-                .unwrap();
-
-            let chars_per_row = (font.image.size().width / font.character_size.width) as usize;
-
-            let mut text = ArrayString::<1024>::new();
-            for (i, c) in mapping.glyph_mapping().chars().enumerate() {
-                if i % chars_per_row == 0 && i != 0 {
-                    text.push('\n');
-                }
-                text.push(c);
-            }
+            assert_font_subset(mapping.glyph_mapping(), font);
+        }
+    }
 
-            let mut output = new_framebuffer();
-            Text::with_baseline(
-                &text,
-                Point::zero(),
-                MonoTextStyle::new(font, BinaryColor::On),
-                Baseline::Top,
-            )
-            .draw(&mut output)
+    #[test]
+    fn draw_symbols() {
+        assert_font_subset(&mapping::SYMBOLS, &symbols::FONT_8X8);
+    }
+
+    #[test]
+    fn symbols_mapping() {
+        for c in [
+            symbols::ARROW_LEFT,
+            symbols::ARROW_UP,
+            symbols::ARROW_RIGHT,
+            symbols::ARROW_DOWN,
+            symbols::NEXT_TRACK,
+            symbols::PREVIOUS_TRACK,
+            symbols::PAUSE,
+            symbols::STOP,
+            symbols::PLAY,
+            symbols::CHECK_MARK,
+            symbols::CROSS_MARK,
+            symbols::BATTERY_EMPTY,
+            symbols::BATTERY_LOW,
+            symbols::BATTERY_MEDIUM,
+            symbols::BATTERY_FULL,
+            symbols::BATTERY_CHARGING,
+            symbols::WIFI_OFF,
+            symbols::WIFI_WEAK,
+            symbols::WIFI_MEDIUM,
+            symbols::WIFI_STRONG,
+            symbols::BLUETOOTH,
+        ] {
+            assert!(mapping::SYMBOLS.contains(c), "{:?}", c);
+        }
+
+        assert_eq!(
+            mapping::SYMBOLS.index('A'),
+            mapping::SYMBOLS.index('\u{FFFD}')
+        );
+    }
+
+    /// Checks that drawing all characters of a glyph mapping reproduces the font image.
+    fn assert_font_subset(mapping: &StrGlyphMapping, font: &MonoFont) {
+        let mut expected = new_framebuffer();
+        Image::new(&font.image, Point::zero())
+            .draw(&mut expected)
             .unwrap();
 
-            if expected != output {
-                let mut message = ArrayString::<65536>::new();
-                message.push_str("Output:\n");
-                dump_framebuffer(&output, &mut message);
-                message.push_str("\nExpected:\n");
-                dump_framebuffer(&expected, &mut message);
+        let chars_per_row = (font.image.size().width / font.character_size.width) as usize;
 
-                panic!("{}", message)
+        let mut text = ArrayString::<1024>::new();
+        for (i, c) in mapping.chars().enumerate() {
+            if i % chars_per_row == 0 && i != 0 {
+                text.push('\n');
             }
+            text.push(c);
+        }
+
+        let mut output = new_framebuffer();
+        Text::with_baseline(
+            &text,
+            Point::zero(),
+            MonoTextStyle::new(font, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut output)
+        .unwrap();
+
+        if expected != output {
+            let mut message = ArrayString::<65536>::new();
+            message.push_str("Output:\n");
+            dump_framebuffer(&output, &mut message);
+            message.push_str("\nExpected:\n");
+            dump_framebuffer(&expected, &mut message);
+
+            panic!("{}", message)
         }
     }
 
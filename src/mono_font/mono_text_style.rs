@@ -3,8 +3,8 @@ use crate::{
     geometry::{Point, Size},
     image::Image,
     mono_font::{
-        draw_target::{Background, Both, Foreground, MonoFontDrawTarget},
-        MonoFont,
+        draw_target::{Background, Both, Foreground, MonoFontDrawTarget, Scaled},
+        DecorationDimensions, MonoFont,
     },
     pixelcolor::{BinaryColor, PixelColor},
     primitives::Rectangle,
@@ -12,6 +12,7 @@ use crate::{
         renderer::{CharacterStyle, TextMetrics, TextRenderer},
         Baseline, DecorationColor,
     },
+    transform::Transform,
     Drawable,
 };
 use az::SaturatingAs;
@@ -46,6 +47,39 @@ pub struct MonoTextStyle<'a, C> {
 
     /// Font.
     pub font: &'a MonoFont<'a>,
+
+    /// Integer scale factor.
+    ///
+    /// Each pixel of a glyph is drawn as a block of `scale.width` by `scale.height` pixels. All
+    /// metrics, like the character size and line height, and the decorations are scaled by the
+    /// same factor.
+    pub scale: Size,
+
+    /// Outline color.
+    ///
+    /// If an outline color is set, a one pixel wide outline is drawn around each glyph. The outline
+    /// makes text legible on top of images and other busy backgrounds.
+    pub outline_color: Option<C>,
+
+    /// Shadow color.
+    ///
+    /// If a shadow color is set, a copy of each glyph is drawn behind the text, offset by
+    /// [`shadow_offset`](Self::shadow_offset).
+    pub shadow_color: Option<C>,
+
+    /// Shadow offset.
+    pub shadow_offset: Point,
+
+    /// Fallback fonts.
+    ///
+    /// Characters that aren't included in [`font`](Self::font) are drawn using the first fallback
+    /// font that contains a glyph for the character. If no font contains the character, the
+    /// replacement glyph of the primary font is used.
+    ///
+    /// Glyphs from fallback fonts are aligned to the baseline of the primary font. The line height,
+    /// character spacing and decorations are always determined by the primary font, which is why
+    /// fallback fonts should have the same character height as the primary font.
+    pub fallback_fonts: &'a [&'a MonoFont<'a>],
 }
 
 impl<'a, C> MonoTextStyle<'a, C>
@@ -70,14 +104,35 @@ where
             && self.background_color.is_none()
             && self.underline_color.is_none()
             && self.strikethrough_color.is_none()
+            && self.outline_color.is_none()
+            && self.shadow_color.is_none()
+    }
+
+    /// Returns `true` if the style has an outline or shadow.
+    const fn has_effects(&self) -> bool {
+        self.outline_color.is_some() || self.shadow_color.is_some()
+    }
+
+    /// Returns the font that is used to draw a character.
+    fn font_for_char(&self, c: char) -> &'a MonoFont<'a> {
+        font_for_char(self.font, self.fallback_fonts, c)
+    }
+
+    /// Returns the unscaled width of a character.
+    fn char_width(&self, c: char) -> u32 {
+        self.font_for_char(c).character_size.width
     }
 
     fn line_elements<'t>(
         &self,
         mut position: Point,
         text: &'t str,
-    ) -> impl Iterator<Item = (Point, LineElement)> + 't {
-        let char_width = self.font.character_size.width as i32;
+    ) -> impl Iterator<Item = (Point, LineElement)> + 't
+    where
+        'a: 't,
+    {
+        let font = self.font;
+        let fallback_fonts = self.fallback_fonts;
         let spacing_width = self.font.character_spacing as i32;
 
         let mut chars = text.chars();
@@ -94,7 +149,7 @@ where
                 Some((p, LineElement::Spacing))
             } else if let Some(c) = next_char {
                 let p = position;
-                position.x += char_width;
+                position.x += font_for_char(font, fallback_fonts, c).character_size.width as i32;
 
                 next_char = chars.next();
                 add_spacing = next_char.is_some();
@@ -116,18 +171,108 @@ where
         D: DrawTarget<Color = C>,
     {
         if let Some(color) = self.strikethrough_color.effective_color(self.text_color) {
-            let rect = self.font.strikethrough.get_bounding_box(position, width);
+            let rect = self
+                .scaled_decoration(&self.font.strikethrough)
+                .get_bounding_box(position, width);
             target.fill_solid(&rect, color)?;
         }
 
         if let Some(color) = self.underline_color.effective_color(self.text_color) {
-            let rect = self.font.underline.get_bounding_box(position, width);
+            let rect = self
+                .scaled_decoration(&self.font.underline)
+                .get_bounding_box(position, width);
             target.fill_solid(&rect, color)?;
         }
 
         Ok(())
     }
 
+    /// Returns the decoration dimensions scaled by the vertical scale factor.
+    const fn scaled_decoration(&self, decoration: &DecorationDimensions) -> DecorationDimensions {
+        DecorationDimensions::new(
+            decoration.offset * self.scale.height,
+            decoration.height * self.scale.height,
+        )
+    }
+
+    /// Draws the glyphs in a single color.
+    fn draw_glyphs<D>(
+        &self,
+        text: &str,
+        position: Point,
+        color: C,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut scaled_target = Scaled::new(target, position, self.scale);
+        self.draw_string_binary(
+            text,
+            position,
+            MonoFontDrawTarget::new(&mut scaled_target, Foreground(color)),
+        )?;
+
+        Ok(())
+    }
+
+    /// Draws a string with outline or shadow effects.
+    ///
+    /// The individual layers are drawn on top of each other, starting with the background and
+    /// ending with the text.
+    fn draw_string_with_effects<D>(
+        &self,
+        text: &str,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(background_color) = self.background_color {
+            let mut scaled_target = Scaled::new(target, position, self.scale);
+            self.draw_string_binary(
+                text,
+                position,
+                MonoFontDrawTarget::new(&mut scaled_target, Background(background_color)),
+            )?;
+        }
+
+        if let Some(shadow_color) = self.shadow_color {
+            self.draw_glyphs(text, position + self.shadow_offset, shadow_color, target)?;
+        }
+
+        if let Some(outline_color) = self.outline_color {
+            for offset in OUTLINE_OFFSETS {
+                self.draw_glyphs(text, position + offset, outline_color, target)?;
+            }
+        }
+
+        if let Some(text_color) = self.text_color {
+            self.draw_glyphs(text, position, text_color, target)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the width of a string.
+    fn text_width(&self, text: &str) -> u32 {
+        text.chars()
+            .map(|c| (self.char_width(c) + self.font.character_spacing) * self.scale.width)
+            .sum::<u32>()
+            .saturating_sub(self.character_spacing())
+    }
+
+    /// Returns the scaled character size.
+    const fn character_size(&self) -> Size {
+        self.font.character_size.component_mul(self.scale)
+    }
+
+    /// Returns the scaled character spacing.
+    const fn character_spacing(&self) -> u32 {
+        self.font.character_spacing * self.scale.width
+    }
+
     fn draw_string_binary<D>(
         &self,
         text: &str,
@@ -140,8 +285,13 @@ where
         for (p, element) in self.line_elements(position, text) {
             match element {
                 LineElement::Char(c) => {
-                    let glyph = self.font.glyph(c);
-                    Image::new(&glyph, p).draw(&mut target)?;
+                    let font = self.font_for_char(c);
+                    let glyph = font.glyph(c);
+
+                    // Align the baseline of fallback fonts with the baseline of the primary font.
+                    let offset = self.font.baseline as i32 - font.baseline as i32;
+
+                    Image::new(&glyph, p + Point::new(0, offset)).draw(&mut target)?;
                 }
                 // Fill space between characters if background color is set.
                 LineElement::Spacing if self.font.character_spacing > 0 => {
@@ -171,15 +321,16 @@ where
         match baseline {
             Baseline::Top => 0,
             Baseline::Bottom => self
-                .font
-                .character_size
+                .character_size()
                 .height
                 .saturating_sub(1)
                 .saturating_as(),
             Baseline::Middle => {
-                (self.font.character_size.height.saturating_sub(1) / 2).saturating_as()
+                (self.character_size().height.saturating_sub(1) / 2).saturating_as()
             }
-            Baseline::Alphabetic => self.font.baseline.saturating_as(),
+            Baseline::Alphabetic => (self.font.baseline * self.scale.height
+                + self.scale.height.saturating_sub(1))
+            .saturating_as(),
         }
     }
 }
@@ -202,29 +353,47 @@ where
     {
         let position = position - Point::new(0, self.baseline_offset(baseline));
 
+        if self.has_effects() {
+            self.draw_string_with_effects(text, position, target)?;
+
+            let width = self.text_width(text);
+            if width > 0 {
+                self.draw_decorations(width, position, target)?;
+            }
+
+            return Ok(position + Point::new(width.saturating_as(), self.baseline_offset(baseline)));
+        }
+
+        // Glyphs are drawn unscaled to a scaled draw target. Only the returned position needs to be
+        // converted back into the coordinate system of the parent draw target.
+        let mut scaled_target = Scaled::new(target, position, self.scale);
+
         let next = match (self.text_color, self.background_color) {
             (Some(text_color), Some(background_color)) => self.draw_string_binary(
                 text,
                 position,
-                MonoFontDrawTarget::new(target, Both(text_color, background_color)),
+                MonoFontDrawTarget::new(&mut scaled_target, Both(text_color, background_color)),
             )?,
             (Some(text_color), None) => self.draw_string_binary(
                 text,
                 position,
-                MonoFontDrawTarget::new(target, Foreground(text_color)),
+                MonoFontDrawTarget::new(&mut scaled_target, Foreground(text_color)),
             )?,
             (None, Some(background_color)) => self.draw_string_binary(
                 text,
                 position,
-                MonoFontDrawTarget::new(target, Background(background_color)),
+                MonoFontDrawTarget::new(&mut scaled_target, Background(background_color)),
             )?,
             (None, None) => {
-                let dx = (self.font.character_size.width + self.font.character_spacing)
-                    * text.chars().count() as u32;
+                let dx = text
+                    .chars()
+                    .map(|c| self.char_width(c) + self.font.character_spacing)
+                    .sum::<u32>();
 
                 position + Size::new(dx, 0)
             }
         };
+        let next = scaled_target.scale_point(next);
 
         if next.x > position.x {
             let width = (next.x - position.x) as u32;
@@ -249,7 +418,7 @@ where
         if width != 0 {
             if let Some(background_color) = self.background_color {
                 target.fill_solid(
-                    &Rectangle::new(position, Size::new(width, self.font.character_size.height)),
+                    &Rectangle::new(position, Size::new(width, self.character_size().height)),
                     background_color,
                 )?;
             }
@@ -263,26 +432,41 @@ where
     fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
         let bb_position = position - Point::new(0, self.baseline_offset(baseline));
 
-        let bb_width = (text.chars().count() as u32
-            * (self.font.character_size.width + self.font.character_spacing))
-            .saturating_sub(self.font.character_spacing);
+        let bb_width = self.text_width(text);
 
         let bb_height = if self.underline_color != DecorationColor::None {
-            self.font.underline.height + self.font.underline.offset
+            let underline = self.scaled_decoration(&self.font.underline);
+            underline.height + underline.offset
         } else {
-            self.font.character_size.height
+            self.character_size().height
         };
 
         let bb_size = Size::new(bb_width, bb_height);
+        let mut bounding_box = Rectangle::new(bb_position, bb_size);
+
+        // Extend the bounding box to include the outline and shadow.
+        let glyphs = Rectangle::new(
+            bb_position,
+            Size::new(bb_width, self.character_size().height),
+        );
+        if !glyphs.is_zero_sized() {
+            if self.outline_color.is_some() {
+                bounding_box = bounding_box.envelope(&glyphs.offset(1));
+            }
+
+            if self.shadow_color.is_some() {
+                bounding_box = bounding_box.envelope(&glyphs.translate(self.shadow_offset));
+            }
+        }
 
         TextMetrics {
-            bounding_box: Rectangle::new(bb_position, bb_size),
+            bounding_box,
             next_position: position + bb_size.x_axis(),
         }
     }
 
     fn line_height(&self) -> u32 {
-        self.font.character_size.height
+        self.character_size().height
     }
 }
 
@@ -309,6 +493,37 @@ where
     }
 }
 
+/// Returns the first font that contains a glyph for the given character.
+///
+/// The primary font is returned if none of the fonts contains the character.
+fn font_for_char<'a>(
+    font: &'a MonoFont<'a>,
+    fallback_fonts: &'a [&'a MonoFont<'a>],
+    c: char,
+) -> &'a MonoFont<'a> {
+    if fallback_fonts.is_empty() || font.glyph_mapping.contains(c) {
+        return font;
+    }
+
+    fallback_fonts
+        .iter()
+        .copied()
+        .find(|font| font.glyph_mapping.contains(c))
+        .unwrap_or(font)
+}
+
+/// Offsets used to draw the outline around glyphs.
+const OUTLINE_OFFSETS: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 enum LineElement {
@@ -385,7 +600,80 @@ enum LineElement {
 ///     .build();
 /// ```
 ///
+/// ## Scaled text
+///
+/// Large text can be drawn by scaling a smaller font. This example draws text that is twice as
+/// wide and three times as high as the [`FONT_10X20`] font, which results in 20x60 pixel sized
+/// characters.
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_10X20, MonoTextStyle, MonoTextStyleBuilder},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MonoTextStyleBuilder::new()
+///     .font(&FONT_10X20)
+///     .text_color(Rgb565::WHITE)
+///     .scale(Size::new(2, 3))
+///     .build();
+///
+/// let text = Text::new("12.5", Point::new(0, 60), style);
+/// ```
+///
+/// ## Outline and shadow
+///
+/// An outline or a drop shadow can be used to make text legible on top of images. This example
+/// draws white text with a black outline and a dark gray shadow, which is offset by 2 pixels to the
+/// right and bottom.
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X9, MonoTextStyle, MonoTextStyleBuilder},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MonoTextStyleBuilder::new()
+///     .font(&FONT_6X9)
+///     .text_color(Rgb565::WHITE)
+///     .outline_color(Rgb565::BLACK)
+///     .shadow_color(Rgb565::new(8, 16, 8))
+///     .shadow_offset(Point::new(2, 2))
+///     .build();
+///
+/// let text = Text::new("Map label", Point::new(10, 10), style);
+/// ```
+///
+/// ## Fallback fonts
+///
+/// Characters that aren't included in the primary font can be drawn using fallback fonts. This
+/// example draws a label which mixes ASCII, Cyrillic and Greek characters on a single line.
+/// Setting the font with [`font`](MonoTextStyleBuilder::font()) removes the fallback fonts, which
+/// is why it is called first.
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, iso_8859_5, iso_8859_7, MonoTextStyle, MonoTextStyleBuilder},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::Text,
+/// };
+///
+/// let style = MonoTextStyleBuilder::new()
+///     .font(&FONT_6X10)
+///     .fallback_fonts(&[&iso_8859_5::FONT_6X10, &iso_8859_7::FONT_6X10])
+///     .text_color(Rgb565::WHITE)
+///     .build();
+///
+/// let text = Text::new("Temp: Ωмега", Point::new(0, 10), style);
+/// ```
+///
 /// [`FONT_6X9`]: crate::mono_font::ascii::FONT_6X9
+/// [`FONT_10X20`]: crate::mono_font::ascii::FONT_10X20
 /// [other fonts]: super
 /// [`Text`]: crate::text::Text
 #[derive(Copy, Clone, Debug)]
@@ -416,11 +704,22 @@ where
                 text_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                scale: Size::new(1, 1),
+                outline_color: None,
+                shadow_color: None,
+                shadow_offset: Point::new(1, 1),
+                fallback_fonts: &[],
             },
         }
     }
 
     /// Sets the font.
+    ///
+    /// The returned builder uses the lifetime of the new font, which makes it possible to replace a
+    /// short lived font by a font with a longer lifetime. The
+    /// [fallback fonts](MonoTextStyleBuilder::fallback_fonts()) can't be converted to the new
+    /// lifetime and are removed. Use [`primary_font`](MonoTextStyleBuilder::primary_font()) to
+    /// change the font without removing the fallback fonts.
     pub const fn font<'b>(self, font: &'b MonoFont<'b>) -> MonoTextStyleBuilder<'b, C> {
         let style = MonoTextStyle {
             font,
@@ -428,11 +727,26 @@ where
             text_color: self.style.text_color,
             underline_color: self.style.underline_color,
             strikethrough_color: self.style.strikethrough_color,
+            scale: self.style.scale,
+            outline_color: self.style.outline_color,
+            shadow_color: self.style.shadow_color,
+            shadow_offset: self.style.shadow_offset,
+            fallback_fonts: &[],
         };
 
         MonoTextStyleBuilder { style }
     }
 
+    /// Sets the font without changing the lifetime of the builder.
+    ///
+    /// Unlike [`font`](MonoTextStyleBuilder::font()), this method keeps the
+    /// [fallback fonts](MonoTextStyleBuilder::fallback_fonts()).
+    pub const fn primary_font(mut self, font: &'a MonoFont<'a>) -> Self {
+        self.style.font = font;
+
+        self
+    }
+
     /// Enables underline using the text color.
     pub const fn underline(mut self) -> Self {
         self.style.underline_color = DecorationColor::TextColor;
@@ -503,6 +817,73 @@ where
         self
     }
 
+    /// Sets the outline color.
+    ///
+    /// A one pixel wide outline in the given color is drawn around each glyph.
+    pub const fn outline_color(mut self, outline_color: C) -> Self {
+        self.style.outline_color = Some(outline_color);
+
+        self
+    }
+
+    /// Removes the outline.
+    pub const fn reset_outline_color(mut self) -> Self {
+        self.style.outline_color = None;
+
+        self
+    }
+
+    /// Sets the shadow color.
+    ///
+    /// A copy of each glyph is drawn in the given color behind the text. The default offset of
+    /// the shadow is `(1, 1)`, which can be changed by using the [`shadow_offset`] method.
+    ///
+    /// [`shadow_offset`]: MonoTextStyleBuilder::shadow_offset()
+    pub const fn shadow_color(mut self, shadow_color: C) -> Self {
+        self.style.shadow_color = Some(shadow_color);
+
+        self
+    }
+
+    /// Sets the shadow offset.
+    pub const fn shadow_offset(mut self, shadow_offset: Point) -> Self {
+        self.style.shadow_offset = shadow_offset;
+
+        self
+    }
+
+    /// Removes the shadow.
+    pub const fn reset_shadow_color(mut self) -> Self {
+        self.style.shadow_color = None;
+
+        self
+    }
+
+    /// Sets the fallback fonts.
+    ///
+    /// Characters that aren't included in the primary font are drawn using the first fallback font
+    /// that contains the character.
+    ///
+    /// The fallback fonts are removed if the primary font is changed afterwards by calling
+    /// [`font`](MonoTextStyleBuilder::font()), but are kept by
+    /// [`primary_font`](MonoTextStyleBuilder::primary_font()).
+    pub const fn fallback_fonts(mut self, fallback_fonts: &'a [&'a MonoFont<'a>]) -> Self {
+        self.style.fallback_fonts = fallback_fonts;
+
+        self
+    }
+
+    /// Sets the integer scale factor.
+    ///
+    /// Each pixel of a glyph will be drawn as a block of `scale.width` by `scale.height` pixels.
+    /// This can be used to draw large text without the need for additional large fonts. A scale
+    /// factor of `Size::new(1, 1)` disables scaling.
+    pub const fn scale(mut self, scale: Size) -> Self {
+        self.style.scale = scale;
+
+        self
+    }
+
     /// Builds the text style.
     ///
     /// This method can only be called after a font was set by using the [`font`] method. All other
@@ -533,11 +914,12 @@ mod tests {
         mono_font::{
             ascii::{FONT_10X20, FONT_6X9},
             iso_8859_1::FONT_6X9 as FONT_6X9_LATIN1,
-            mapping,
+            iso_8859_5, iso_8859_7, jis_x0201, mapping,
             tests::*,
             DecorationDimensions,
         },
         pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::PointsIter,
         text::Text,
         Drawable,
     };
@@ -566,6 +948,11 @@ mod tests {
                 background_color: None,
                 underline_color: DecorationColor::None,
                 strikethrough_color: DecorationColor::None,
+                scale: Size::new(1, 1),
+                outline_color: None,
+                shadow_color: None,
+                shadow_offset: Point::new(1, 1),
+                fallback_fonts: &[],
             }
         );
     }
@@ -1003,6 +1390,11 @@ mod tests {
                 underline_color: DecorationColor::TextColor,
                 strikethrough_color: DecorationColor::Custom(BinaryColor::On),
                 font: &FONT_6X9,
+                scale: Size::new(1, 1),
+                outline_color: None,
+                shadow_color: None,
+                shadow_offset: Point::new(1, 1),
+                fallback_fonts: &[],
             }
         );
     }
@@ -1223,4 +1615,388 @@ mod tests {
             style2
         };
     }
+
+    /// Scales the content of a mock display by an integer factor.
+    fn scale_display(display: &MockDisplay<BinaryColor>, scale: Size) -> MockDisplay<BinaryColor> {
+        let mut scaled = MockDisplay::new();
+
+        for point in display.affected_area().points() {
+            let area = Rectangle::new(
+                point.component_mul(Point::new(scale.width as i32, scale.height as i32)),
+                scale,
+            );
+            scaled.set_pixels(area.points(), display.get_pixel(point));
+        }
+
+        scaled
+    }
+
+    #[test]
+    fn scaled_text() {
+        let scale = Size::new(2, 3);
+
+        for builder in [
+            MonoTextStyleBuilder::new().text_color(BinaryColor::On),
+            MonoTextStyleBuilder::new().background_color(BinaryColor::On),
+            MonoTextStyleBuilder::new()
+                .text_color(BinaryColor::On)
+                .background_color(BinaryColor::Off),
+            MonoTextStyleBuilder::new()
+                .text_color(BinaryColor::On)
+                .background_color(BinaryColor::Off)
+                .underline_with_color(BinaryColor::On)
+                .strikethrough(),
+        ] {
+            for font in [&FONT_6X9, &SPACED_FONT] {
+                let style = builder.font(font).build();
+                let scaled_style = builder.font(font).scale(scale).build();
+
+                let mut expected = MockDisplay::new();
+                expected.set_allow_overdraw(true);
+                let text = Text::with_baseline("Ag", Point::zero(), style, Baseline::Top);
+                text.draw(&mut expected).unwrap();
+
+                let mut display = MockDisplay::new();
+                display.set_allow_overdraw(true);
+                let scaled_text =
+                    Text::with_baseline("Ag", Point::zero(), scaled_style, Baseline::Top);
+                scaled_text.draw(&mut display).unwrap();
+
+                display.assert_eq(&scale_display(&expected, scale));
+
+                let bounding_box = text.bounding_box();
+                assert_eq!(
+                    scaled_text.bounding_box(),
+                    Rectangle::new(
+                        bounding_box.top_left,
+                        bounding_box.size.component_mul(scale)
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_metrics() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .scale(Size::new(3, 2))
+            .build();
+
+        assert_eq!(style.line_height(), 9 * 2);
+
+        let metrics = style.measure_string("AB", Point::new(1, 20), Baseline::Alphabetic);
+        assert_eq!(
+            metrics,
+            TextMetrics {
+                bounding_box: Rectangle::new(Point::new(1, 20 - 13), Size::new(2 * 6 * 3, 9 * 2)),
+                next_position: Point::new(1 + 2 * 6 * 3, 20),
+            }
+        );
+
+        let mut display = MockDisplay::new();
+        let next = Text::new("AB", Point::new(1, 20), style)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(next, metrics.next_position);
+
+        // The bottom edge of 'A' must be on the baseline.
+        assert_eq!(display.affected_area().bottom_right().unwrap().y, 20);
+    }
+
+    #[test]
+    fn scaled_whitespace() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .background_color(BinaryColor::On)
+            .underline_with_color(BinaryColor::Off)
+            .scale(Size::new(2, 2))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let next = style
+            .draw_whitespace(3, Point::zero(), Baseline::Top, &mut display)
+            .unwrap();
+        assert_eq!(next, Point::new(3, 0));
+
+        let mut pattern = ["###"; 16].to_vec();
+        pattern.extend_from_slice(&["...", "..."]);
+        display.assert_pattern(&pattern);
+    }
+
+    #[test]
+    fn outline() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .outline_color(BinaryColor::Off)
+            .build();
+
+        let text = Text::with_baseline("T", Point::new(1, 1), style, Baseline::Top);
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::zero(), Size::new(8, 11))
+        );
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        text.draw(&mut display).unwrap();
+
+        display.assert_pattern(&[
+            "       ", //
+            ".......", //
+            ".#####.", //
+            "...#...", //
+            "  .#.  ", //
+            "  .#.  ", //
+            "  .#.  ", //
+            "  .#.  ", //
+            "  ...  ", //
+        ]);
+    }
+
+    #[test]
+    fn shadow() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .shadow_color(BinaryColor::Off)
+            .shadow_offset(Point::new(2, 1))
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        Text::with_baseline("T", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "       ", //
+            "#####  ", //
+            "  #....", //
+            "  # .  ", //
+            "  # .  ", //
+            "  # .  ", //
+            "  # .  ", //
+            "    .  ", //
+        ]);
+    }
+
+    #[test]
+    fn effects_bounding_box() {
+        for builder in [
+            MonoTextStyleBuilder::new().outline_color(Rgb888::RED),
+            MonoTextStyleBuilder::new().shadow_color(Rgb888::RED),
+            MonoTextStyleBuilder::new()
+                .shadow_color(Rgb888::RED)
+                .shadow_offset(Point::new(-2, 3)),
+            MonoTextStyleBuilder::new()
+                .outline_color(Rgb888::RED)
+                .shadow_color(Rgb888::BLUE)
+                .shadow_offset(Point::new(3, -2))
+                .underline(),
+        ] {
+            let style = builder
+                .font(&FONT_6X9)
+                .text_color(Rgb888::WHITE)
+                .background_color(Rgb888::BLACK)
+                .build();
+
+            let text = Text::new("AB", Point::new(10, 20), style);
+
+            let mut display = MockDisplay::new();
+            display.set_allow_overdraw(true);
+            text.draw(&mut display).unwrap();
+
+            // The bounding box is based on the glyph cells and not on the actually drawn pixels.
+            let bounding_box = text.bounding_box();
+            assert_eq!(
+                bounding_box.envelope(&display.affected_area()),
+                bounding_box
+            );
+        }
+    }
+
+    #[test]
+    fn effects_next_position() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .outline_color(BinaryColor::On)
+            .shadow_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let next = Text::new("AB", Point::new(10, 20), style)
+            .draw(&mut display)
+            .unwrap();
+
+        assert_eq!(next, Point::new(10 + 2 * 6, 20));
+        assert!(!style.is_transparent());
+    }
+
+    #[test]
+    fn builder_reset_effects() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .outline_color(BinaryColor::Off)
+            .shadow_color(BinaryColor::Off)
+            .reset_outline_color()
+            .reset_shadow_color()
+            .build();
+
+        assert_eq!(style, MonoTextStyle::new(&FONT_6X9, BinaryColor::On));
+    }
+
+    #[test]
+    fn fallback_fonts() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .fallback_fonts(&[&iso_8859_7::FONT_6X9, &iso_8859_5::FONT_6X9])
+            .build();
+
+        let mut display = MockDisplay::new();
+        let next = Text::with_baseline("AΩЖ", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(next, Point::new(18, 0));
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline(
+            "A",
+            Point::zero(),
+            MonoTextStyle::new(&FONT_6X9, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+        Text::with_baseline(
+            "Ω",
+            Point::new(6, 0),
+            MonoTextStyle::new(&iso_8859_7::FONT_6X9, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+        Text::with_baseline(
+            "Ж",
+            Point::new(12, 0),
+            MonoTextStyle::new(&iso_8859_5::FONT_6X9, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn fallback_fonts_missing_char() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .fallback_fonts(&[&iso_8859_5::FONT_6X9])
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline("Ω", Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Text::with_baseline(
+            "?",
+            Point::zero(),
+            MonoTextStyle::new(&FONT_6X9, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn fallback_fonts_baseline() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .fallback_fonts(&[&jis_x0201::FONT_6X13])
+            .build();
+
+        let mut display = MockDisplay::new();
+        Text::new("Aｱ", Point::new(0, 20), style)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Text::new(
+            "A",
+            Point::new(0, 20),
+            MonoTextStyle::new(&FONT_6X9, BinaryColor::On),
+        )
+        .draw(&mut expected)
+        .unwrap();
+        Text::new(
+            "ｱ",
+            Point::new(6, 20),
+            MonoTextStyle::new(&jis_x0201::FONT_6X13, BinaryColor::On),
+        )
+        .draw(&mut expected)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn fallback_fonts_metrics() {
+        let style = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .fallback_fonts(&[&iso_8859_5::FONT_10X20])
+            .build();
+
+        let metrics = style.measure_string("AЖA", Point::zero(), Baseline::Top);
+        assert_eq!(
+            metrics.bounding_box,
+            Rectangle::new(Point::zero(), Size::new(22, 9))
+        );
+        assert_eq!(metrics.next_position, Point::new(22, 0));
+    }
+
+    #[test]
+    fn fallback_fonts_builder_order() {
+        let fallback_fonts: &[&MonoFont] = &[&iso_8859_5::FONT_6X9];
+
+        let expected = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .fallback_fonts(fallback_fonts)
+            .build();
+
+        let style = MonoTextStyleBuilder::new()
+            .text_color(BinaryColor::On)
+            .fallback_fonts(fallback_fonts)
+            .primary_font(&FONT_6X9)
+            .build();
+        assert_eq!(style, expected);
+
+        // `font` changes the lifetime of the builder and removes the fallback fonts.
+        let style = MonoTextStyleBuilder::new()
+            .text_color(BinaryColor::On)
+            .fallback_fonts(fallback_fonts)
+            .font(&FONT_6X9)
+            .build();
+        assert!(style.fallback_fonts.is_empty());
+
+        let style = MonoTextStyleBuilder::from(&expected)
+            .primary_font(&FONT_10X20)
+            .build();
+        assert!(core::ptr::eq(style.font, &FONT_10X20));
+        assert_eq!(style.fallback_fonts, expected.fallback_fonts);
+    }
 }
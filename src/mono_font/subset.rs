@@ -0,0 +1,324 @@
+//! Font subsets.
+//!
+//! The built-in fonts contain all glyphs of a glyph subset, like ASCII or ISO 8859-1, even if an
+//! application only uses a few characters. The [`mono_font_subset`] macro creates a trimmed copy of
+//! a font at compile time, which only contains the glyphs for the given characters. Only the glyph
+//! image of the subset font is included in the binary, as long as the original font isn't used
+//! elsewhere in the application.
+//!
+//! The functions in this module are used by the [`mono_font_subset`] macro and aren't normally used
+//! directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use embedded_graphics::{
+//!     mono_font::{iso_8859_1::FONT_10X20, mapping::ISO_8859_1, MonoFont, MonoTextStyle},
+//!     mono_font_subset,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::Text,
+//! };
+//! # let mut display = embedded_graphics::mock_display::MockDisplay::<BinaryColor>::new();
+//! # display.set_allow_out_of_bounds_drawing(true);
+//!
+//! /// Font which only contains the glyphs required to display a time or a temperature.
+//! const FONT_READOUT: MonoFont = mono_font_subset!(FONT_10X20, ISO_8859_1, "0123456789.-:");
+//!
+//! let style = MonoTextStyle::new(&FONT_READOUT, BinaryColor::On);
+//! Text::new("12:34", Point::new(0, 20), style).draw(&mut display)?;
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
+//! [`mono_font_subset`]: crate::mono_font_subset
+
+use crate::{
+    geometry::Size,
+    mono_font::{
+        mapping::{decode_char, StrGlyphMapping},
+        MonoFont,
+    },
+};
+
+/// Creates a subset of a monospaced font at compile time.
+///
+/// The macro takes three arguments: the original [`MonoFont`], the [`StrGlyphMapping`] that is
+/// used by the original font and a string literal or constant which contains the characters to
+/// include in the subset. The macro expands to a constant expression of type `MonoFont<'static>`,
+/// which can be used to initialize a `const` or `static` item.
+///
+/// The order of the glyphs in the subset is the same as the order of the characters in the string.
+/// Characters which aren't included in the subset are drawn using the glyph for `?`, if it is
+/// included in the subset, or the first glyph in the subset otherwise.
+///
+/// Compilation fails if the string is empty or contains duplicate characters or `\0`.
+///
+/// See the [`subset` module documentation] for an example.
+///
+/// [`MonoFont`]: crate::mono_font::MonoFont
+/// [`StrGlyphMapping`]: crate::mono_font::mapping::StrGlyphMapping
+/// [`subset` module documentation]: crate::mono_font::subset
+#[macro_export]
+macro_rules! mono_font_subset {
+    ($font:expr, $mapping:expr, $chars:expr $(,)?) => {{
+        const FONT: $crate::mono_font::MonoFont<'static> = $font;
+        const MAPPING: $crate::mono_font::mapping::StrGlyphMapping<'static> = $mapping;
+        const CHARS: &str = $chars;
+
+        const SIZE: $crate::geometry::Size = $crate::mono_font::subset::image_size(&FONT, CHARS);
+        const DATA: [u8; $crate::mono_font::subset::image_data_len(&FONT, CHARS)] =
+            $crate::mono_font::subset::image_data(&FONT, &MAPPING, CHARS);
+        const SUBSET_MAPPING: $crate::mono_font::mapping::StrGlyphMapping<'static> =
+            $crate::mono_font::subset::glyph_mapping(CHARS);
+
+        $crate::mono_font::MonoFont {
+            image: $crate::image::ImageRaw::new_const(&DATA, SIZE),
+            glyph_mapping: &SUBSET_MAPPING,
+            character_size: FONT.character_size,
+            character_spacing: FONT.character_spacing,
+            baseline: FONT.baseline,
+            strikethrough: FONT.strikethrough,
+            underline: FONT.underline,
+        }
+    }};
+}
+
+/// Returns the size of the glyph image for a font subset.
+///
+/// All glyphs are stored in a single row.
+///
+/// # Panics
+///
+/// Panics if `chars` is empty or contains duplicate characters or `\0`.
+pub const fn image_size(font: &MonoFont<'_>, chars: &str) -> Size {
+    let count = char_count(chars);
+
+    Size::new(
+        font.character_size.width * count as u32,
+        font.character_size.height,
+    )
+}
+
+/// Returns the length of the glyph image data for a font subset in bytes.
+///
+/// # Panics
+///
+/// Panics if `chars` is empty or contains duplicate characters or `\0`.
+pub const fn image_data_len(font: &MonoFont<'_>, chars: &str) -> usize {
+    let size = image_size(font, chars);
+
+    size.width.div_ceil(8) as usize * size.height as usize
+}
+
+/// Returns the glyph image data for a font subset.
+///
+/// The glyphs are copied from the image of the original font. `mapping` must be the glyph mapping
+/// that is used by `font`.
+///
+/// # Panics
+///
+/// Panics if `N` doesn't match the value returned by [`image_data_len`] or if `chars` is empty or
+/// contains duplicate characters or `\0`.
+pub const fn image_data<const N: usize>(
+    font: &MonoFont<'_>,
+    mapping: &StrGlyphMapping<'_>,
+    chars: &str,
+) -> [u8; N] {
+    assert!(
+        N == image_data_len(font, chars),
+        "invalid image data length"
+    );
+
+    let mut data = [0; N];
+
+    let glyph_width = font.character_size.width;
+    let glyph_height = font.character_size.height;
+    if glyph_width == 0 {
+        return data;
+    }
+
+    let source = font.image.data();
+    let source_bytes_per_row = font.image.size.width.div_ceil(8) as usize;
+    let source_glyphs_per_row = font.image.size.width / glyph_width;
+
+    let target_width = image_size(font, chars).width;
+    let target_bytes_per_row = target_width.div_ceil(8) as usize;
+
+    let bytes = chars.as_bytes();
+    let mut i = 0;
+    let mut target_index = 0;
+    while i < bytes.len() {
+        let (c, len) = decode_char(bytes, i);
+        i += len;
+
+        let source_index = match char::from_u32(c) {
+            Some(c) => mapping.const_index(c) as u32,
+            None => 0,
+        };
+        let source_x = (source_index % source_glyphs_per_row) * glyph_width;
+        let source_y = (source_index / source_glyphs_per_row) * glyph_height;
+        let target_x = target_index * glyph_width;
+
+        let mut y = 0;
+        while y < glyph_height {
+            let mut x = 0;
+            while x < glyph_width {
+                let sx = (source_x + x) as usize;
+                let sy = (source_y + y) as usize;
+                let source_byte = source[sy * source_bytes_per_row + sx / 8];
+
+                if source_byte & (0x80 >> (sx % 8)) != 0 {
+                    let tx = (target_x + x) as usize;
+                    data[y as usize * target_bytes_per_row + tx / 8] |= 0x80 >> (tx % 8);
+                }
+
+                x += 1;
+            }
+            y += 1;
+        }
+
+        target_index += 1;
+    }
+
+    data
+}
+
+/// Returns the glyph mapping for a font subset.
+///
+/// # Panics
+///
+/// Panics if `chars` is empty or contains duplicate characters or `\0`.
+pub const fn glyph_mapping(chars: &str) -> StrGlyphMapping<'_> {
+    // Validate the characters.
+    char_count(chars);
+
+    let replacement_index = match char_index(chars, '?' as u32) {
+        Some(index) => index,
+        None => 0,
+    };
+
+    StrGlyphMapping::new(chars, replacement_index)
+}
+
+/// Returns the number of characters in a string.
+///
+/// # Panics
+///
+/// Panics if `chars` is empty or contains duplicate characters or `\0`.
+const fn char_count(chars: &str) -> usize {
+    let bytes = chars.as_bytes();
+
+    let mut i = 0;
+    let mut count = 0;
+    while i < bytes.len() {
+        let (c, len) = decode_char(bytes, i);
+
+        assert!(c != '\0' as u32, "font subsets cannot contain '\\0'");
+        match char_index(chars, c) {
+            Some(index) if index == count => {}
+            _ => panic!("font subsets cannot contain duplicate characters"),
+        }
+
+        i += len;
+        count += 1;
+    }
+
+    assert!(
+        count > 0,
+        "font subsets must contain at least one character"
+    );
+
+    count
+}
+
+/// Returns the index of the first occurrence of a character in a string.
+const fn char_index(chars: &str, c: u32) -> Option<usize> {
+    let bytes = chars.as_bytes();
+
+    let mut i = 0;
+    let mut index = 0;
+    while i < bytes.len() {
+        let (value, len) = decode_char(bytes, i);
+        if value == c {
+            return Some(index);
+        }
+
+        i += len;
+        index += 1;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Point,
+        mock_display::MockDisplay,
+        mono_font::{
+            ascii::FONT_6X9,
+            iso_8859_1::FONT_10X20,
+            mapping::{ASCII, ISO_8859_1},
+            MonoTextStyle,
+        },
+        pixelcolor::BinaryColor,
+        text::{Baseline, Text},
+        Drawable,
+    };
+
+    const FONT_DIGITS: MonoFont = crate::mono_font_subset!(FONT_10X20, ISO_8859_1, "0123456789.-:");
+
+    fn draw(text: &str, font: &MonoFont) -> MockDisplay<BinaryColor> {
+        let mut display = MockDisplay::new();
+        Text::with_baseline(
+            text,
+            Point::zero(),
+            MonoTextStyle::new(font, BinaryColor::On),
+            Baseline::Top,
+        )
+        .draw(&mut display)
+        .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn image_size() {
+        assert_eq!(FONT_DIGITS.image.size, Size::new(130, 20));
+        assert_eq!(FONT_DIGITS.image.data().len(), 17 * 20);
+    }
+
+    #[test]
+    fn same_glyphs() {
+        draw("-12.5", &FONT_DIGITS).assert_eq(&draw("-12.5", &FONT_10X20));
+        draw("09:38", &FONT_DIGITS).assert_eq(&draw("09:38", &FONT_10X20));
+    }
+
+    #[test]
+    fn replacement_glyph() {
+        // Missing chars are replaced by the first glyph if `?` isn't included.
+        draw("A", &FONT_DIGITS).assert_eq(&draw("0", &FONT_10X20));
+
+        const FONT: MonoFont = crate::mono_font_subset!(FONT_6X9, ASCII, "AB?");
+        draw("ABC", &FONT).assert_eq(&draw("AB?", &FONT_6X9));
+    }
+
+    #[test]
+    fn non_ascii_chars() {
+        const FONT: MonoFont = crate::mono_font_subset!(FONT_10X20, ISO_8859_1, "°C");
+        draw("°C", &FONT).assert_eq(&draw("°C", &FONT_10X20));
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate characters")]
+    fn duplicate_chars() {
+        glyph_mapping("abca");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one character")]
+    fn empty() {
+        glyph_mapping("");
+    }
+}
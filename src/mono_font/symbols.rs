@@ -0,0 +1,107 @@
+//! Symbol and icon glyph subset.
+//!
+//! The fonts in this module contain arrows, media controls, check marks and status icons for
+//! battery, Wi-Fi and Bluetooth indicators. Characters that have a Unicode equivalent use their
+//! Unicode code point, all other icons are placed in the Unicode Private Use Area. The constants in
+//! this module can be used to refer to the individual characters.
+//!
+//! | Type | Screenshot |
+//! |------|------------|
+//! | `FONT_8X8` | ![FONT_8X8](data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAIAAAAAQAQAAAAD2I0olAAAAhElEQVR42mNgEABCZCBgwSHQtSinxoHp2B8QYDCo4RE49yynpoAtpwUIpjDUC/wTePcup6amx6Jt2bY92xgMBHhqwCpu5IAEljEICHBYgM0oOAbSsoQBbosCA8RQrEAAjV8j8Q9VoCmkCYVvYWGBLKDB4OKCIgAyEEWLhoCAALqhKNYCABlgNIDWsHXBAAAAAElFTkSuQmCC) |
+//!
+//! # Examples
+//!
+//! Symbol fonts don't contain any letters or digits. To draw icons inline with other text, the
+//! symbol font can be used as a [fallback font](super::MonoTextStyle::fallback_fonts).
+//!
+//! ```
+//! use embedded_graphics::{
+//!     mono_font::{ascii::FONT_6X10, symbols, MonoTextStyleBuilder},
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::Text,
+//! };
+//! # let mut display = embedded_graphics::mock_display::MockDisplay::<BinaryColor>::new();
+//! # display.set_allow_out_of_bounds_drawing(true);
+//!
+//! let style = MonoTextStyleBuilder::new()
+//!     .font(&FONT_6X10)
+//!     .fallback_fonts(&[&symbols::FONT_8X8])
+//!     .text_color(BinaryColor::On)
+//!     .build();
+//!
+//! // `\u{e003}` is the `BATTERY_FULL` icon.
+//! Text::new("\u{e003} 95%", Point::new(0, 10), style).draw(&mut display)?;
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+
+use crate::{
+    geometry::Size,
+    image::ImageRaw,
+    mono_font::{mapping, DecorationDimensions, MonoFont},
+};
+
+/// Leftwards arrow (`U+2190`).
+pub const ARROW_LEFT: char = '\u{2190}';
+/// Upwards arrow (`U+2191`).
+pub const ARROW_UP: char = '\u{2191}';
+/// Rightwards arrow (`U+2192`).
+pub const ARROW_RIGHT: char = '\u{2192}';
+/// Downwards arrow (`U+2193`).
+pub const ARROW_DOWN: char = '\u{2193}';
+
+/// Next track (`U+23ED`).
+pub const NEXT_TRACK: char = '\u{23ED}';
+/// Previous track (`U+23EE`).
+pub const PREVIOUS_TRACK: char = '\u{23EE}';
+/// Pause (`U+23F8`).
+pub const PAUSE: char = '\u{23F8}';
+/// Stop (`U+23F9`).
+pub const STOP: char = '\u{23F9}';
+/// Play (`U+25B6`).
+pub const PLAY: char = '\u{25B6}';
+
+/// Check mark (`U+2713`).
+pub const CHECK_MARK: char = '\u{2713}';
+/// Cross mark (`U+2717`).
+pub const CROSS_MARK: char = '\u{2717}';
+
+/// Empty battery (`U+E000`).
+pub const BATTERY_EMPTY: char = '\u{E000}';
+/// Battery with low charge (`U+E001`).
+pub const BATTERY_LOW: char = '\u{E001}';
+/// Battery with medium charge (`U+E002`).
+pub const BATTERY_MEDIUM: char = '\u{E002}';
+/// Full battery (`U+E003`).
+pub const BATTERY_FULL: char = '\u{E003}';
+/// Charging battery (`U+E004`).
+pub const BATTERY_CHARGING: char = '\u{E004}';
+
+/// Wi-Fi disconnected (`U+E005`).
+pub const WIFI_OFF: char = '\u{E005}';
+/// Wi-Fi with weak signal (`U+E006`).
+pub const WIFI_WEAK: char = '\u{E006}';
+/// Wi-Fi with medium signal (`U+E007`).
+pub const WIFI_MEDIUM: char = '\u{E007}';
+/// Wi-Fi with strong signal (`U+E008`).
+pub const WIFI_STRONG: char = '\u{E008}';
+
+/// Bluetooth (`U+E009`).
+pub const BLUETOOTH: char = '\u{E009}';
+
+/// 8x8 pixel symbol font.
+///
+/// The bottom row of each glyph is below the baseline. If this font is used as a fallback font,
+/// the symbols are aligned to the baseline of the primary font.
+pub const FONT_8X8: MonoFont = MonoFont {
+    image: ImageRaw::new_const(
+        include_bytes!("../../fonts/raw/symbols/font_8x8.raw"),
+        Size::new(128, 16),
+    ),
+    glyph_mapping: &mapping::SYMBOLS,
+    character_size: Size::new(8, 8),
+    character_spacing: 0,
+    baseline: 6,
+    underline: DecorationDimensions::new(7, 1),
+    strikethrough: DecorationDimensions::new(8 / 2, 1),
+};
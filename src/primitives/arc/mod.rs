@@ -14,6 +14,9 @@ pub use styled::StyledPixelsIterator;
 
 /// Arc primitive
 ///
+/// The ends of the arc are drawn using the
+/// [`line_cap`](crate::primitives::PrimitiveStyle::line_cap) setting.
+///
 /// # Examples
 ///
 /// ## Create some arcs with different styles
@@ -22,7 +25,7 @@ pub use styled::StyledPixelsIterator;
 /// use embedded_graphics::{
 ///     pixelcolor::Rgb565,
 ///     prelude::*,
-///     primitives::{Arc, PrimitiveStyle, PrimitiveStyleBuilder},
+///     primitives::{Arc, LineCap, PrimitiveStyle, PrimitiveStyleBuilder},
 /// };
 /// # use embedded_graphics::mock_display::MockDisplay;
 /// # let mut display = MockDisplay::default();
@@ -41,6 +44,17 @@ pub use styled::StyledPixelsIterator;
 /// Arc::new(Point::new(15, 25), 20, 180.0.deg(), -90.0.deg())
 ///     .into_styled(style)
 ///     .draw(&mut display)?;
+///
+/// // Progress ring with round caps, centered at (50, 14) with a diameter of 20
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::GREEN)
+///     .stroke_width(6)
+///     .line_cap(LineCap::Round)
+///     .build();
+///
+/// Arc::with_center(Point::new(50, 14), 20, -90.0.deg(), 240.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
 /// # Ok::<(), core::convert::Infallible>(())
 /// ```
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
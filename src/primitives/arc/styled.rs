@@ -1,10 +1,10 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::Dimensions,
+    geometry::{Dimensions, Real},
     pixelcolor::PixelColor,
     primitives::{
         arc::Arc,
-        common::{DistanceIterator, PlaneSector},
+        common::{ArcCaps, DistanceIterator, PlaneSector},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         OffsetOutline, PrimitiveStyle, Rectangle,
     },
@@ -20,6 +20,7 @@ pub struct StyledPixelsIterator<C> {
     iter: DistanceIterator,
 
     plane_sector: PlaneSector,
+    caps: Option<ArcCaps>,
 
     outer_threshold: u32,
     inner_threshold: u32,
@@ -36,16 +37,30 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
 
         let iter = if !style.is_transparent() {
             // PERF: The distance iterator should use the smaller arc bounding box
-            outside_edge.distances()
+            let cap_offset = ArcCaps::outside_offset(style).saturating_as();
+            DistanceIterator::new(
+                circle.center_2x(),
+                &outside_edge.bounding_box().offset(cap_offset),
+            )
         } else {
             DistanceIterator::empty()
         };
 
         let plane_sector = PlaneSector::new(primitive.angle_start, primitive.angle_sweep);
 
+        // The center line of the stroke lies halfway between the inside and outside edge.
+        let radius = Real::from(outside_edge.diameter + inside_edge.diameter) / Real::from(2);
+        let caps = ArcCaps::new(
+            primitive.angle_start,
+            primitive.angle_sweep,
+            (radius, radius),
+            style,
+        );
+
         Self {
             iter,
             plane_sector,
+            caps,
             outer_threshold: outside_edge.threshold(),
             inner_threshold: inside_edge.threshold(),
             stroke_color: style.stroke_color,
@@ -61,9 +76,10 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
 
         self.iter
             .find(|(_, delta, distance)| {
-                *distance < self.outer_threshold
+                (*distance < self.outer_threshold
                     && *distance >= self.inner_threshold
-                    && self.plane_sector.contains(*delta)
+                    && self.plane_sector.contains(*delta))
+                    || self.caps.is_some_and(|caps| caps.contains(*delta))
             })
             .map(|(point, ..)| Pixel(point, stroke_color))
     }
@@ -96,7 +112,8 @@ impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for Arc {
 impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Arc {
     // FIXME: This doesn't take into account start/end angles. This should be fixed to close #405.
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
-        let offset = style.outside_stroke_width().saturating_as();
+        let offset =
+            (style.outside_stroke_width() + ArcCaps::outside_offset(style)).saturating_as();
 
         self.bounding_box().offset(offset)
     }
@@ -110,7 +127,9 @@ mod tests {
         geometry::{AnchorPoint, AngleUnit, Point, Size},
         mock_display::MockDisplay,
         pixelcolor::BinaryColor,
-        primitives::{Circle, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            Circle, LineCap, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
         Drawable,
     };
 
@@ -259,4 +278,105 @@ mod tests {
 
         assert_eq!(transparent_arc.bounding_box(), stroked_arc.bounding_box(),);
     }
+
+    fn capped_arc(line_cap: LineCap) -> MockDisplay<BinaryColor> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .line_cap(line_cap)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Arc::new(Point::new(2, 2), 11, 0.0.deg(), 90.0.deg())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn round_cap() {
+        capped_arc(LineCap::Round).assert_pattern(&[
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "            # ",
+            "           ###",
+            "           ###",
+            "          ####",
+            "         #### ",
+            "       ###### ",
+            "      ######  ",
+            "       ###    ",
+        ]);
+    }
+
+    #[test]
+    fn square_cap() {
+        capped_arc(LineCap::Square).assert_pattern(&[
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "              ",
+            "           ###",
+            "           ###",
+            "           ###",
+            "          ####",
+            "         #### ",
+            "      ####### ",
+            "      ######  ",
+            "      ####    ",
+        ]);
+    }
+
+    #[test]
+    fn caps_are_ignored_for_full_circles() {
+        let arc = Arc::new(Point::new(2, 2), 11, 45.0.deg(), 360.0.deg());
+
+        for line_cap in [LineCap::Round, LineCap::Square] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .line_cap(line_cap)
+                .build();
+
+            assert!(arc.into_styled(style).pixels().eq(arc
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+                .pixels()));
+        }
+    }
+
+    #[test]
+    fn caps_are_inside_bounding_box() {
+        for line_cap in [LineCap::Round, LineCap::Square] {
+            for stroke_width in [2, 3, 6] {
+                for angle_start in [0.0, 30.0, 135.0, 200.0, 315.0] {
+                    let style = PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .line_cap(line_cap)
+                        .build();
+                    let styled =
+                        Arc::with_center(Point::new(20, 20), 20, angle_start.deg(), 100.0.deg())
+                            .into_styled(style);
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+
+                    let affected_area = display.affected_area();
+                    assert_eq!(
+                        affected_area.intersection(&styled.bounding_box()),
+                        affected_area,
+                        "{line_cap:?}, {stroke_width} px, {angle_start}°"
+                    );
+                }
+            }
+        }
+    }
 }
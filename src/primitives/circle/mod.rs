@@ -3,9 +3,10 @@
 use crate::{
     geometry::{Dimensions, Point, PointExt, Size},
     primitives::{
-        common::DistanceIterator, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
+        common::DistanceIterator, ContainsPoint, Ellipse, OffsetOutline, PointsIter, Primitive,
+        Rectangle, RotatedEllipse,
     },
-    transform::Transform,
+    transform::{Affine2, AffineTransform, Transform},
 };
 
 mod points;
@@ -185,6 +186,27 @@ pub(in crate::primitives) const fn diameter_to_threshold(diameter: u32) -> u32 {
     }
 }
 
+impl AffineTransform for Circle {
+    type Output = RotatedEllipse;
+
+    /// Transforms the circle into a rotated ellipse.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Circle;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let circle = Circle::with_center(Point::new(20, 20), 11);
+    /// let ellipse = circle.transform(&Affine2::scale(3.0, 1.0).around(Point::new(20, 20)));
+    ///
+    /// assert_eq!(ellipse.ellipse.size, Size::new(33, 11));
+    /// assert_eq!(ellipse.center(), Point::new(20, 20));
+    /// ```
+    fn transform(&self, affine: &Affine2) -> RotatedEllipse {
+        Ellipse::new(self.top_left, Size::new_equal(self.diameter)).transform(affine)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
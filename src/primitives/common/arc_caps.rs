@@ -0,0 +1,123 @@
+use crate::{
+    geometry::{angle_consts::ANGLE_360DEG, Angle, Point, PointExt, Real, Trigonometry},
+    pixelcolor::PixelColor,
+    primitives::{
+        circle::diameter_to_threshold, common::NORMAL_VECTOR_SCALE, LineCap, PrimitiveStyle,
+    },
+};
+
+/// Line caps at both ends of an arc shaped primitive.
+///
+/// The caps are centered on the center line of the stroke at the start and end angle of the arc.
+/// Square caps cover the end with a square and round caps with a circle, both with a size equal to
+/// the stroke width. The square is aligned to the tangent of the arc, which extends the stroke by
+/// half the stroke width beyond the radial end of the stroke.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ArcCaps {
+    start: ArcCap,
+    end: ArcCap,
+
+    line_cap: LineCap,
+    stroke_width: i32,
+    threshold: u32,
+}
+
+impl ArcCaps {
+    /// Creates the caps for an arc.
+    ///
+    /// `radii` are the horizontal and vertical radii of the center line of the stroke, scaled by
+    /// 2. `None` is returned if the arc has butt caps, a stroke width of one or less or if the arc
+    /// is a closed shape.
+    pub fn new<C: PixelColor>(
+        angle_start: Angle,
+        angle_sweep: Angle,
+        radii: (Real, Real),
+        style: &PrimitiveStyle<C>,
+    ) -> Option<Self> {
+        if style.line_cap == LineCap::Butt
+            || style.stroke_width <= 1
+            || angle_sweep.abs() >= ANGLE_360DEG
+        {
+            return None;
+        }
+
+        Some(Self {
+            start: ArcCap::new(angle_start, radii),
+            end: ArcCap::new(angle_start + angle_sweep, radii),
+            line_cap: style.line_cap,
+            stroke_width: style.stroke_width as i32,
+            threshold: diameter_to_threshold(style.stroke_width),
+        })
+    }
+
+    /// Returns the distance by which the caps can extend beyond the outer edge of the stroke.
+    pub fn outside_offset<C: PixelColor>(style: &PrimitiveStyle<C>) -> u32 {
+        if style.line_cap == LineCap::Square && style.stroke_width > 1 {
+            style.stroke_width.div_ceil(2)
+        } else {
+            0
+        }
+    }
+
+    /// Returns `true` if a point is inside one of the caps.
+    ///
+    /// `delta` is the offset of the point from the center of the arc, scaled by 2.
+    pub fn contains(&self, delta: Point) -> bool {
+        self.start.contains(delta, self) || self.end.contains(delta, self)
+    }
+}
+
+/// Line cap at one end of an arc.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+struct ArcCap {
+    /// Center of the end of the stroke, scaled by 2.
+    center: Point,
+
+    /// Tangent unit vector, scaled by `NORMAL_VECTOR_SCALE`.
+    tangent: Point,
+}
+
+impl ArcCap {
+    fn new(angle: Angle, radii: (Real, Real)) -> Self {
+        let (cos, sin) = (angle.cos(), angle.sin());
+        let (radius_x, radius_y) = radii;
+
+        // Distance from the center to the point on the ellipse in the direction of `angle`.
+        let divisor = (radius_y * cos).hypot(radius_x * sin);
+        let radius = if divisor == Real::from(0) {
+            Real::from(0)
+        } else {
+            radius_x * (radius_y / divisor)
+        };
+
+        let scale = Real::from(NORMAL_VECTOR_SCALE);
+
+        Self {
+            center: Point::new(
+                i32::from((cos * radius).round()),
+                i32::from((sin * radius).round()),
+            ),
+            tangent: Point::new(i32::from(-sin * scale), i32::from(cos * scale)),
+        }
+    }
+
+    fn contains(&self, delta: Point, caps: &ArcCaps) -> bool {
+        let delta = delta - self.center;
+
+        match caps.line_cap {
+            LineCap::Round => (delta.length_squared() as u32) < caps.threshold,
+            LineCap::Square => {
+                // Half the stroke width in scaled coordinates.
+                let half_width = caps.stroke_width * NORMAL_VECTOR_SCALE;
+
+                let along = delta.dot_product(self.tangent);
+                let across = delta.dot_product(self.tangent.rotate_90());
+
+                along.abs() < half_width && across.abs() < half_width
+            }
+            LineCap::Butt => false,
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, StrokeOffset, ThickSegment},
+    primitives::common::{LineJoin, StrokeOffset, ThickSegment, ThickStroke},
 };
 
 /// Closed shape thick segments iterator.
@@ -21,8 +21,7 @@ pub struct ClosedThickSegmentIter<'a> {
     windows: core::slice::Windows<'a, Point>,
     first_join: LineJoin,
     start_join: LineJoin,
-    width: u32,
-    stroke_offset: StrokeOffset,
+    stroke: ThickStroke,
     points: &'a [Point],
     stop: bool,
     idx: usize,
@@ -32,16 +31,15 @@ static EMPTY: &[Point; 0] = &[];
 
 impl<'a> ClosedThickSegmentIter<'a> {
     /// Create a new thick segments iterator.
-    pub fn new(points: &'a [Point], width: u32, stroke_offset: StrokeOffset) -> Self {
+    pub fn new(points: &'a [Point], stroke: ThickStroke) -> Self {
         if let [start, end] = points {
             // Single line segment.
-            let start_join = LineJoin::start(*start, *end, width, stroke_offset);
+            let start_join = LineJoin::start(*start, *end, stroke);
 
             Self {
                 windows: EMPTY.windows(3),
                 start_join,
-                width,
-                stroke_offset,
+                stroke,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -52,19 +50,13 @@ impl<'a> ClosedThickSegmentIter<'a> {
         } else {
             let windows = points.windows(3);
 
-            let start_join = LineJoin::from_points(
-                *points.last().unwrap(),
-                points[0],
-                points[1],
-                width,
-                stroke_offset,
-            );
+            let start_join =
+                LineJoin::from_points(*points.last().unwrap(), points[0], points[1], stroke);
 
             Self {
                 windows,
                 start_join,
-                width,
-                stroke_offset,
+                stroke,
                 points,
                 stop: false,
                 first_join: start_join,
@@ -78,8 +70,7 @@ impl<'a> ClosedThickSegmentIter<'a> {
         Self {
             windows: EMPTY.windows(3),
             start_join: LineJoin::empty(),
-            width: 0,
-            stroke_offset: StrokeOffset::None,
+            stroke: ThickStroke::new(0, StrokeOffset::None),
             points: EMPTY,
             stop: true,
             first_join: LineJoin::empty(),
@@ -99,7 +90,7 @@ impl Iterator for ClosedThickSegmentIter<'_> {
         self.idx += 1;
 
         let end_join = if let Some([start, mid, end]) = self.windows.next() {
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            LineJoin::from_points(*start, *mid, *end, self.stroke)
         } else if self.idx == self.points.len() {
             // The join at the end of the line. This will become the start join of the closing
             // segment.
@@ -107,7 +98,7 @@ impl Iterator for ClosedThickSegmentIter<'_> {
             let mid = self.points.last()?;
             let end = self.points.first()?;
 
-            LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset)
+            LineJoin::from_points(*start, *mid, *end, self.stroke)
         } else {
             // Final closing line between start/end.
             self.stop = true;
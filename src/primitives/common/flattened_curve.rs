@@ -0,0 +1,384 @@
+use crate::{
+    geometry::{Angle, Point, Real, Size, Trigonometry},
+    primitives::Polyline,
+};
+use integer_sqrt::IntegerSquareRoot;
+
+/// Maximum number of line segments a curve is flattened into.
+const MAX_CURVE_SEGMENTS: usize = 64;
+
+/// Bézier curve flattened into a polyline.
+///
+/// The number of segments is chosen adaptively, based on the curvature of the curve, to keep the
+/// maximum distance between the curve and the polyline below a quarter pixel. Very long curves are
+/// limited to [`MAX_CURVE_SEGMENTS`] segments.
+#[derive(Copy, Clone, Debug)]
+pub struct FlattenedCurve {
+    vertices: [Point; MAX_CURVE_SEGMENTS + 1],
+    len: usize,
+}
+
+impl FlattenedCurve {
+    /// Flattens a quadratic Bézier curve.
+    pub fn quadratic(start: Point, control: Point, end: Point) -> Self {
+        // The flattening error for `n` segments is bounded by `|B''| / (8 n²)`, with
+        // `|B''| = 2 |start - 2 control + end|`. The error stays below 0.25px if `n² >= d`.
+        let d = second_difference(start, control, end);
+        let segments = ceil_sqrt(d);
+
+        let p1 = control - start;
+        let p2 = end - start;
+
+        Self::from_fn(start, end, segments, |t| {
+            let mt = Real::from(1) - t;
+
+            (
+                Real::from(2) * mt * t * Real::from(p1.x) + t * t * Real::from(p2.x),
+                Real::from(2) * mt * t * Real::from(p1.y) + t * t * Real::from(p2.y),
+            )
+        })
+    }
+
+    /// Flattens a cubic Bézier curve.
+    pub fn cubic(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        // The flattening error for `n` segments is bounded by `max |B''| / (8 n²)`, with
+        // `max |B''| <= 6 max(|start - 2 control1 + control2|, |control1 - 2 control2 + end|)`.
+        // The error stays below 0.25px if `n² >= 3 d`.
+        let d = second_difference(start, control1, control2)
+            .max(second_difference(control1, control2, end));
+        let segments = ceil_sqrt(d.saturating_mul(3));
+
+        let p1 = control1 - start;
+        let p2 = control2 - start;
+        let p3 = end - start;
+
+        Self::from_fn(start, end, segments, |t| {
+            let mt = Real::from(1) - t;
+            let a = Real::from(3) * mt * mt * t;
+            let b = Real::from(3) * mt * t * t;
+            let c = t * t * t;
+
+            (
+                a * Real::from(p1.x) + b * Real::from(p2.x) + c * Real::from(p3.x),
+                a * Real::from(p1.y) + b * Real::from(p2.y) + c * Real::from(p3.y),
+            )
+        })
+    }
+
+    /// Flattens an elliptical arc.
+    ///
+    /// The arc is specified like an SVG arc without x axis rotation. The ellipse is chosen to pass
+    /// through `start` and `end` and the flags select one of the four possible arcs. If the radii
+    /// are too small to connect both points they are scaled up.
+    pub fn arc(start: Point, radii: Size, large_arc: bool, sweep: bool, end: Point) -> Self {
+        let mut curve = Self {
+            vertices: [start; MAX_CURVE_SEGMENTS + 1],
+            len: 1,
+        };
+
+        if start == end {
+            return curve;
+        }
+
+        if radii.width == 0 || radii.height == 0 {
+            curve.push(end);
+            return curve;
+        }
+
+        let two = Real::from(2);
+        let mut rx = Real::from(radii.width);
+        let mut ry = Real::from(radii.height);
+
+        // Half the distance between the end points, normalized to a unit circle.
+        let a = Real::from(start.x - end.x) / two / rx;
+        let b = Real::from(start.y - end.y) / two / ry;
+
+        // The vector `(a, b)` is split into its length and direction. The length is calculated
+        // relative to the larger component to keep the precision for short vectors, which would
+        // otherwise be lost in `a * a + b * b` with the `fixed_point` feature.
+        let max = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+        if max == Real::from(0) {
+            curve.push(end);
+            return curve;
+        }
+        let (a_norm, b_norm) = (a / max, b / max);
+        let length = max * (a_norm * a_norm + b_norm * b_norm).sqrt();
+        let (dir_x, dir_y) = (a / length, b / length);
+
+        // The center is offset from the midpoint between the end points perpendicular to the
+        // chord. `h` is the length of this offset on the unit circle.
+        let (a, b, h) = if length >= Real::from(1) {
+            // Scale up the radii to make the end points lie on the ellipse.
+            rx *= length;
+            ry *= length;
+
+            (dir_x, dir_y, Real::from(0))
+        } else {
+            let h = (Real::from(1) - length * length).sqrt();
+
+            (a, b, if large_arc == sweep { -h } else { h })
+        };
+        let (offset_x, offset_y) = (-h * dir_y, h * dir_x);
+
+        let center_x = (Real::from(start.x) + Real::from(end.x)) / two - offset_x * rx;
+        let center_y = (Real::from(start.y) + Real::from(end.y)) / two - offset_y * ry;
+
+        // Start and end vectors on the unit circle.
+        let (mut x, mut y) = (a + offset_x, b + offset_y);
+        let (end_x, end_y) = (-a + offset_x, -b + offset_y);
+
+        // The angle between two vertices is chosen to keep the error below approximately a
+        // quarter pixel, but limited to make a full ellipse fit into `MAX_CURVE_SEGMENTS`.
+        let radius = u32::from(if rx > ry { rx } else { ry }).max(1);
+        let segments = (9 * u64::from(radius).integer_sqrt() / 2).max(1) as u32;
+        let step = (360 / segments).clamp(360 / MAX_CURVE_SEGMENTS as u32 + 1, 45);
+
+        let step = Angle::from_degrees(step as f32);
+        let (sin, cos) = if sweep {
+            (step.sin(), step.cos())
+        } else {
+            (-step.sin(), step.cos())
+        };
+
+        // Returns the cross product of the vector and the end vector, in sweep direction.
+        let cross = |x: Real, y: Real| {
+            let c = x * end_y - y * end_x;
+            if sweep {
+                c
+            } else {
+                -c
+            }
+        };
+
+        for _ in 0..MAX_CURVE_SEGMENTS - 1 {
+            let next_x = x * cos - y * sin;
+            let next_y = x * sin + y * cos;
+
+            // Stop if the next vertex would pass the end point.
+            let dot = next_x * end_x + next_y * end_y;
+            if cross(x, y) > Real::from(0)
+                && cross(next_x, next_y) <= Real::from(0)
+                && dot > Real::from(0)
+            {
+                break;
+            }
+
+            x = next_x;
+            y = next_y;
+
+            curve.push(Point::new(
+                i32::from((center_x + x * rx).round()),
+                i32::from((center_y + y * ry).round()),
+            ));
+        }
+
+        curve.push(end);
+
+        curve
+    }
+
+    /// Creates a flattened curve by evaluating `f` at evenly spaced parameter values.
+    ///
+    /// `f` returns the offset of the curve point relative to `start`.
+    fn from_fn<F>(start: Point, end: Point, segments: u64, f: F) -> Self
+    where
+        F: Fn(Real) -> (Real, Real),
+    {
+        let segments = segments.clamp(1, MAX_CURVE_SEGMENTS as u64) as i32;
+
+        let mut curve = Self {
+            vertices: [start; MAX_CURVE_SEGMENTS + 1],
+            len: 1,
+        };
+
+        for i in 1..segments {
+            let t = Real::from(i) / Real::from(segments);
+            let (x, y) = f(t);
+
+            curve.push(start + Point::new(i32::from(x.round()), i32::from(y.round())));
+        }
+
+        curve.push(end);
+
+        curve
+    }
+
+    /// Appends a vertex, skipping duplicates of the previous vertex.
+    fn push(&mut self, vertex: Point) {
+        if self.vertices[self.len - 1] != vertex {
+            self.vertices[self.len] = vertex;
+            self.len += 1;
+        }
+    }
+
+    /// Returns the vertices of the flattened curve.
+    pub fn vertices(&self) -> &[Point] {
+        // A curve with identical start and end points that was reduced to a single point is
+        // returned as a zero length segment to make sure it is still drawn.
+        if self.len == 1 {
+            &self.vertices[0..2]
+        } else {
+            &self.vertices[0..self.len]
+        }
+    }
+
+    /// Returns the flattened curve as a polyline.
+    pub fn polyline(&self) -> Polyline<'_> {
+        Polyline::new(self.vertices())
+    }
+}
+
+/// Returns the length of the second difference `a - 2 b + c`, rounded up.
+fn second_difference(a: Point, b: Point, c: Point) -> u64 {
+    let x = i64::from(a.x) - 2 * i64::from(b.x) + i64::from(c.x);
+    let y = i64::from(a.y) - 2 * i64::from(b.y) + i64::from(c.y);
+
+    ceil_sqrt((x * x + y * y) as u64)
+}
+
+/// Returns the square root of `value`, rounded up.
+fn ceil_sqrt(value: u64) -> u64 {
+    let root = value.integer_sqrt();
+
+    if root * root < value {
+        root + 1
+    } else {
+        root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn straight_curve() {
+        let curve =
+            FlattenedCurve::quadratic(Point::new(0, 0), Point::new(5, 5), Point::new(10, 10));
+
+        assert_eq!(curve.vertices(), &[Point::new(0, 0), Point::new(10, 10)]);
+    }
+
+    #[test]
+    fn single_point() {
+        let p = Point::new(3, 4);
+
+        assert_eq!(FlattenedCurve::quadratic(p, p, p).vertices(), &[p, p]);
+        assert_eq!(FlattenedCurve::cubic(p, p, p, p).vertices(), &[p, p]);
+    }
+
+    #[test]
+    fn half_circle_arc() {
+        let curve = FlattenedCurve::arc(
+            Point::new(0, 10),
+            Size::new(10, 10),
+            false,
+            true,
+            Point::new(20, 10),
+        );
+
+        let vertices = curve.vertices();
+        assert_eq!(vertices.first(), Some(&Point::new(0, 10)));
+        assert_eq!(vertices.last(), Some(&Point::new(20, 10)));
+
+        // The clockwise arc from left to right passes through the top of the circle.
+        assert_eq!(vertices.iter().map(|p| p.y).min(), Some(0));
+        assert!(vertices.iter().all(|p| {
+            let d = *p - Point::new(10, 10);
+            (d.x * d.x + d.y * d.y - 100).abs() <= 21 && p.y <= 10
+        }));
+    }
+
+    #[test]
+    fn arc_flags() {
+        let start = Point::new(0, 0);
+        let end = Point::new(10, 0);
+        let radii = Size::new(10, 10);
+
+        // Small arc above the chord.
+        let small = FlattenedCurve::arc(start, radii, false, true, end);
+        assert!(small.vertices().iter().all(|p| p.y <= 0));
+        assert!(small.vertices().iter().all(|p| p.y >= -2));
+
+        // Large arc below the chord.
+        let large = FlattenedCurve::arc(start, radii, true, false, end);
+        assert!(large.vertices().iter().any(|p| p.y >= 18));
+
+        // Small arc below the chord.
+        let small = FlattenedCurve::arc(start, radii, false, false, end);
+        assert!(small.vertices().iter().all(|p| p.y >= 0 && p.y <= 2));
+    }
+
+    #[test]
+    fn degenerate_arcs() {
+        let p = Point::new(3, 4);
+        let end = Point::new(10, 4);
+
+        assert_eq!(
+            FlattenedCurve::arc(p, Size::new(5, 5), false, false, p).vertices(),
+            &[p, p]
+        );
+        assert_eq!(
+            FlattenedCurve::arc(p, Size::new(0, 5), false, false, end).vertices(),
+            &[p, end]
+        );
+    }
+
+    #[test]
+    fn arc_with_short_chord() {
+        // Regression test: the center of the ellipse overflowed with the `fixed_point` feature if
+        // the distance between the end points was small compared to the radii.
+        let start = Point::new(10, 10);
+        let end = Point::new(11, 10);
+
+        let curve = FlattenedCurve::arc(start, Size::new(100, 100), false, true, end);
+        assert_eq!(curve.vertices(), &[start, end]);
+
+        let curve = FlattenedCurve::arc(start, Size::new(100, 100), true, true, end);
+        let vertices = curve.vertices();
+        assert_eq!(vertices.first(), Some(&start));
+        assert_eq!(vertices.last(), Some(&end));
+        assert_eq!(vertices.iter().map(|p| p.y).min(), Some(-190));
+        assert!(vertices.iter().all(|p| {
+            // Distance from the center at (10.5, -90) in doubled coordinates.
+            let d = *p * 2 - Point::new(21, -180);
+            (d.x * d.x + d.y * d.y - 200 * 200).abs() <= 600
+        }));
+    }
+
+    #[test]
+    fn adaptive_segment_count() {
+        let small = FlattenedCurve::quadratic(Point::new(0, 0), Point::new(4, 8), Point::new(8, 0));
+        let large =
+            FlattenedCurve::quadratic(Point::new(0, 0), Point::new(40, 80), Point::new(80, 0));
+
+        assert!(small.vertices().len() < large.vertices().len());
+        assert_eq!(small.vertices().first(), Some(&Point::new(0, 0)));
+        assert_eq!(small.vertices().last(), Some(&Point::new(8, 0)));
+    }
+
+    #[test]
+    fn max_segments() {
+        let curve = FlattenedCurve::cubic(
+            Point::new(0, 0),
+            Point::new(0, 10000),
+            Point::new(10000, -10000),
+            Point::new(10000, 0),
+        );
+
+        assert_eq!(curve.vertices().len(), MAX_CURVE_SEGMENTS + 1);
+        assert_eq!(curve.vertices().last(), Some(&Point::new(10000, 0)));
+    }
+
+    #[test]
+    fn no_duplicate_vertices() {
+        let curve = FlattenedCurve::cubic(
+            Point::new(0, 0),
+            Point::new(0, 2),
+            Point::new(2, 2),
+            Point::new(2, 0),
+        );
+
+        assert!(curve.vertices().windows(2).all(|w| w[0] != w[1]));
+    }
+}
@@ -3,11 +3,12 @@
 use crate::{
     geometry::{Point, PointExt},
     primitives::{
-        common::{LineSide, LinearEquation, StrokeOffset},
+        common::{LineSide, LinearEquation, StrokeOffset, ThickStroke},
         line::intersection_params::{Intersection, IntersectionParams},
-        Line,
+        Circle, Line, LineCap, LineJoin as LineJoinStyle,
     },
 };
+use integer_sqrt::IntegerSquareRoot;
 
 /// Join kind
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -30,6 +31,9 @@ pub enum JoinKind {
         outer_side: LineSide,
     },
 
+    /// Rounded (both lines end in a butt end and the corner is covered by a circle).
+    Round,
+
     /// Lines are colinear.
     ///
     /// Start and end points for this join will be equal.
@@ -65,16 +69,28 @@ pub struct LineJoin {
 
     /// Corners comprising the start edge of the line that begins at this join.
     pub second_edge_start: EdgeCorners,
+
+    /// Circle that covers round joins and caps.
+    pub disc: Option<Circle>,
 }
 
 impl LineJoin {
     /// Create a starting join.
     ///
     /// `first_edge_end` and `second_edge_start` are set to the same points.
-    pub fn start(start: Point, mid: Point, width: u32, stroke_offset: StrokeOffset) -> Self {
-        let line = Line::new(start, mid);
+    pub fn start(start: Point, mid: Point, stroke: ThickStroke) -> Self {
+        let cap_start = match stroke.line_cap {
+            // The square caps of a zero length line are extended horizontally, which results in
+            // a square that is centered on the point. For even stroke widths the square is offset
+            // to the top left, like the stroke of a horizontal line is offset to the top.
+            LineCap::Square if start == mid => extend(start, Point::new(-1, 0), stroke.width),
+            LineCap::Square => extend(start, start - mid, stroke.width),
+            LineCap::Butt | LineCap::Round => start,
+        };
+
+        let line = Line::new(cap_start, mid);
 
-        let (l, r) = line.extents(width, stroke_offset);
+        let (l, r) = line.extents(stroke.width, stroke.offset);
 
         let points = EdgeCorners {
             left: l.start,
@@ -85,16 +101,25 @@ impl LineJoin {
             kind: JoinKind::Start,
             first_edge_end: points,
             second_edge_start: points,
+            disc: round_cap(start, stroke),
         }
     }
 
     /// Create an ending join.
     ///
     /// `first_edge_end` and `second_edge_start` are set to the same points.
-    pub fn end(mid: Point, end: Point, width: u32, stroke_offset: StrokeOffset) -> Self {
-        let line = Line::new(mid, end);
+    pub fn end(mid: Point, end: Point, stroke: ThickStroke) -> Self {
+        let cap_end = match stroke.line_cap {
+            LineCap::Square if end == mid => {
+                extend(end, Point::new(1, 0), stroke.width.saturating_sub(1))
+            }
+            LineCap::Square => extend(end, end - mid, stroke.width),
+            LineCap::Butt | LineCap::Round => end,
+        };
 
-        let (l, r) = line.extents(width, stroke_offset);
+        let line = Line::new(mid, cap_end);
+
+        let (l, r) = line.extents(stroke.width, stroke.offset);
 
         let points = EdgeCorners {
             left: l.end,
@@ -105,6 +130,7 @@ impl LineJoin {
             kind: JoinKind::End,
             first_edge_end: points,
             second_edge_start: points,
+            disc: round_cap(end, stroke),
         }
     }
 
@@ -120,24 +146,20 @@ impl LineJoin {
                 left: Point::zero(),
                 right: Point::zero(),
             },
+            disc: None,
         }
     }
 
     /// Compute a join.
-    pub fn from_points(
-        start: Point,
-        mid: Point,
-        end: Point,
-        width: u32,
-        stroke_offset: StrokeOffset,
-    ) -> Self {
+    pub fn from_points(start: Point, mid: Point, end: Point, stroke: ThickStroke) -> Self {
         let first_line = Line::new(start, mid);
         let second_line = Line::new(mid, end);
 
         // Left and right edges of thick first segment
-        let (first_edge_left, first_edge_right) = first_line.extents(width, stroke_offset);
+        let (first_edge_left, first_edge_right) = first_line.extents(stroke.width, stroke.offset);
         // Left and right edges of thick second segment
-        let (second_edge_left, second_edge_right) = second_line.extents(width, stroke_offset);
+        let (second_edge_left, second_edge_right) =
+            second_line.extents(stroke.width, stroke.offset);
 
         if let Some((l_intersection, outer_side, r_intersection)) = intersections(
             &first_edge_left,
@@ -145,6 +167,23 @@ impl LineJoin {
             &second_edge_left,
             &second_edge_right,
         ) {
+            // Round joins are only supported for centered strokes, because the circle would
+            // otherwise cover parts of the shape that shouldn't be stroked.
+            if stroke.line_join == LineJoinStyle::Round && stroke.offset == StrokeOffset::None {
+                return Self {
+                    kind: JoinKind::Round,
+                    first_edge_end: EdgeCorners {
+                        left: first_edge_left.end,
+                        right: first_edge_right.end,
+                    },
+                    second_edge_start: EdgeCorners {
+                        left: second_edge_left.start,
+                        right: second_edge_right.start,
+                    },
+                    disc: Some(Circle::with_center(mid, stroke.width)),
+                };
+            }
+
             // Check if the inside end point of the second line lies inside the first segment.
             let self_intersection = match outer_side {
                 LineSide::Right => LinearEquation::from_line(&first_edge_left)
@@ -166,12 +205,16 @@ impl LineJoin {
                 .delta()
                 .length_squared() as u32;
 
-                // Miter length limit is double the line width (but squared to avoid sqrt() costs)
-                let miter_limit = (width * 2).pow(2);
+                // The miter length is measured across a centered stroke, which is twice the
+                // distance from the midpoint (but squared to avoid sqrt() costs).
+                let miter_limit = match stroke.line_join {
+                    LineJoinStyle::Miter => u64::from(stroke.miter_limit) * u64::from(stroke.width),
+                    LineJoinStyle::Bevel | LineJoinStyle::Round => 0,
+                };
 
                 // Intersection is within limit at which it will be chopped off into a bevel, so
                 // return a miter.
-                if miter_length_squared <= miter_limit {
+                if 4 * u64::from(miter_length_squared) <= miter_limit.saturating_pow(2) {
                     let corners = EdgeCorners {
                         left: l_intersection,
                         right: r_intersection,
@@ -181,6 +224,7 @@ impl LineJoin {
                         kind: JoinKind::Miter,
                         first_edge_end: corners,
                         second_edge_start: corners,
+                        disc: None,
                     }
                 }
                 // Miter is too long, chop it into bevel-style corner
@@ -196,6 +240,7 @@ impl LineJoin {
                                 left: l_intersection,
                                 right: second_edge_right.start,
                             },
+                            disc: None,
                         },
                         LineSide::Left => Self {
                             kind: JoinKind::Bevel { outer_side },
@@ -207,6 +252,7 @@ impl LineJoin {
                                 left: second_edge_left.start,
                                 right: r_intersection,
                             },
+                            disc: None,
                         },
                     }
                 }
@@ -223,6 +269,7 @@ impl LineJoin {
                         left: second_edge_left.start,
                         right: second_edge_right.start,
                     },
+                    disc: None,
                 }
             }
         }
@@ -238,6 +285,7 @@ impl LineJoin {
                     left: second_edge_left.start,
                     right: second_edge_right.start,
                 },
+                disc: None,
             }
         }
     }
@@ -294,6 +342,31 @@ impl LineJoin {
     }
 }
 
+/// Returns the circle for a round cap at the given point.
+fn round_cap(point: Point, stroke: ThickStroke) -> Option<Circle> {
+    if stroke.line_cap == LineCap::Round {
+        Some(Circle::with_center(point, stroke.width))
+    } else {
+        None
+    }
+}
+
+/// Moves `point` by half the stroke width in the given direction.
+fn extend(point: Point, direction: Point, width: u32) -> Point {
+    let (dx, dy) = (i64::from(direction.x), i64::from(direction.y));
+    let length = ((dx * dx + dy * dy) as u64).integer_sqrt() as i64;
+
+    let half_width = i64::from(width / 2);
+    let offset = |d: i64| {
+        let n = 2 * d * half_width;
+        let rounding = if n >= 0 { length } else { -length };
+
+        ((n + rounding) / (2 * length)) as i32
+    };
+
+    point + Point::new(offset(dx), offset(dy))
+}
+
 fn intersections(
     first_edge_left: &Line,
     first_edge_right: &Line,
@@ -1,22 +1,34 @@
+mod arc_caps;
 mod closed_thick_segment_iter;
 mod distance_iterator;
+mod flattened_curve;
 mod line_join;
 mod linear_equation;
+pub mod outline;
 mod plane_sector;
+pub mod polygon;
+mod radial_stroke;
+pub mod rotation;
 mod scanline;
+pub mod scanline_renderer;
 mod styled_scanline;
 mod thick_segment;
 mod thick_segment_iter;
+mod thick_stroke;
 
+pub use arc_caps::ArcCaps;
 pub use closed_thick_segment_iter::ClosedThickSegmentIter;
 pub use distance_iterator::DistanceIterator;
+pub use flattened_curve::FlattenedCurve;
 pub use line_join::{JoinKind, LineJoin};
 pub use linear_equation::{LinearEquation, OriginLinearEquation, NORMAL_VECTOR_SCALE};
 pub use plane_sector::PlaneSector;
+pub use radial_stroke::RadialStroke;
 pub use scanline::Scanline;
 pub use styled_scanline::StyledScanline;
 pub use thick_segment::ThickSegment;
 pub use thick_segment_iter::ThickSegmentIter;
+pub use thick_stroke::ThickStroke;
 
 use crate::primitives::StrokeAlignment;
 
@@ -0,0 +1,214 @@
+//! Outlines that are defined by a sequence of vertices.
+//!
+//! The functions in this module don't require the vertices to be stored in a buffer. Vertex
+//! iterators are cloned if multiple passes are required. Consecutive vertices must not be equal.
+
+use crate::{
+    geometry::Point,
+    primitives::common::{LineJoin, StrokeOffset, ThickSegment, ThickStroke},
+};
+
+/// Calls `f` for each line segment in the outline.
+///
+/// If `close` is `true`, the closing segment from the last to the first vertex is included.
+pub fn for_each_edge<I, F>(mut vertices: I, close: bool, f: &mut F)
+where
+    I: Iterator<Item = Point>,
+    F: FnMut(Point, Point),
+{
+    let first = match vertices.next() {
+        Some(first) => first,
+        None => return,
+    };
+
+    let last = vertices.fold(first, |start, end| {
+        f(start, end);
+
+        end
+    });
+
+    if close && last != first {
+        f(last, first);
+    }
+}
+
+/// Calls `f` for each thick segment in the outline.
+///
+/// Closed outlines with at least three vertices use line joins at all vertices. All other
+/// outlines are stroked like polylines with line caps at both ends.
+pub fn for_each_thick_segment<I, F>(mut vertices: I, closed: bool, stroke: ThickStroke, f: &mut F)
+where
+    I: Iterator<Item = Point> + Clone,
+    F: FnMut(ThickSegment),
+{
+    let (v0, v1) = match (vertices.next(), vertices.next()) {
+        (Some(v0), Some(v1)) => (v0, v1),
+        _ => return,
+    };
+
+    // Closed outlines ignore a final vertex that coincides with the start point, because the
+    // closing segment is added implicitly.
+    let (count, last) = if closed {
+        let (count, second_to_last, last) = vertices
+            .clone()
+            .fold((2, v0, v1), |(count, _, prev), v| (count + 1, prev, v));
+
+        if last == v0 {
+            (count - 1, second_to_last)
+        } else {
+            (count, last)
+        }
+    } else {
+        (0, v1)
+    };
+
+    if closed && count >= 3 {
+        let first_join = LineJoin::from_points(last, v0, v1, stroke);
+
+        let mut start_join = first_join;
+        let (mut prev, mut current) = (v0, v1);
+
+        for next in vertices.take(count - 2) {
+            let end_join = LineJoin::from_points(prev, current, next, stroke);
+            f(ThickSegment::new(start_join, end_join));
+
+            start_join = end_join;
+            prev = current;
+            current = next;
+        }
+
+        let end_join = LineJoin::from_points(prev, current, v0, stroke);
+        f(ThickSegment::new(start_join, end_join));
+        f(ThickSegment::new(end_join, first_join));
+    } else {
+        // Open outlines are always centered on the skeleton.
+        let stroke = stroke.with_offset(StrokeOffset::None);
+
+        let mut start_join = LineJoin::start(v0, v1, stroke);
+        let (mut prev, mut current) = (v0, v1);
+
+        for next in vertices {
+            let end_join = LineJoin::from_points(prev, current, next, stroke);
+            f(ThickSegment::new(start_join, end_join));
+
+            start_join = end_join;
+            prev = current;
+            current = next;
+        }
+
+        let end_join = LineJoin::end(prev, current, stroke);
+        f(ThickSegment::new(start_join, end_join));
+    }
+}
+
+/// Returns `true` if the outline is wound clockwise.
+pub fn is_clockwise<I>(vertices: I) -> bool
+where
+    I: Iterator<Item = Point>,
+{
+    let mut area = 0i64;
+
+    for_each_edge(vertices, true, &mut |start, end| {
+        area += i64::from(start.x) * i64::from(end.y) - i64::from(end.x) * i64::from(start.y);
+    });
+
+    area >= 0
+}
+
+/// Returns the stroke offset for an outline.
+///
+/// The offset is swapped for counterclockwise outlines to make the stroke alignment independent
+/// of the winding direction.
+pub fn stroke_offset<I>(vertices: I, stroke_offset: StrokeOffset) -> StrokeOffset
+where
+    I: Iterator<Item = Point>,
+{
+    if stroke_offset == StrokeOffset::None || is_clockwise(vertices) {
+        return stroke_offset;
+    }
+
+    match stroke_offset {
+        StrokeOffset::Left => StrokeOffset::Right,
+        StrokeOffset::Right => StrokeOffset::Left,
+        StrokeOffset::None => StrokeOffset::None,
+    }
+}
+
+/// Iterator adapter that skips consecutive duplicate vertices.
+#[derive(Clone, Debug)]
+pub struct Deduplicated<I> {
+    vertices: I,
+    last: Option<Point>,
+}
+
+impl<I> Deduplicated<I> {
+    /// Creates a new deduplicating iterator.
+    pub const fn new(vertices: I) -> Self {
+        Self {
+            vertices,
+            last: None,
+        }
+    }
+}
+
+impl<I> Iterator for Deduplicated<I>
+where
+    I: Iterator<Item = Point>,
+{
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let vertex = self.vertices.next()?;
+
+            if self.last != Some(vertex) {
+                self.last = Some(vertex);
+
+                return Some(vertex);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn winding() {
+        let clockwise = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 10)];
+        assert!(is_clockwise(clockwise.iter().copied()));
+        assert!(!is_clockwise(clockwise.iter().rev().copied()));
+    }
+
+    #[test]
+    fn edges() {
+        let vertices = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 10)];
+
+        let mut count = 0;
+        for_each_edge(vertices.iter().copied(), false, &mut |_, _| count += 1);
+        assert_eq!(count, 2);
+
+        let mut count = 0;
+        for_each_edge(vertices.iter().copied(), true, &mut |_, _| count += 1);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn deduplicated() {
+        let vertices = [
+            Point::new(0, 0),
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(10, 0),
+            Point::new(10, 0),
+            Point::new(0, 0),
+        ];
+
+        assert!(Deduplicated::new(vertices.iter().copied()).eq([
+            Point::new(0, 0),
+            Point::new(10, 0),
+            Point::new(0, 0),
+        ]));
+    }
+}
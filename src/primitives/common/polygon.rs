@@ -0,0 +1,272 @@
+//! Renderer for closed polygons that are defined by a vertex iterator.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Angle, Point, Real, Size, Trigonometry},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{
+            outline::{self, Deduplicated},
+            scanline_renderer::{
+                self, bresenham_intersection, crossing, Row, RowEvent, DEFAULT_ROW_EVENTS,
+                SUBPIXELS,
+            },
+            PointType, StrokeOffset, ThickStroke,
+        },
+        FillRule, PrimitiveStyle, Rectangle,
+    },
+};
+
+/// Returns the point at the given distance and angle from the center.
+///
+/// An angle of 0° points to the right and positive angles rotate clockwise.
+pub fn polar_point(center: Point, radius: u32, angle: Angle) -> Point {
+    let radius = Real::from(radius);
+
+    center
+        + Point::new(
+            i32::from((angle.cos() * radius).round()),
+            i32::from((angle.sin() * radius).round()),
+        )
+}
+
+/// Maximum number of vertices that are stored in a buffer by [`draw`].
+const CACHED_VERTICES: usize = 32;
+
+/// Draws a styled polygon.
+///
+/// The vertices of polygons with up to [`CACHED_VERTICES`] vertices are stored in a buffer on the
+/// stack, which makes sure that each vertex is only calculated once. The vertices of larger
+/// polygons are calculated again for every row.
+pub fn draw<I, D>(
+    vertices: I,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    I: ExactSizeIterator<Item = Point> + Clone,
+    D: DrawTarget,
+{
+    let len = vertices.len();
+
+    if len <= CACHED_VERTICES {
+        let mut buffer = [Point::zero(); CACHED_VERTICES];
+        for (vertex, point) in buffer.iter_mut().zip(vertices) {
+            *vertex = point;
+        }
+
+        PolygonRenderer::new(buffer[0..len].iter().copied(), style).draw(style, target)
+    } else {
+        PolygonRenderer::new(vertices, style).draw(style, target)
+    }
+}
+
+/// Scanline renderer for closed polygons.
+///
+/// The vertex iterator is cloned for every pass over the outline, which means that no vertex
+/// buffer is required. Consecutive duplicate vertices are skipped and the fill area is determined
+/// using the non-zero fill rule.
+pub struct PolygonRenderer<I> {
+    vertices: Deduplicated<I>,
+    stroke: ThickStroke,
+    has_stroke: bool,
+    fill: bool,
+}
+
+impl<I> PolygonRenderer<I>
+where
+    I: Iterator<Item = Point> + Clone,
+{
+    /// Creates a new polygon renderer.
+    pub fn new<C: PixelColor>(vertices: I, style: &PrimitiveStyle<C>) -> Self {
+        let vertices = Deduplicated::new(vertices);
+
+        let offset =
+            outline::stroke_offset(vertices.clone(), StrokeOffset::from(style.stroke_alignment));
+
+        Self {
+            vertices,
+            stroke: ThickStroke::from_style(style, offset),
+            has_stroke: style.effective_stroke_color().is_some(),
+            fill: style.fill_color.is_some(),
+        }
+    }
+
+    /// Adds the stroke intervals and edge crossings in a row.
+    fn add_events(&self, row: &mut Row) {
+        let y = row.y();
+
+        if self.has_stroke && self.stroke.width == 1 {
+            outline::for_each_edge(self.vertices.clone(), true, &mut |start, end| {
+                row.add_stroke(&bresenham_intersection(start, end, y));
+            });
+        } else if self.has_stroke {
+            outline::for_each_thick_segment(
+                self.vertices.clone(),
+                true,
+                self.stroke,
+                &mut |segment| {
+                    row.add_stroke(&segment.intersection(y));
+                },
+            );
+        }
+
+        if self.fill {
+            outline::for_each_edge(self.vertices.clone(), true, &mut |start, end| {
+                row.add_boundary(&bresenham_intersection(start, end, y));
+
+                if let Some((crossing, direction)) = crossing(start, end, y) {
+                    row.add_crossing(crossing, direction);
+                }
+            });
+        }
+    }
+
+    /// Draws the polygon.
+    pub fn draw<D>(&self, style: &PrimitiveStyle<D::Color>, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+    {
+        let rows = self.bounding_box(style).rows();
+        let mut buffer = [RowEvent::default(); DEFAULT_ROW_EVENTS];
+
+        scanline_renderer::for_each_row_scanline(
+            rows,
+            &mut buffer,
+            FillRule::NonZero,
+            |row| self.add_events(row),
+            |scanline, point_type| {
+                let color = match point_type {
+                    PointType::Stroke => style.effective_stroke_color(),
+                    PointType::Fill => style.fill_color,
+                };
+
+                match color {
+                    Some(color) => target.fill_solid(&scanline.to_rectangle(), color),
+                    None => Ok(()),
+                }
+            },
+        )
+    }
+
+    /// Returns the bounding box of the styled polygon.
+    pub fn bounding_box<C: PixelColor>(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let (min, max) = match vertices_bounding_box(self.vertices.clone()) {
+            Some(bounds) => bounds,
+            None => return Rectangle::zero(),
+        };
+
+        if !self.has_stroke && !self.fill {
+            return Rectangle::new(Rectangle::with_corners(min, max).center(), Size::zero());
+        }
+
+        if !self.has_stroke || style.stroke_width <= 1 {
+            return Rectangle::with_corners(min, max);
+        }
+
+        // The fill area is always inside the outline of a thick stroke, unless the stroke is drawn
+        // on the inside.
+        let (mut min, mut max) = if self.fill {
+            (min, max)
+        } else {
+            (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN))
+        };
+
+        outline::for_each_thick_segment(self.vertices.clone(), true, self.stroke, &mut |segment| {
+            let bb = segment.edges_bounding_box();
+
+            min = min.component_min(bb.top_left);
+            max = max.component_max(bb.bottom_right().unwrap_or(bb.top_left));
+        });
+
+        if min.x > max.x {
+            return Rectangle::zero();
+        }
+
+        Rectangle::with_corners(min, max)
+    }
+}
+
+/// Returns `true` if the point is inside the filled polygon.
+pub fn contains<I>(vertices: I, point: Point) -> bool
+where
+    I: Iterator<Item = Point> + Clone,
+{
+    let mut boundary = false;
+    let mut winding = 0;
+
+    outline::for_each_edge(Deduplicated::new(vertices), true, &mut |start, end| {
+        boundary |= bresenham_intersection(start, end, point.y)
+            .x
+            .contains(&point.x);
+
+        if let Some((crossing, direction)) = crossing(start, end, point.y) {
+            if crossing <= i64::from(point.x) * SUBPIXELS {
+                winding += direction;
+            }
+        }
+    });
+
+    boundary || winding != 0
+}
+
+/// Returns the minimum and maximum coordinates of all vertices.
+pub fn vertices_bounding_box<I>(vertices: I) -> Option<(Point, Point)>
+where
+    I: Iterator<Item = Point>,
+{
+    vertices.fold(None, |bounds, vertex| {
+        Some(match bounds {
+            Some((min, max)) => (min.component_min(vertex), max.component_max(vertex)),
+            None => (vertex, vertex),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn polar_point() {
+        let center = Point::new(10, 20);
+
+        assert_eq!(super::polar_point(center, 5, 0.0.deg()), Point::new(15, 20));
+        assert_eq!(
+            super::polar_point(center, 5, 90.0.deg()),
+            Point::new(10, 25)
+        );
+        assert_eq!(
+            super::polar_point(center, 5, 180.0.deg()),
+            Point::new(5, 20)
+        );
+        assert_eq!(
+            super::polar_point(center, 5, -90.0.deg()),
+            Point::new(10, 15)
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let square = [
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(0, 4),
+        ];
+
+        for y in -2..7 {
+            for x in -2..7 {
+                let point = Point::new(x, y);
+                let expected = (0..=4).contains(&x) && (0..=4).contains(&y);
+
+                assert_eq!(super::contains(square.iter().copied(), point), expected);
+                assert_eq!(
+                    super::contains(square.iter().rev().copied(), point),
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    geometry::{angle_consts::ANGLE_90DEG, Angle, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{LineSide, LinearEquation, PlaneSector, PointType, NORMAL_VECTOR_SCALE},
+        PrimitiveStyle,
+    },
+};
+use az::SaturatingAs;
+
+/// Radial stroke of sector shaped primitives.
+///
+/// Determines if a point is part of the two straight edges of a sector, which start at the center
+/// point, including the bevel at the corner between both edges. This is shared by all sector
+/// shaped primitives, which only differ in the shape of their curved edges.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RadialStroke {
+    plane_sector: PlaneSector,
+
+    stroke_threshold_inside: i32,
+    stroke_threshold_outside: i32,
+
+    bevel: Option<(BevelKind, LinearEquation)>,
+}
+
+impl RadialStroke {
+    /// Creates a new radial stroke for a sector with the given angles.
+    pub fn new<C: PixelColor>(
+        angle_start: Angle,
+        angle_sweep: Angle,
+        style: &PrimitiveStyle<C>,
+    ) -> Self {
+        let plane_sector = PlaneSector::new(angle_start, angle_sweep);
+
+        let inside_stroke_width: i32 = style.inside_stroke_width().saturating_as();
+        let outside_stroke_width: i32 = style.outside_stroke_width().saturating_as();
+
+        let stroke_threshold_inside =
+            inside_stroke_width * NORMAL_VECTOR_SCALE * 2 - NORMAL_VECTOR_SCALE;
+        let stroke_threshold_outside =
+            outside_stroke_width * NORMAL_VECTOR_SCALE * 2 + NORMAL_VECTOR_SCALE;
+
+        // The corner is beveled at a fixed angle, independent of the line join and miter limit
+        // settings in the primitive style.
+        let angle_sweep_abs = angle_sweep.abs();
+        let exterior_bevel = angle_sweep_abs < Angle::from_degrees(55.0);
+        let interior_bevel = angle_sweep_abs > Angle::from_degrees(360.0 - 55.0)
+            && angle_sweep_abs < Angle::from_degrees(360.0);
+
+        let bevel = if exterior_bevel || interior_bevel {
+            let half_sweep = angle_start + Angle::from_radians(angle_sweep.to_radians() / 2.0);
+            let threshold = -outside_stroke_width * NORMAL_VECTOR_SCALE * 4;
+
+            if interior_bevel {
+                Some((
+                    BevelKind::Interior,
+                    LinearEquation::with_angle_and_distance(half_sweep + ANGLE_90DEG, threshold),
+                ))
+            } else {
+                Some((
+                    BevelKind::Exterior,
+                    LinearEquation::with_angle_and_distance(half_sweep - ANGLE_90DEG, threshold),
+                ))
+            }
+        } else {
+            None
+        };
+
+        Self {
+            plane_sector,
+            stroke_threshold_inside,
+            stroke_threshold_outside,
+            bevel,
+        }
+    }
+
+    /// Returns the type of a point.
+    ///
+    /// `delta` is the offset of the point from the center of the sector, scaled by 2. Points which
+    /// aren't part of the radial stroke are returned as [`PointType::Fill`], as long as they are
+    /// inside the sector. `None` is returned for points outside the sector.
+    pub fn point_type(&self, delta: Point) -> Option<PointType> {
+        let point_type = self.plane_sector.point_type(
+            delta,
+            self.stroke_threshold_inside,
+            self.stroke_threshold_outside,
+        )?;
+
+        // Bevel the line join.
+        if point_type == PointType::Stroke {
+            if let Some((kind, equation)) = self.bevel {
+                if equation.check_side(delta, LineSide::Left) {
+                    return match kind {
+                        BevelKind::Interior => Some(PointType::Fill),
+                        BevelKind::Exterior => None,
+                    };
+                }
+            }
+        }
+
+        Some(point_type)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum BevelKind {
+    Interior,
+    Exterior,
+}
@@ -0,0 +1,582 @@
+//! Rotation of shapes around their center.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Angle, Dimensions, Point, Real, Size, Trigonometry},
+    primitives::{common::Scanline, OffsetOutline, PrimitiveStyle, Rectangle},
+};
+use core::ops::Range;
+
+/// Scale factor of the local coordinates returned by [`Rotation::local_point`].
+pub const LOCAL_SCALE: i64 = 64;
+
+/// Rotation around the center of a shape.
+///
+/// Points are converted into a local coordinate system, which is aligned with the axes of the
+/// unrotated shape. Local coordinates are measured in half pixels relative to the center of the
+/// shape and are scaled by [`LOCAL_SCALE`] to reduce rounding errors.
+#[derive(Copy, Clone, Debug)]
+pub struct Rotation {
+    center_2x: Point,
+    cos: Real,
+    sin: Real,
+}
+
+impl Rotation {
+    /// Creates a new rotation around a center point, which is scaled by a factor of 2.
+    pub fn new(center_2x: Point, angle: Angle) -> Self {
+        Self {
+            center_2x,
+            cos: angle.cos(),
+            sin: angle.sin(),
+        }
+    }
+
+    /// Creates a new rotation by 0° around a center point, which is scaled by a factor of 2.
+    ///
+    /// The local coordinates of an unrotated shape are exact multiples of [`LOCAL_SCALE`].
+    pub fn unrotated(center_2x: Point) -> Self {
+        Self {
+            center_2x,
+            cos: Real::from(1),
+            sin: Real::from(0),
+        }
+    }
+
+    /// Converts a point into local coordinates.
+    pub fn local_point(&self, point: Point) -> (i64, i64) {
+        let delta = point * 2 - self.center_2x;
+        let x = Real::from(delta.x);
+        let y = Real::from(delta.y);
+
+        (
+            scale(x * self.cos + y * self.sin),
+            scale(y * self.cos - x * self.sin),
+        )
+    }
+
+    /// Returns the half extents of the rotated rectangle with the given size.
+    ///
+    /// The returned values are measured in half pixels.
+    pub fn rectangle_extents(&self, size: Size) -> (Real, Real) {
+        let width = Real::from(size.width);
+        let height = Real::from(size.height);
+        let cos = self.cos.abs();
+        let sin = self.sin.abs();
+
+        (width * cos + height * sin, width * sin + height * cos)
+    }
+
+    /// Returns the half extents of the rotated ellipse with the given size.
+    ///
+    /// The returned values are measured in half pixels.
+    pub fn ellipse_extents(&self, size: Size) -> (Real, Real) {
+        // The axes are normalized to prevent overflows when fixed point math is used.
+        let max = size.width.max(size.height).max(1);
+        let width = Real::from(size.width) / Real::from(max);
+        let height = Real::from(size.height) / Real::from(max);
+
+        let hypot = |a: Real, b: Real| (a * a + b * b).sqrt() * Real::from(max);
+
+        (
+            hypot(width * self.cos, height * self.sin),
+            hypot(width * self.sin, height * self.cos),
+        )
+    }
+
+    /// Returns the intersection of a row with a rectangle in local coordinates.
+    ///
+    /// `dy` is the offset of the row from the center of the rotation and `center` and `extents`
+    /// are the center and the half extents of the rectangle in local coordinates. All values are
+    /// measured in half pixels, but aren't scaled by [`LOCAL_SCALE`].
+    ///
+    /// The intersection is returned as a range of `t`, the offset in half pixels from the center
+    /// of the rotation along the row. The range is limited to `-limit..limit` and an empty
+    /// intersection is returned as a range with a start greater than its end.
+    pub fn rectangle_chord(
+        &self,
+        dy: Real,
+        center: (Real, Real),
+        extents: (Real, Real),
+        limit: Real,
+    ) -> (Real, Real) {
+        // Local coordinates of the point at `t`:
+        // x = t * cos + dy * sin
+        // y = dy * cos - t * sin
+        let (x_start, x_end) = slab(dy * self.sin - center.0, self.cos, extents.0, limit);
+        let (y_start, y_end) = slab(dy * self.cos - center.1, -self.sin, extents.1, limit);
+
+        (
+            if x_start > y_start { x_start } else { y_start },
+            if x_end < y_end { x_end } else { y_end },
+        )
+    }
+
+    /// Returns the intersection of a row with an ellipse in local coordinates.
+    ///
+    /// The parameters and the return value are the same as in
+    /// [`rectangle_chord`](Self::rectangle_chord), with `extents` being the semi-axes of the
+    /// ellipse.
+    pub fn ellipse_chord(
+        &self,
+        dy: Real,
+        center: (Real, Real),
+        extents: (Real, Real),
+        limit: Real,
+    ) -> (Real, Real) {
+        let (width, height) = extents;
+        let (cos, sin) = (self.cos, self.sin);
+
+        // Half height of the rotated ellipse.
+        let half_height = (height * cos).hypot(width * sin);
+        if half_height == Real::from(0) {
+            return (limit, -limit);
+        }
+
+        // Rotate the center of the ellipse back into the coordinate system of the rows.
+        let center_t = center.0 * cos - center.1 * sin;
+        let dy = dy - (center.0 * sin + center.1 * cos);
+
+        // Rows which are less than one half pixel away from the ellipse are treated as tangents.
+        if dy.abs() >= half_height + Real::from(1) {
+            return (limit, -limit);
+        }
+
+        let one = Real::from(1);
+        let mut q = dy / half_height;
+        if q > one {
+            q = one;
+        } else if q < -one {
+            q = -one;
+        }
+
+        // The factors are ordered to keep all intermediate values inside the size of the
+        // ellipse, which prevents overflows when fixed point math is used.
+        let (major, minor) = if width > height {
+            (width, height)
+        } else {
+            (height, width)
+        };
+        let mid = center_t
+            + q * ((width * sin / half_height) * (width * cos)
+                - (height * cos / half_height) * (height * sin));
+        let half_chord = (one - q * q).sqrt() * major * (minor / half_height);
+
+        let start = mid - half_chord;
+        let end = mid + half_chord;
+
+        (
+            if start > -limit { start } else { -limit },
+            if end < limit { end } else { limit },
+        )
+    }
+
+    /// Returns the bounding box of all pixels whose centers lie inside the given half extents.
+    pub fn bounding_box(&self, extents: (Real, Real)) -> Rectangle {
+        // The extents are increased by one unit to include pixels on the boundary, which might
+        // be included due to rounding errors in the local coordinates.
+        let x = scale(extents.0) + 1;
+        let y = scale(extents.1) + 1;
+
+        let center_x = i64::from(self.center_2x.x) * LOCAL_SCALE;
+        let center_y = i64::from(self.center_2x.y) * LOCAL_SCALE;
+
+        let top_left = Point::new(
+            (center_x - x).div_euclid(2 * LOCAL_SCALE) as i32 + 1,
+            (center_y - y).div_euclid(2 * LOCAL_SCALE) as i32 + 1,
+        );
+        let bottom_right = Point::new(
+            -(-(center_x + x)).div_euclid(2 * LOCAL_SCALE) as i32 - 1,
+            -(-(center_y + y)).div_euclid(2 * LOCAL_SCALE) as i32 - 1,
+        );
+
+        Rectangle::with_corners(top_left, bottom_right)
+    }
+}
+
+/// Scales a value by [`LOCAL_SCALE`] and rounds it to the nearest integer.
+fn scale(value: Real) -> i64 {
+    // The integer part is converted separately to prevent overflows when fixed point math is used.
+    let integer = value.round();
+    let fraction = (value - integer) * Real::from(LOCAL_SCALE as i32);
+
+    i64::from(i32::from(integer)) * LOCAL_SCALE + i64::from(i32::from(fraction.round()))
+}
+
+/// Returns `true` if the local point is inside the ellipse with the given size.
+pub fn ellipse_contains(x: i64, y: i64, size: Size) -> bool {
+    let a = i64::from(size.width).pow(2);
+    let b = i64::from(size.height).pow(2);
+
+    b * x * x + a * y * y < a * b * LOCAL_SCALE * LOCAL_SCALE
+}
+
+/// Shape in the local coordinate system of a [`Rotation`].
+pub trait LocalShape {
+    /// Returns `true` if a point in local coordinates is inside the shape.
+    fn contains(&self, x: i64, y: i64) -> bool;
+
+    /// Returns the approximate intersection of a row with the shape.
+    ///
+    /// See [`Rotation::rectangle_chord`] for a description of the parameters and the return
+    /// value.
+    fn chord(&self, rotation: &Rotation, dy: Real, limit: Real) -> (Real, Real);
+}
+
+/// Local shape of a rotated rectangle.
+#[derive(Copy, Clone, Debug)]
+pub struct LocalRectangle {
+    size: Size,
+}
+
+impl LocalRectangle {
+    /// Creates a new local rectangle, which is centered around the origin.
+    pub const fn new(size: Size) -> Self {
+        Self { size }
+    }
+}
+
+impl LocalShape for LocalRectangle {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        x.abs() < i64::from(self.size.width) * LOCAL_SCALE
+            && y.abs() < i64::from(self.size.height) * LOCAL_SCALE
+    }
+
+    fn chord(&self, rotation: &Rotation, dy: Real, limit: Real) -> (Real, Real) {
+        let zero = Real::from(0);
+        let extents = (Real::from(self.size.width), Real::from(self.size.height));
+
+        rotation.rectangle_chord(dy, (zero, zero), extents, limit)
+    }
+}
+
+/// Local shape of a rotated ellipse.
+#[derive(Copy, Clone, Debug)]
+pub struct LocalEllipse {
+    size: Size,
+}
+
+impl LocalEllipse {
+    /// Creates a new local ellipse, which is centered around the origin.
+    pub const fn new(size: Size) -> Self {
+        Self { size }
+    }
+}
+
+impl LocalShape for LocalEllipse {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        ellipse_contains(x, y, self.size)
+    }
+
+    fn chord(&self, rotation: &Rotation, dy: Real, limit: Real) -> (Real, Real) {
+        let zero = Real::from(0);
+        let extents = (Real::from(self.size.width), Real::from(self.size.height));
+
+        rotation.ellipse_chord(dy, (zero, zero), extents, limit)
+    }
+}
+
+/// Shape with a precomputed rotation.
+///
+/// The rotation and all other parameters of the shape are calculated once, which makes it
+/// possible to test many points or draw many scanlines without evaluating trigonometric
+/// functions for each of them.
+#[derive(Copy, Clone, Debug)]
+pub struct RotatedShape<S> {
+    rotation: Rotation,
+    shape: S,
+}
+
+impl<S: LocalShape> RotatedShape<S> {
+    /// Creates a new rotated shape.
+    pub const fn new(rotation: Rotation, shape: S) -> Self {
+        Self { rotation, shape }
+    }
+
+    /// Returns `true` if the point is inside the shape.
+    pub fn contains(&self, point: Point) -> bool {
+        let (x, y) = self.rotation.local_point(point);
+
+        self.shape.contains(x, y)
+    }
+
+    /// Returns the intersection of a row with the shape.
+    ///
+    /// The intersection is first calculated analytically and the end points are then adjusted
+    /// to match [`contains`](Self::contains) exactly. Only points inside `columns` are returned.
+    pub fn scanline(&self, y: i32, columns: &Range<i32>) -> Scanline {
+        if columns.is_empty() {
+            return Scanline::new_empty(y);
+        }
+
+        let center_x = self.rotation.center_2x.x;
+        let t_start = columns.start * 2 - center_x;
+        let t_end = (columns.end - 1) * 2 - center_x;
+        let limit = Real::from(t_start.abs().max(t_end.abs()) + 2);
+
+        let dy = Real::from(y * 2 - self.rotation.center_2x.y);
+        let (mut lo, mut hi) = self.shape.chord(&self.rotation, dy, limit);
+
+        // Rows which are more than one pixel away from the shape are skipped. Closer rows are
+        // checked, because the shape might include a pixel due to rounding errors.
+        if lo > hi + Real::from(2) {
+            return Scanline::new_empty(y);
+        } else if lo > hi {
+            lo = (lo + hi) / Real::from(2);
+            hi = lo;
+        }
+
+        let to_x = |t: Real| i32::from(((t + Real::from(center_x)) / Real::from(2)).round());
+        let mut start = (to_x(lo) - 1).max(columns.start);
+        let mut end = (to_x(hi) + 1).min(columns.end - 1);
+        if start > end {
+            return Scanline::new_empty(y);
+        }
+
+        let contains = |x| self.contains(Point::new(x, y));
+
+        if contains(start) {
+            while start > columns.start && contains(start - 1) {
+                start -= 1;
+            }
+        } else {
+            loop {
+                start += 1;
+                if start > end {
+                    return Scanline::new_empty(y);
+                }
+                if contains(start) {
+                    break;
+                }
+            }
+        }
+
+        if contains(end) {
+            while end < columns.end - 1 && contains(end + 1) {
+                end += 1;
+            }
+        } else {
+            while !contains(end) {
+                end -= 1;
+            }
+        }
+
+        Scanline::new(y, start..end + 1)
+    }
+}
+
+/// Rotated primitive.
+pub(in crate::primitives) trait RotatedPrimitive:
+    OffsetOutline + Dimensions
+{
+    /// Local shape of the primitive.
+    type Shape: LocalShape;
+
+    /// Returns the shape of the primitive with a precomputed rotation.
+    fn rotated_shape(&self) -> RotatedShape<Self::Shape>;
+}
+
+/// Returns the range of `t` for which `|offset + t * direction| < extent`.
+///
+/// The range is limited to `-limit..limit` and an empty range is returned as `(limit, -limit)`.
+fn slab(offset: Real, direction: Real, extent: Real, limit: Real) -> (Real, Real) {
+    if direction == Real::from(0) {
+        return if offset.abs() < extent {
+            (-limit, limit)
+        } else {
+            (limit, -limit)
+        };
+    }
+
+    let t1 = div_limited(-extent - offset, direction, limit);
+    let t2 = div_limited(extent - offset, direction, limit);
+
+    if t1 <= t2 {
+        (t1, t2)
+    } else {
+        (t2, t1)
+    }
+}
+
+/// Divides two numbers and limits the result to `-limit..=limit`.
+///
+/// The limit is checked before the division to prevent overflows when fixed point math is used.
+fn div_limited(numerator: Real, denominator: Real, limit: Real) -> Real {
+    if numerator.abs() >= denominator.abs() * limit {
+        if (numerator < Real::from(0)) != (denominator < Real::from(0)) {
+            -limit
+        } else {
+            limit
+        }
+    } else {
+        numerator / denominator
+    }
+}
+
+/// Draws a styled rotated shape.
+///
+/// The stroke and fill areas are determined by offsetting the outline of the shape. The
+/// intersections of each row with both areas are used to draw the fill and the stroke on both
+/// sides of the fill as scanlines.
+pub fn draw_styled<T, D>(
+    primitive: &T,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    T: RotatedPrimitive,
+    D: DrawTarget,
+{
+    if style.is_transparent() {
+        return Ok(());
+    }
+
+    let stroke_area = style.stroke_area(primitive);
+    let fill_area = style.fill_area(primitive);
+    let stroke_color = style.effective_stroke_color();
+
+    let bounding_box = stroke_area.bounding_box();
+    let columns = bounding_box.columns();
+
+    let stroke_shape = stroke_area.rotated_shape();
+    let fill_shape = fill_area.rotated_shape();
+
+    for y in bounding_box.rows() {
+        let fill = fill_shape.scanline(y, &columns);
+        let stroke = stroke_color.map(|color| (stroke_shape.scanline(y, &columns), color));
+
+        if let Some((stroke, color)) = &stroke {
+            let end = if fill.is_empty() {
+                stroke.x.end
+            } else {
+                stroke.x.end.min(fill.x.start)
+            };
+            Scanline::new(y, stroke.x.start..end).draw(target, *color)?;
+        }
+
+        if let Some(color) = style.fill_color {
+            fill.draw(target, color)?;
+        }
+
+        if let Some((stroke, color)) = &stroke {
+            if !fill.is_empty() {
+                let start = stroke.x.start.max(fill.x.end);
+                Scanline::new(y, start..stroke.x.end).draw(target, *color)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::PointsIter};
+
+    /// Checks that the scanlines of a shape contain the same points as `contains`.
+    fn assert_scanlines_match<S: LocalShape>(shape: &RotatedShape<S>, area: Rectangle) {
+        let columns = area.columns();
+
+        for y in area.rows() {
+            let scanline = shape.scanline(y, &columns);
+
+            for x in columns.clone() {
+                assert_eq!(
+                    scanline.x.contains(&x),
+                    shape.contains(Point::new(x, y)),
+                    "({x}, {y}) {scanline:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rectangle_scanlines() {
+        for size in [
+            Size::new(20, 9),
+            Size::new(1, 30),
+            Size::new(30, 2),
+            Size::new(7, 7),
+        ] {
+            for angle in (0..360).step_by(7) {
+                let rotation = Rotation::new(Point::new(41, 40), (angle as f32).deg());
+                let shape = RotatedShape::new(rotation, LocalRectangle::new(size));
+
+                assert_scanlines_match(&shape, Rectangle::new(Point::zero(), Size::new(42, 42)));
+            }
+        }
+    }
+
+    #[test]
+    fn ellipse_scanlines() {
+        for size in [
+            Size::new(20, 9),
+            Size::new(1, 30),
+            Size::new(30, 2),
+            Size::new(7, 6),
+        ] {
+            for angle in (0..360).step_by(7) {
+                let rotation = Rotation::new(Point::new(40, 41), (angle as f32).deg());
+                let shape = RotatedShape::new(rotation, LocalEllipse::new(size));
+
+                assert_scanlines_match(&shape, Rectangle::new(Point::zero(), Size::new(42, 42)));
+            }
+        }
+    }
+
+    #[test]
+    fn scanline_is_limited_to_columns() {
+        let rotation = Rotation::new(Point::new(20, 20), 30.0.deg());
+        let shape = RotatedShape::new(rotation, LocalRectangle::new(Size::new(16, 8)));
+
+        let area = Rectangle::new(Point::new(8, 5), Size::new(4, 10));
+        assert_scanlines_match(&shape, area);
+
+        assert!(area.rows().all(|y| shape.scanline(y, &(20..20)).is_empty()));
+        assert!(area.points().any(|point| shape.contains(point)));
+    }
+
+    #[test]
+    fn local_point() {
+        let rotation = Rotation::new(Point::new(20, 20), 90.0.deg());
+
+        assert_eq!(rotation.local_point(Point::new(10, 10)), (0, 0));
+        assert_eq!(
+            rotation.local_point(Point::new(13, 10)),
+            (0, -6 * LOCAL_SCALE)
+        );
+        assert_eq!(
+            rotation.local_point(Point::new(10, 13)),
+            (6 * LOCAL_SCALE, 0)
+        );
+    }
+
+    #[test]
+    fn unrotated_bounding_box() {
+        let rectangle = Rectangle::new(Point::new(3, 4), Size::new(5, 6));
+        let center_2x = rectangle.top_left * 2 + rectangle.size - Size::new(1, 1);
+
+        let rotation = Rotation::new(center_2x, 0.0.deg());
+
+        assert_eq!(
+            rotation.bounding_box(rotation.rectangle_extents(rectangle.size)),
+            rectangle
+        );
+        assert_eq!(
+            rotation.bounding_box(rotation.ellipse_extents(rectangle.size)),
+            rectangle
+        );
+    }
+
+    #[test]
+    fn ellipse_contains() {
+        let size = Size::new(10, 6);
+
+        assert!(super::ellipse_contains(0, 0, size));
+        assert!(super::ellipse_contains(9 * LOCAL_SCALE, 0, size));
+        assert!(!super::ellipse_contains(10 * LOCAL_SCALE, 0, size));
+        assert!(super::ellipse_contains(0, 5 * LOCAL_SCALE, size));
+        assert!(!super::ellipse_contains(0, 6 * LOCAL_SCALE, size));
+    }
+}
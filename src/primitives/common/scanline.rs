@@ -1,9 +1,11 @@
 use crate::{
     draw_target::DrawTarget,
     geometry::{Point, Size},
-    primitives::{Line, PointsIter, Rectangle},
+    primitives::{Circle, Line, PointsIter, Rectangle},
+    Pixel,
 };
 use core::ops::Range;
+use integer_sqrt::IntegerSquareRoot;
 
 /// Scanline.
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -66,6 +68,38 @@ impl Scanline {
             });
     }
 
+    /// Extends the scanline to include the intersection with a filled circle.
+    ///
+    /// The circle is expected to overlap or touch the current scanline. Any gap between the
+    /// scanline and the intersection is filled.
+    pub fn circle_intersection(&mut self, circle: &Circle) {
+        let center_2x = circle.center_2x();
+        let dy = i64::from(center_2x.y) - 2 * i64::from(self.y);
+
+        // Points inside the circle satisfy `dx² + dy² < threshold` in doubled coordinates.
+        let remaining = i64::from(circle.threshold()) - dy * dy;
+        if remaining <= 0 {
+            return;
+        }
+
+        // Largest `dx` with `dx² < remaining`.
+        let root = (remaining as u64).integer_sqrt() as i64;
+        let max_dx = if root * root == remaining {
+            root - 1
+        } else {
+            root
+        };
+
+        let center_x = i64::from(center_2x.x);
+        let start = (center_x - max_dx + 1).div_euclid(2);
+        let end = (center_x + max_dx).div_euclid(2);
+
+        if start <= end {
+            self.extend(start as i32);
+            self.extend(end as i32);
+        }
+    }
+
     /// Check for lines that are adjacent or overlapping.
     ///
     /// This assumes that both lines have the same y coordinate.
@@ -152,6 +186,41 @@ impl Scanline {
             color,
         )
     }
+
+    /// Draws pixels by combining adjacent pixels with the same color into scanlines.
+    ///
+    /// Only pixels which directly follow each other in the iterator are combined. The pixels should
+    /// therefore be ordered by their y coordinate first and their x coordinate second.
+    pub fn draw_pixels<I, T>(pixels: I, target: &mut T) -> Result<(), T::Error>
+    where
+        I: IntoIterator<Item = Pixel<T::Color>>,
+        T: DrawTarget,
+    {
+        let mut scanline = Scanline::new_empty(0);
+        let mut scanline_color = None;
+
+        for Pixel(point, color) in pixels {
+            let pixel = Scanline::new(point.y, point.x..point.x + 1);
+
+            if scanline_color == Some(color) && scanline.y == point.y && scanline.try_extend(&pixel)
+            {
+                continue;
+            }
+
+            if let Some(color) = scanline_color {
+                scanline.draw(target, color)?;
+            }
+
+            scanline = pixel;
+            scanline_color = Some(color);
+        }
+
+        if let Some(color) = scanline_color {
+            scanline.draw(target, color)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl Iterator for Scanline {
@@ -165,6 +234,7 @@ impl Iterator for Scanline {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{geometry::OriginDimensions, pixelcolor::BinaryColor};
 
     fn run_touches_test(s1: i32, e1: i32, s2: i32, e2: i32, expected: bool, ident: &str) {
         let mut l1 = Scanline::new_empty(0);
@@ -206,4 +276,78 @@ mod tests {
         assert!(result);
         assert_eq!(l1, Scanline { y: 5, x: 11..26 });
     }
+
+    #[test]
+    fn draw_pixels_combines_adjacent_pixels() {
+        /// Draw target that records the rectangles passed to `fill_solid`.
+        struct FillRecorder {
+            rectangles: [(Rectangle, BinaryColor); 4],
+            len: usize,
+        }
+
+        impl DrawTarget for FillRecorder {
+            type Color = BinaryColor;
+            type Error = core::convert::Infallible;
+
+            fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+            where
+                I: IntoIterator<Item = Pixel<Self::Color>>,
+            {
+                unreachable!()
+            }
+
+            fn fill_solid(
+                &mut self,
+                area: &Rectangle,
+                color: Self::Color,
+            ) -> Result<(), Self::Error> {
+                self.rectangles[self.len] = (*area, color);
+                self.len += 1;
+
+                Ok(())
+            }
+        }
+
+        impl OriginDimensions for FillRecorder {
+            fn size(&self) -> Size {
+                Size::new(10, 10)
+            }
+        }
+
+        let mut target = FillRecorder {
+            rectangles: [(Rectangle::zero(), BinaryColor::Off); 4],
+            len: 0,
+        };
+
+        let pixels = [
+            Pixel(Point::new(1, 1), BinaryColor::On),
+            Pixel(Point::new(2, 1), BinaryColor::On),
+            Pixel(Point::new(3, 1), BinaryColor::Off),
+            Pixel(Point::new(5, 1), BinaryColor::Off),
+            Pixel(Point::new(6, 2), BinaryColor::Off),
+        ];
+        Scanline::draw_pixels(pixels, &mut target).unwrap();
+
+        assert_eq!(
+            target.rectangles,
+            [
+                (
+                    Rectangle::new(Point::new(1, 1), Size::new(2, 1)),
+                    BinaryColor::On
+                ),
+                (
+                    Rectangle::new(Point::new(3, 1), Size::new(1, 1)),
+                    BinaryColor::Off
+                ),
+                (
+                    Rectangle::new(Point::new(5, 1), Size::new(1, 1)),
+                    BinaryColor::Off
+                ),
+                (
+                    Rectangle::new(Point::new(6, 2), Size::new(1, 1)),
+                    BinaryColor::Off
+                ),
+            ]
+        );
+    }
 }
@@ -0,0 +1,240 @@
+//! Helpers for scanline renderers.
+//!
+//! These renderers determine the x coordinates at which the pixel type changes for one row at a
+//! time. The changes are collected in a bounded buffer, which is sorted by the x coordinate.
+
+use core::ops::Range;
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{PointType, Scanline},
+        FillRule, Line,
+    },
+};
+
+/// Subpixel resolution used for scanline crossings.
+pub const SUBPIXELS: i64 = 256;
+
+/// Number of row events that are buffered by default.
+pub const DEFAULT_ROW_EVENTS: usize = 32;
+
+/// Change of the pixel type in a row.
+///
+/// Events are stored in a buffer while a row is drawn. Each event contains the changes of the
+/// number of stroke and boundary intervals and of the winding number at an x coordinate.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RowEvent {
+    x: i32,
+    stroke: i32,
+    boundary: i32,
+    winding: i32,
+}
+
+/// Events of a single row.
+///
+/// Only events to the right of the `start` coordinate are collected. If the buffer is full, the
+/// events with the largest x coordinates are discarded and the row is processed in multiple
+/// passes.
+pub struct Row<'a> {
+    y: i32,
+    start: i32,
+    events: &'a mut [RowEvent],
+    len: usize,
+    cutoff: Option<i32>,
+}
+
+impl<'a> Row<'a> {
+    fn new(y: i32, start: i32, events: &'a mut [RowEvent]) -> Self {
+        Self {
+            y,
+            start,
+            events,
+            len: 0,
+            cutoff: None,
+        }
+    }
+
+    /// Returns the y coordinate of the row.
+    pub const fn y(&self) -> i32 {
+        self.y
+    }
+
+    /// Adds a stroke interval.
+    pub fn add_stroke(&mut self, scanline: &Scanline) {
+        if !scanline.is_empty() {
+            self.add(scanline.x.start, 1, 0, 0);
+            self.add(scanline.x.end, -1, 0, 0);
+        }
+    }
+
+    /// Adds a boundary interval, which is filled independent of the fill rule.
+    pub fn add_boundary(&mut self, scanline: &Scanline) {
+        if !scanline.is_empty() {
+            self.add(scanline.x.start, 0, 1, 0);
+            self.add(scanline.x.end, 0, -1, 0);
+        }
+    }
+
+    /// Adds a crossing of an edge, which is returned by [`crossing`].
+    pub fn add_crossing(&mut self, crossing: i64, direction: i32) {
+        // First pixel with a center on or to the right of the crossing.
+        let x = (crossing + SUBPIXELS - 1).div_euclid(SUBPIXELS) as i32;
+
+        self.add(x, 0, 0, direction);
+    }
+
+    fn add(&mut self, x: i32, stroke: i32, boundary: i32, winding: i32) {
+        if x <= self.start || self.cutoff.is_some_and(|cutoff| x >= cutoff) {
+            return;
+        }
+
+        let index = match self.events[0..self.len].binary_search_by_key(&x, |event| event.x) {
+            Ok(index) => {
+                let event = &mut self.events[index];
+                event.stroke += stroke;
+                event.boundary += boundary;
+                event.winding += winding;
+
+                return;
+            }
+            Err(index) => index,
+        };
+
+        if self.len == self.events.len() {
+            // Discard the event with the largest x coordinate. All events at or to the right of
+            // the discarded event are handled in the next pass.
+            if index == self.len {
+                self.cutoff = Some(x);
+                return;
+            }
+
+            self.len -= 1;
+            self.cutoff = Some(self.events[self.len].x);
+        }
+
+        self.events.copy_within(index..self.len, index + 1);
+        self.events[index] = RowEvent {
+            x,
+            stroke,
+            boundary,
+            winding,
+        };
+        self.len += 1;
+    }
+}
+
+/// Calls `f` for each scanline run with the same pixel type in the given rows.
+///
+/// `add_events` is called for each row and must add all stroke intervals, boundary intervals and
+/// edge crossings in that row. Rows with more events than fit into the buffer are processed in
+/// multiple passes, which call `add_events` again. An empty buffer is replaced by a buffer with a
+/// single entry.
+pub fn for_each_row_scanline<A, F, E>(
+    rows: Range<i32>,
+    buffer: &mut [RowEvent],
+    fill_rule: FillRule,
+    mut add_events: A,
+    mut f: F,
+) -> Result<(), E>
+where
+    A: FnMut(&mut Row),
+    F: FnMut(Scanline, PointType) -> Result<(), E>,
+{
+    let mut single = [RowEvent::default()];
+    let buffer = if buffer.is_empty() {
+        &mut single[..]
+    } else {
+        buffer
+    };
+
+    for y in rows {
+        let mut stroke = 0;
+        let mut boundary = 0;
+        let mut winding = 0;
+
+        let mut run_start = i32::MIN;
+        let mut run_type = None;
+
+        let mut start = i32::MIN;
+
+        loop {
+            let mut row = Row::new(y, start, buffer);
+            add_events(&mut row);
+
+            let Row { len, cutoff, .. } = row;
+
+            for event in buffer[0..len]
+                .iter()
+                .take_while(|event| cutoff.map_or(true, |cutoff| event.x < cutoff))
+            {
+                stroke += event.stroke;
+                boundary += event.boundary;
+                winding += event.winding;
+
+                let inside = match fill_rule {
+                    FillRule::NonZero => winding != 0,
+                    FillRule::EvenOdd => winding % 2 != 0,
+                };
+
+                let point_type = if stroke > 0 {
+                    Some(PointType::Stroke)
+                } else if boundary > 0 || inside {
+                    Some(PointType::Fill)
+                } else {
+                    None
+                };
+
+                if point_type != run_type {
+                    if let Some(run_type) = run_type {
+                        f(Scanline::new(y, run_start..event.x), run_type)?;
+                    }
+
+                    run_start = event.x;
+                    run_type = point_type;
+                }
+            }
+
+            match cutoff {
+                Some(cutoff) => start = cutoff - 1,
+                None => break,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the intersection of a scanline with the Bresenham representation of a line.
+pub fn bresenham_intersection(start: Point, end: Point, y: i32) -> Scanline {
+    let mut scanline = Scanline::new_empty(y);
+    scanline.bresenham_intersection(&Line::new(start, end));
+
+    scanline
+}
+
+/// Returns the crossing of a line with the horizontal line through the pixel centers of a row.
+///
+/// The x coordinate of the crossing is returned in subpixels, together with the winding
+/// direction. The end point with the larger y coordinate isn't included to make sure that
+/// crossings at vertices are only counted once.
+pub fn crossing(start: Point, end: Point, y: i32) -> Option<(i64, i32)> {
+    let (top, bottom, direction) = if start.y < end.y {
+        (start, end, 1)
+    } else if start.y > end.y {
+        (end, start, -1)
+    } else {
+        return None;
+    };
+
+    if y < top.y || y >= bottom.y {
+        return None;
+    }
+
+    let dy = i64::from(bottom.y - top.y);
+    let dx = i64::from(bottom.x - top.x);
+    let x = i64::from(top.x) * SUBPIXELS + (i64::from(y - top.y) * dx * SUBPIXELS).div_euclid(dy);
+
+    Some((x, direction))
+}
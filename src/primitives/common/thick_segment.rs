@@ -4,11 +4,12 @@ use crate::{
     geometry::Dimensions,
     primitives::{
         common::{LineJoin, Scanline},
-        Line, Rectangle,
+        Circle, Line, Rectangle,
     },
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct ThickSegment {
     start_join: LineJoin,
     end_join: LineJoin,
@@ -46,7 +47,8 @@ impl ThickSegment {
     ///
     /// Note that this does not include any bevel/cap lines as returned by `perimeter` which is why
     /// this is not `impl Dimensions`. These lines don't need to be included as other segments
-    /// in the polyline will expand the bounding box to the right place anyway.
+    /// in the polyline will expand the bounding box to the right place anyway. Circles of round
+    /// joins and caps are included.
     pub fn edges_bounding_box(&self) -> Rectangle {
         let (right, left) = self.edges();
 
@@ -54,18 +56,32 @@ impl ThickSegment {
             return left.bounding_box();
         }
 
-        Rectangle::with_corners(
-            right
-                .start
-                .component_min(right.end)
-                .component_min(left.start)
-                .component_min(left.end),
-            right
-                .start
-                .component_max(right.end)
-                .component_max(left.start)
-                .component_max(left.end),
-        )
+        let mut min = right
+            .start
+            .component_min(right.end)
+            .component_min(left.start)
+            .component_min(left.end);
+        let mut max = right
+            .start
+            .component_max(right.end)
+            .component_max(left.start)
+            .component_max(left.end);
+
+        for disc in self.discs() {
+            let bounding_box = disc.bounding_box();
+
+            if let Some(bottom_right) = bounding_box.bottom_right() {
+                min = min.component_min(bounding_box.top_left);
+                max = max.component_max(bottom_right);
+            }
+        }
+
+        Rectangle::with_corners(min, max)
+    }
+
+    /// Returns the circles of round joins and caps at both ends of the segment.
+    fn discs(&self) -> impl Iterator<Item = Circle> {
+        self.start_join.disc.into_iter().chain(self.end_join.disc)
     }
 
     pub fn intersection(&self, scanline_y: i32) -> Scanline {
@@ -90,6 +106,12 @@ impl ThickSegment {
             let (line1, line2) = self.edges();
             scanline.bresenham_intersection(&line1);
             scanline.bresenham_intersection(&line2);
+
+            // The segment and the circles at its ends form a convex shape, which guarantees
+            // that the intersections are contiguous.
+            for disc in self.discs() {
+                scanline.circle_intersection(&disc);
+            }
         }
 
         scanline
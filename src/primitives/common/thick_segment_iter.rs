@@ -2,7 +2,7 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{JoinKind, LineJoin, StrokeOffset, ThickSegment},
+    primitives::common::{JoinKind, LineJoin, StrokeOffset, ThickSegment, ThickStroke},
 };
 
 /// Thick segments iterator.
@@ -17,8 +17,7 @@ pub struct ThickSegmentIter<'a> {
     windows: core::slice::Windows<'a, Point>,
     start_join: LineJoin,
     end_join: LineJoin,
-    width: u32,
-    stroke_offset: StrokeOffset,
+    stroke: ThickStroke,
     points: &'a [Point],
     stop: bool,
 }
@@ -27,37 +26,35 @@ static EMPTY: &[Point; 0] = &[];
 
 impl<'a> ThickSegmentIter<'a> {
     /// Create a new thick segments iterator.
-    pub fn new(points: &'a [Point], width: u32, _stroke_offset: StrokeOffset) -> Self {
+    pub fn new(points: &'a [Point], stroke: ThickStroke) -> Self {
         // Fix stroke alignment to None. There are issues with degenerate joints when using
         // Inside/Outside stroke alignment on polylines, so this is disabled for now.
-        let stroke_offset = StrokeOffset::None;
+        let stroke = stroke.with_offset(StrokeOffset::None);
 
         let mut windows = points.windows(3);
 
         if let Some([start, mid, end]) = windows.next() {
-            let start_join = LineJoin::start(*start, *mid, width, stroke_offset);
-            let end_join = LineJoin::from_points(*start, *mid, *end, width, stroke_offset);
+            let start_join = LineJoin::start(*start, *mid, stroke);
+            let end_join = LineJoin::from_points(*start, *mid, *end, stroke);
 
             Self {
                 windows,
                 start_join,
                 end_join,
-                width,
-                stroke_offset,
+                stroke,
                 points,
                 stop: false,
             }
         } else if let [start, end] = points {
             // Single line segment.
-            let start_join = LineJoin::start(*start, *end, width, stroke_offset);
-            let end_join = LineJoin::end(*start, *end, width, stroke_offset);
+            let start_join = LineJoin::start(*start, *end, stroke);
+            let end_join = LineJoin::end(*start, *end, stroke);
 
             Self {
                 windows: EMPTY.windows(3),
                 start_join,
                 end_join,
-                width,
-                stroke_offset,
+                stroke,
                 points,
                 stop: false,
             }
@@ -73,8 +70,7 @@ impl<'a> ThickSegmentIter<'a> {
             windows: EMPTY.windows(3),
             start_join: LineJoin::empty(),
             end_join: LineJoin::empty(),
-            width: 0,
-            stroke_offset: StrokeOffset::None,
+            stroke: ThickStroke::new(0, StrokeOffset::None),
             points: EMPTY,
             stop: true,
         }
@@ -94,13 +90,12 @@ impl Iterator for ThickSegmentIter<'_> {
         self.start_join = self.end_join;
 
         if let Some([start, mid, end]) = self.windows.next() {
-            self.end_join =
-                LineJoin::from_points(*start, *mid, *end, self.width, self.stroke_offset);
+            self.end_join = LineJoin::from_points(*start, *mid, *end, self.stroke);
         } else if self.end_join.kind != JoinKind::End {
             let start = *self.points.get(self.points.len() - 2)?;
             let end = *self.points.last()?;
 
-            self.end_join = LineJoin::end(start, end, self.width, self.stroke_offset);
+            self.end_join = LineJoin::end(start, end, self.stroke);
         } else {
             self.stop = true;
         }
@@ -0,0 +1,60 @@
+//! Thick stroke settings.
+
+use crate::{
+    pixelcolor::PixelColor,
+    primitives::{common::StrokeOffset, LineCap, LineJoin, PrimitiveStyle},
+};
+
+/// Thick stroke settings.
+///
+/// Combines the style properties that are required to construct line joins and caps.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct ThickStroke {
+    /// Stroke width.
+    pub width: u32,
+
+    /// Stroke offset.
+    pub offset: StrokeOffset,
+
+    /// Line cap.
+    pub line_cap: LineCap,
+
+    /// Line join.
+    pub line_join: LineJoin,
+
+    /// Miter limit.
+    pub miter_limit: u32,
+}
+
+impl ThickStroke {
+    /// Creates thick stroke settings with butt caps and miter joins.
+    pub const fn new(width: u32, offset: StrokeOffset) -> Self {
+        Self {
+            width,
+            offset,
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+            miter_limit: 4,
+        }
+    }
+
+    /// Creates thick stroke settings from a primitive style.
+    pub const fn from_style<C: PixelColor>(
+        style: &PrimitiveStyle<C>,
+        offset: StrokeOffset,
+    ) -> Self {
+        Self {
+            width: style.stroke_width,
+            offset,
+            line_cap: style.line_cap,
+            line_join: style.line_join,
+            miter_limit: style.miter_limit,
+        }
+    }
+
+    /// Returns a copy of these settings with a different stroke offset.
+    pub const fn with_offset(self, offset: StrokeOffset) -> Self {
+        Self { offset, ..self }
+    }
+}
@@ -0,0 +1,330 @@
+//! The cubic Bézier curve primitive
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::FlattenedCurve,
+        styled::{StyledDimensions, StyledDrawable},
+        Primitive, PrimitiveStyle, Rectangle,
+    },
+    transform::{Affine2, AffineTransform, Transform},
+};
+
+/// Cubic Bézier curve primitive
+///
+/// A cubic Bézier curve starts at `start`, ends at `end` and is bent towards the `control1` and
+/// `control2` points. The direction of the curve at the start point is given by `control1` and
+/// at the end point by `control2`. The curve is drawn by flattening it into a polyline, which
+/// means that thick strokes use the same line joins as [`Polyline`]. The fill color of the style
+/// is ignored, because a curve isn't a closed shape.
+///
+/// The number of line segments is chosen adaptively, based on the curvature, and limited to 64
+/// segments.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, CubicBezier},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Red 3 pixel wide S-curve from (5, 50) to (55, 10)
+/// CubicBezier::new(
+///     Point::new(5, 50),
+///     Point::new(40, 50),
+///     Point::new(20, 10),
+///     Point::new(55, 10),
+/// )
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Polyline`]: crate::primitives::Polyline
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct CubicBezier {
+    /// Start point
+    pub start: Point,
+
+    /// First control point
+    pub control1: Point,
+
+    /// Second control point
+    pub control2: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl CubicBezier {
+    /// Creates a cubic Bézier curve.
+    pub const fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        Self {
+            start,
+            control1,
+            control2,
+            end,
+        }
+    }
+
+    /// Returns the curve flattened into line segments.
+    pub(in crate::primitives) fn flatten(&self) -> FlattenedCurve {
+        FlattenedCurve::cubic(self.start, self.control1, self.control2, self.end)
+    }
+}
+
+impl Primitive for CubicBezier {}
+
+impl Dimensions for CubicBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.flatten().polyline().bounding_box()
+    }
+}
+
+impl Transform for CubicBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `CubicBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(5, 0),
+    ///     Point::new(15, 0),
+    ///     Point::new(15, 10),
+    /// );
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Point::new(15, 20));
+    /// assert_eq!(moved.control1, Point::new(15, 10));
+    /// assert_eq!(moved.control2, Point::new(25, 10));
+    /// assert_eq!(moved.end, Point::new(25, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control1: self.control1 + by,
+            control2: self.control2 + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve = CubicBezier::new(
+    ///     Point::new(5, 10),
+    ///     Point::new(5, 0),
+    ///     Point::new(15, 0),
+    ///     Point::new(15, 10),
+    /// );
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(curve.start, Point::new(15, 20));
+    /// assert_eq!(curve.control1, Point::new(15, 10));
+    /// assert_eq!(curve.control2, Point::new(25, 10));
+    /// assert_eq!(curve.end, Point::new(25, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control1 += by;
+        self.control2 += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl AffineTransform for CubicBezier {
+    type Output = Self;
+
+    /// Transforms the start, end and control points of the curve.
+    ///
+    /// Affine transformations map Bézier curves onto Bézier curves, which is why the result is
+    /// exact up to the rounding of the points.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::CubicBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Point::new(0, 10),
+    ///     Point::new(3, 0),
+    ///     Point::new(7, 0),
+    ///     Point::new(10, 10),
+    /// );
+    /// let scaled = curve.transform(&Affine2::scale(2.0, 3.0));
+    ///
+    /// assert_eq!(
+    ///     scaled,
+    ///     CubicBezier::new(
+    ///         Point::new(0, 30),
+    ///         Point::new(6, 0),
+    ///         Point::new(14, 0),
+    ///         Point::new(20, 30),
+    ///     )
+    /// );
+    /// ```
+    fn transform(&self, affine: &Affine2) -> Self {
+        Self {
+            start: affine.transform_point(self.start),
+            control1: affine.transform_point(self.control1),
+            control2: affine.transform_point(self.control2),
+            end: affine.transform_point(self.end),
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for CubicBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.flatten().polyline().draw_styled(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for CubicBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        self.flatten().polyline().styled_bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, QuadraticBezier},
+        Drawable,
+    };
+
+    #[test]
+    fn thin_stroke() {
+        let mut display = MockDisplay::new();
+
+        CubicBezier::new(
+            Point::new(0, 8),
+            Point::new(8, 8),
+            Point::new(4, 0),
+            Point::new(12, 0),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_pattern(&[
+            "         ####",
+            "        #    ",
+            "        #    ",
+            "      ##     ",
+            "      #      ",
+            "      #      ",
+            "    ##       ",
+            "   #         ",
+            "####         ",
+        ]);
+    }
+
+    #[test]
+    fn thick_stroke() {
+        let mut display = MockDisplay::new();
+
+        let curve = CubicBezier::new(
+            Point::new(2, 20),
+            Point::new(30, 20),
+            Point::new(2, 2),
+            Point::new(30, 2),
+        )
+        .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 4));
+
+        curve.draw(&mut display).unwrap();
+
+        assert_eq!(display.affected_area(), curve.bounding_box());
+    }
+
+    #[test]
+    fn degree_elevated_quadratic() {
+        // A quadratic curve can be represented by a cubic curve with control points at 2/3 of
+        // the distance to the quadratic control point.
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let mut expected = MockDisplay::new();
+        QuadraticBezier::new(Point::new(0, 30), Point::new(15, 0), Point::new(30, 30))
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        CubicBezier::new(
+            Point::new(0, 30),
+            Point::new(10, 10),
+            Point::new(20, 10),
+            Point::new(30, 30),
+        )
+        .into_styled(style)
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn straight_curve_matches_line() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+
+        let mut expected = MockDisplay::new();
+        Line::new(Point::new(2, 2), Point::new(20, 8))
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        CubicBezier::new(
+            Point::new(2, 2),
+            Point::new(8, 4),
+            Point::new(14, 6),
+            Point::new(20, 8),
+        )
+        .into_styled(style)
+        .draw(&mut display)
+        .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let curve = CubicBezier::new(
+            Point::new(0, 8),
+            Point::new(8, 8),
+            Point::new(4, 0),
+            Point::new(12, 0),
+        );
+
+        assert_eq!(
+            curve.bounding_box(),
+            Rectangle::new(Point::new(0, 0), Size::new(13, 9))
+        );
+    }
+}
@@ -1,9 +1,11 @@
 //! The ellipse primitive
 
 use crate::{
-    geometry::{Dimensions, Point, Size},
-    primitives::{circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    geometry::{Angle, Dimensions, Point, Size},
+    primitives::{
+        circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle, RotatedEllipse,
+    },
+    transform::{Affine2, AffineTransform, Transform},
 };
 
 mod points;
@@ -215,6 +217,26 @@ impl EllipseContains {
     }
 }
 
+impl AffineTransform for Ellipse {
+    type Output = RotatedEllipse;
+
+    /// Transforms the ellipse into a rotated ellipse.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Ellipse;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let ellipse = Ellipse::with_center(Point::new(20, 20), Size::new(20, 10));
+    /// let rotated = ellipse.transform(&Affine2::skew(30.0.deg(), 0.0.deg()));
+    ///
+    /// assert_ne!(rotated.rotation, Angle::zero());
+    /// ```
+    fn transform(&self, affine: &Affine2) -> RotatedEllipse {
+        RotatedEllipse::new(*self, Angle::zero()).transform(affine)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
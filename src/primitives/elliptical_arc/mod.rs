@@ -0,0 +1,233 @@
+//! The elliptical arc primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Size},
+    primitives::{ellipse, Ellipse, PointsIter, Primitive, Rectangle},
+    transform::Transform,
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Elliptical arc primitive
+///
+/// An elliptical arc is a part of the outline of an [`Ellipse`]. The start and sweep angles are
+/// measured from the center of the ellipse, which means that an elliptical arc with equal width
+/// and height is drawn like an [`Arc`] with the same angles.
+///
+/// The fill color of the style is ignored, because an elliptical arc is an open shape. The ends
+/// of the arc are drawn using the [`line_cap`](crate::primitives::PrimitiveStyle::line_cap)
+/// setting.
+///
+/// # Examples
+///
+/// ## Create some elliptical arcs with different styles
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{EllipticalArc, PrimitiveStyle, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Elliptical arc with 1 pixel wide white stroke with top-left point at (10, 20) with a size of
+/// // (40, 20)
+/// EllipticalArc::new(Point::new(10, 20), Size::new(40, 20), 0.0.deg(), 90.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Elliptical arc with styled stroke with top-left point at (15, 25) with a size of (30, 15)
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .build();
+///
+/// EllipticalArc::new(Point::new(15, 25), Size::new(30, 15), 180.0.deg(), -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Arc`]: crate::primitives::Arc
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct EllipticalArc {
+    /// Top-left point of the bounding-box of the ellipse supporting the arc
+    pub top_left: Point,
+
+    /// Size of the ellipse supporting the arc
+    pub size: Size,
+
+    /// Angle at which the arc starts
+    pub angle_start: Angle,
+
+    /// Angle defining the arc sweep starting at angle_start
+    pub angle_sweep: Angle,
+}
+
+impl EllipticalArc {
+    /// Create a new elliptical arc delimited with a top-left point with a specific size and start
+    /// and sweep angles
+    pub const fn new(top_left: Point, size: Size, angle_start: Angle, angle_sweep: Angle) -> Self {
+        EllipticalArc {
+            top_left,
+            size,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Create a new elliptical arc centered around a given point with a specific size and start
+    /// and sweep angles
+    pub const fn with_center(
+        center: Point,
+        size: Size,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        Self::from_ellipse(Ellipse::with_center(center, size), angle_start, angle_sweep)
+    }
+
+    /// Creates an elliptical arc based on an ellipse.
+    ///
+    /// The resulting arc will match the `top_left` and `size` of the base ellipse.
+    pub const fn from_ellipse(ellipse: Ellipse, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Self {
+            top_left: ellipse.top_left,
+            size: ellipse.size,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Returns an ellipse with the same `top_left` and `size` as this arc.
+    pub const fn to_ellipse(&self) -> Ellipse {
+        Ellipse::new(self.top_left, self.size)
+    }
+
+    /// Return the center point of the arc.
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns the center point of the arc scaled by a factor of 2.
+    fn center_2x(&self) -> Point {
+        ellipse::center_2x(self.top_left, self.size)
+    }
+}
+
+impl Primitive for EllipticalArc {}
+
+impl PointsIter for EllipticalArc {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl Dimensions for EllipticalArc {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, self.size)
+    }
+}
+
+impl Transform for EllipticalArc {
+    /// Translate the elliptical arc from its current position to a new position by (x, y)
+    /// pixels, returning a new `EllipticalArc`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::EllipticalArc;
+    /// # use embedded_graphics::prelude::*;
+    /// let arc = EllipticalArc::new(Point::new(5, 10), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+    /// let moved = arc.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the elliptical arc from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::EllipticalArc;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut arc = EllipticalArc::new(Point::new(5, 10), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+    /// arc.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(arc.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::Arc};
+
+    #[test]
+    fn dimensions() {
+        let arc = EllipticalArc::new(Point::new(5, 15), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+
+        assert_eq!(
+            arc.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(20, 10))
+        );
+    }
+
+    #[test]
+    fn it_handles_negative_coordinates() {
+        let size = Size::new(9, 5);
+        let positive = EllipticalArc::new(Point::new(10, 10), size, 0.0.deg(), 90.0.deg()).points();
+
+        let negative =
+            EllipticalArc::new(Point::new(-10, -10), size, 0.0.deg(), 90.0.deg()).points();
+
+        assert!(negative.eq(positive.map(|p| p - Point::new(20, 20))));
+    }
+
+    #[test]
+    fn center_is_correct() {
+        let arc = EllipticalArc::new(Point::new(10, 10), Size::new(5, 6), 0.0.deg(), 90.0.deg());
+        assert_eq!(arc.center(), Point::new(12, 12));
+
+        let arc =
+            EllipticalArc::with_center(Point::new(10, 10), Size::new(5, 6), 0.0.deg(), 90.0.deg());
+        assert_eq!(arc.center(), Point::new(10, 10));
+    }
+
+    #[test]
+    fn circular_arc_points() {
+        for diameter in [5, 6, 11, 20] {
+            for (angle_start, angle_sweep) in [
+                (0.0.deg(), 90.0.deg()),
+                (30.0.deg(), 200.0.deg()),
+                (-45.0.deg(), -120.0.deg()),
+            ] {
+                let arc = Arc::new(Point::new(1, 2), diameter, angle_start, angle_sweep);
+                let elliptical_arc = EllipticalArc::new(
+                    Point::new(1, 2),
+                    Size::new_equal(diameter),
+                    angle_start,
+                    angle_sweep,
+                );
+
+                assert!(arc.points().eq(elliptical_arc.points()));
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::{DistanceIterator, PlaneSector},
+        ellipse::EllipseContains,
+        elliptical_arc::EllipticalArc,
+        OffsetOutline,
+    },
+};
+
+/// Iterator over all points on the elliptical arc line.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+
+    outer_ellipse: EllipseContains,
+    inner_ellipse: EllipseContains,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(arc: &EllipticalArc) -> Self {
+        let outer_ellipse = arc.to_ellipse();
+        let inner_ellipse = outer_ellipse.offset(-1);
+
+        let plane_sector = PlaneSector::new(arc.angle_start, arc.angle_sweep);
+
+        Self {
+            // PERF: The distance iterator should use the smaller arc bounding box
+            iter: DistanceIterator::new(arc.center_2x(), &outer_ellipse.bounding_box()),
+            plane_sector,
+            outer_ellipse: EllipseContains::new(outer_ellipse.size),
+            inner_ellipse: EllipseContains::new(inner_ellipse.size),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .find(|(_, delta, _)| {
+                self.outer_ellipse.contains(*delta)
+                    && !self.inner_ellipse.contains(*delta)
+                    && self.plane_sector.contains(*delta)
+            })
+            .map(|(point, ..)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Size},
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Pixel,
+    };
+
+    #[test]
+    fn points_equals_stroke() {
+        let arc =
+            EllipticalArc::with_center(Point::new(10, 10), Size::new(9, 5), 0.0.deg(), 90.0.deg());
+
+        let styled_points = arc
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .pixels()
+            .map(|Pixel(p, _)| p);
+
+        assert!(arc.points().eq(styled_points));
+    }
+}
@@ -0,0 +1,345 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Real},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{ArcCaps, DistanceIterator, PlaneSector},
+        ellipse::EllipseContains,
+        elliptical_arc::EllipticalArc,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        OffsetOutline, PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+
+use az::SaturatingAs;
+
+/// Pixel iterator for each pixel in the elliptical arc border
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<C> {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+    caps: Option<ArcCaps>,
+
+    outside_edge: EllipseContains,
+    inside_edge: EllipseContains,
+
+    stroke_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(primitive: &EllipticalArc, style: &PrimitiveStyle<C>) -> Self {
+        let ellipse = primitive.to_ellipse();
+
+        let outside_edge = ellipse.offset(style.outside_stroke_width().saturating_as());
+        let inside_edge = ellipse.offset(-style.inside_stroke_width().saturating_as::<i32>());
+
+        let iter = if !style.is_transparent() {
+            // PERF: The distance iterator should use the smaller arc bounding box
+            let cap_offset = ArcCaps::outside_offset(style).saturating_as();
+            DistanceIterator::new(
+                primitive.center_2x(),
+                &outside_edge.bounding_box().offset(cap_offset),
+            )
+        } else {
+            DistanceIterator::empty()
+        };
+
+        let plane_sector = PlaneSector::new(primitive.angle_start, primitive.angle_sweep);
+
+        // The center line of the stroke lies halfway between the inside and outside edge.
+        let radius = |outside: u32, inside: u32| Real::from(outside + inside) / Real::from(2);
+        let caps = ArcCaps::new(
+            primitive.angle_start,
+            primitive.angle_sweep,
+            (
+                radius(outside_edge.size.width, inside_edge.size.width),
+                radius(outside_edge.size.height, inside_edge.size.height),
+            ),
+            style,
+        );
+
+        Self {
+            iter,
+            plane_sector,
+            caps,
+            outside_edge: EllipseContains::new(outside_edge.size),
+            inside_edge: EllipseContains::new(inside_edge.size),
+            stroke_color: style.stroke_color,
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stroke_color = self.stroke_color?;
+
+        self.iter
+            .find(|(_, delta, _)| {
+                (self.outside_edge.contains(*delta)
+                    && !self.inside_edge.contains(*delta)
+                    && self.plane_sector.contains(*delta))
+                    || self.caps.is_some_and(|caps| caps.contains(*delta))
+            })
+            .map(|(point, ..)| Pixel(point, stroke_color))
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for EllipticalArc {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for EllipticalArc {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for EllipticalArc {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset =
+            (style.outside_stroke_width() + ArcCaps::outside_offset(style)).saturating_as();
+
+        self.bounding_box().offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::{AnchorPoint, AngleUnit, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Arc, Ellipse, LineCap, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn tiny_elliptical_arc() {
+        let mut display = MockDisplay::new();
+
+        EllipticalArc::new(Point::zero(), Size::new(11, 7), 210.0.deg(), 120.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   #####   ", //
+            "  #     #  ", //
+        ]);
+    }
+
+    #[test]
+    fn matches_arc() {
+        for stroke_width in [1, 2, 5] {
+            for stroke_alignment in [
+                StrokeAlignment::Inside,
+                StrokeAlignment::Center,
+                StrokeAlignment::Outside,
+            ] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(stroke_alignment)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Arc::new(Point::new(6, 6), 21, 30.0.deg(), 240.0.deg())
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                EllipticalArc::new(Point::new(6, 6), Size::new(21, 21), 30.0.deg(), 240.0.deg())
+                    .into_styled(style)
+                    .draw(&mut display)
+                    .unwrap();
+
+                display.assert_eq(&expected);
+            }
+        }
+    }
+
+    /// Draws elliptical arcs with +/-90° sweep angle and compares the result with drawing a
+    /// quarter of an ellipse.
+    #[test]
+    fn quadrant_arcs() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 2);
+
+        for size in [Size::new(21, 11), Size::new(12, 20)] {
+            for (angle_start, angle_sweep, anchor_point) in [
+                (0.0.deg(), 90.0.deg(), AnchorPoint::BottomRight),
+                (90.0.deg(), 90.0.deg(), AnchorPoint::BottomLeft),
+                (180.0.deg(), 90.0.deg(), AnchorPoint::TopLeft),
+                (270.0.deg(), 90.0.deg(), AnchorPoint::TopRight),
+                (0.0.deg(), -90.0.deg(), AnchorPoint::TopRight),
+                (90.0.deg(), -90.0.deg(), AnchorPoint::BottomRight),
+                (180.0.deg(), -90.0.deg(), AnchorPoint::BottomLeft),
+                (270.0.deg(), -90.0.deg(), AnchorPoint::TopLeft),
+            ] {
+                let ellipse = Ellipse::new(Point::new(1, 1), size).into_styled(style);
+
+                // Calculate a clip rectangle for the tested quadrant.
+                let bounding_box = ellipse.bounding_box();
+                let clip_rect = bounding_box
+                    .resized((bounding_box.size + Size::new_equal(1)) / 2, anchor_point);
+
+                // Draw expected display by clipping the ellipse to the quadrant.
+                let mut expected = MockDisplay::new();
+                ellipse.draw(&mut expected.clipped(&clip_rect)).unwrap();
+
+                // Draw the arc.
+                let mut display = MockDisplay::new();
+                EllipticalArc::new(Point::new(1, 1), size, angle_start, angle_sweep)
+                    .into_styled(style)
+                    .draw(&mut display)
+                    .unwrap();
+
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(
+                        f,
+                        "size: {:?}, angle_start: {}, angle_sweep: {}",
+                        size,
+                        angle_start.to_degrees(),
+                        angle_sweep.to_degrees()
+                    )
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let arc = EllipticalArc::new(Point::new(5, 5), Size::new(20, 10), 0.0.deg(), 360.0.deg());
+
+        for stroke_width in [1, 3, 4] {
+            let styled =
+                arc.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
+    }
+
+    #[test]
+    fn fill_is_ignored() {
+        let arc = EllipticalArc::new(Point::new(5, 5), Size::new(20, 10), 0.0.deg(), 180.0.deg());
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(2)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        assert!(arc.into_styled(style).pixels().eq(arc
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 2))
+            .pixels()));
+    }
+
+    fn capped_elliptical_arc(line_cap: LineCap) -> MockDisplay<BinaryColor> {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .line_cap(line_cap)
+            .build();
+
+        let mut display = MockDisplay::new();
+        EllipticalArc::new(Point::new(2, 2), Size::new(15, 9), 180.0.deg(), -90.0.deg())
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display
+    }
+
+    #[test]
+    fn round_cap() {
+        capped_elliptical_arc(LineCap::Round).assert_pattern(&[
+            "           ",
+            "           ",
+            "           ",
+            "           ",
+            "           ",
+            "  #        ",
+            " ###       ",
+            " ###       ",
+            "  ####     ",
+            "  ######## ",
+            "    #######",
+            "      #### ",
+        ]);
+    }
+
+    #[test]
+    fn square_cap() {
+        capped_elliptical_arc(LineCap::Square).assert_pattern(&[
+            "           ",
+            "           ",
+            "           ",
+            "           ",
+            "           ",
+            " ###       ",
+            " ###       ",
+            " ###       ",
+            "  ####     ",
+            "  #########",
+            "    #######",
+            "      #####",
+        ]);
+    }
+
+    #[test]
+    fn caps_match_arc() {
+        for line_cap in [LineCap::Round, LineCap::Square] {
+            for angle_start in [0.0, 30.0, 135.0, 200.0, 315.0] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(4)
+                    .line_cap(line_cap)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Arc::new(Point::new(5, 5), 21, angle_start.deg(), 100.0.deg())
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                EllipticalArc::new(
+                    Point::new(5, 5),
+                    Size::new(21, 21),
+                    angle_start.deg(),
+                    100.0.deg(),
+                )
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{line_cap:?}, {angle_start}°")
+                });
+            }
+        }
+    }
+}
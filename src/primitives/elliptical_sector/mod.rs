@@ -0,0 +1,286 @@
+//! The elliptical sector primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Size},
+    primitives::{
+        common::PlaneSector, ellipse, ContainsPoint, Ellipse, OffsetOutline, PointsIter, Primitive,
+        Rectangle,
+    },
+    transform::Transform,
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Elliptical sector primitive
+///
+/// An elliptical sector is a pie shaped part of an [`Ellipse`]. The start and sweep angles are
+/// measured from the center of the ellipse, which means that an elliptical sector with equal
+/// width and height is drawn like a [`Sector`] with the same angles.
+///
+/// # Examples
+///
+/// ## Create some elliptical sectors with different styles
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{EllipticalSector, PrimitiveStyle, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+///
+/// // Elliptical sector with 1 pixel wide white stroke with top-left point at (10, 20) with a size
+/// // of (40, 20)
+/// EllipticalSector::new(Point::new(10, 20), Size::new(40, 20), 0.0.deg(), 90.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Elliptical sector with styled stroke and fill with top-left point at (10, 20) with a size
+/// // of (40, 20)
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// EllipticalSector::new(Point::new(10, 20), Size::new(40, 20), 180.0.deg(), -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// // Elliptical sector with blue fill and no stroke with a translation applied
+/// EllipticalSector::new(Point::new(10, 20), Size::new(40, 20), 0.0.deg(), 90.0.deg())
+///     .translate(Point::new(5, 15))
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Sector`]: crate::primitives::Sector
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct EllipticalSector {
+    /// Top-left point of the bounding-box of the ellipse supporting the sector
+    pub top_left: Point,
+
+    /// Size of the ellipse supporting the sector
+    pub size: Size,
+
+    /// Angle at which the sector starts
+    pub angle_start: Angle,
+
+    /// Angle defining the sector sweep starting at angle_start
+    pub angle_sweep: Angle,
+}
+
+impl EllipticalSector {
+    /// Create a new elliptical sector delimited with a top-left point with a specific size and
+    /// start and sweep angles
+    pub const fn new(top_left: Point, size: Size, angle_start: Angle, angle_sweep: Angle) -> Self {
+        EllipticalSector {
+            top_left,
+            size,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Create a new elliptical sector centered around a given point with a specific size and
+    /// start and sweep angles
+    pub const fn with_center(
+        center: Point,
+        size: Size,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        Self::from_ellipse(Ellipse::with_center(center, size), angle_start, angle_sweep)
+    }
+
+    /// Creates an elliptical sector based on an ellipse.
+    ///
+    /// The resulting sector will match the `top_left` and `size` of the base ellipse.
+    pub const fn from_ellipse(ellipse: Ellipse, angle_start: Angle, angle_sweep: Angle) -> Self {
+        Self {
+            top_left: ellipse.top_left,
+            size: ellipse.size,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Returns an ellipse with the same `top_left` and `size` as this sector.
+    pub const fn to_ellipse(&self) -> Ellipse {
+        Ellipse::new(self.top_left, self.size)
+    }
+
+    /// Return the center point of the sector.
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns the center point of the sector scaled by a factor of 2.
+    fn center_2x(&self) -> Point {
+        ellipse::center_2x(self.top_left, self.size)
+    }
+}
+
+impl OffsetOutline for EllipticalSector {
+    fn offset(&self, offset: i32) -> Self {
+        let ellipse = self.to_ellipse().offset(offset);
+
+        Self::from_ellipse(ellipse, self.angle_start, self.angle_sweep)
+    }
+}
+
+impl Primitive for EllipticalSector {}
+
+impl PointsIter for EllipticalSector {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for EllipticalSector {
+    fn contains(&self, point: Point) -> bool {
+        if self.to_ellipse().contains(point) {
+            let delta = point * 2 - self.center_2x();
+            PlaneSector::new(self.angle_start, self.angle_sweep).contains(delta)
+        } else {
+            false
+        }
+    }
+}
+
+impl Dimensions for EllipticalSector {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, self.size)
+    }
+}
+
+impl Transform for EllipticalSector {
+    /// Translate the elliptical sector from its current position to a new position by (x, y)
+    /// pixels, returning a new `EllipticalSector`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::EllipticalSector;
+    /// # use embedded_graphics::prelude::*;
+    /// let sector =
+    ///     EllipticalSector::new(Point::new(5, 10), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+    /// let moved = sector.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the elliptical sector from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::EllipticalSector;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut sector =
+    ///     EllipticalSector::new(Point::new(5, 10), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+    /// sector.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(sector.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::Sector};
+
+    #[test]
+    fn dimensions() {
+        let sector =
+            EllipticalSector::new(Point::new(5, 15), Size::new(20, 10), 0.0.deg(), 90.0.deg());
+
+        assert_eq!(
+            sector.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(20, 10))
+        );
+    }
+
+    #[test]
+    fn center_is_correct() {
+        let sector =
+            EllipticalSector::new(Point::new(10, 10), Size::new(5, 6), 0.0.deg(), 90.0.deg());
+        assert_eq!(sector.center(), Point::new(12, 12));
+
+        let sector = EllipticalSector::with_center(
+            Point::new(10, 10),
+            Size::new(5, 6),
+            0.0.deg(),
+            90.0.deg(),
+        );
+        assert_eq!(sector.center(), Point::new(10, 10));
+    }
+
+    #[test]
+    fn contains() {
+        let sector =
+            EllipticalSector::new(Point::zero(), Size::new(20, 11), 30.0.deg(), 200.0.deg());
+
+        let contained_points = Rectangle::new(Point::new(-10, -10), Size::new(40, 40))
+            .points()
+            .filter(|p| sector.contains(*p));
+
+        assert!(contained_points.eq(sector.points()));
+    }
+
+    #[test]
+    fn circular_sector_points() {
+        for diameter in [5, 6, 11, 20] {
+            for (angle_start, angle_sweep) in [
+                (0.0.deg(), 90.0.deg()),
+                (30.0.deg(), 200.0.deg()),
+                (-45.0.deg(), -120.0.deg()),
+            ] {
+                let sector = Sector::new(Point::new(1, 2), diameter, angle_start, angle_sweep);
+                let elliptical_sector = EllipticalSector::new(
+                    Point::new(1, 2),
+                    Size::new_equal(diameter),
+                    angle_start,
+                    angle_sweep,
+                );
+
+                assert!(sector.points().eq(elliptical_sector.points()));
+            }
+        }
+    }
+
+    #[test]
+    fn offset() {
+        let center = Point::new(5, 7);
+        let sector = EllipticalSector::with_center(center, Size::new(5, 3), 0.0.deg(), 90.0.deg());
+
+        assert_eq!(sector.offset(0), sector);
+
+        assert_eq!(
+            sector.offset(1),
+            EllipticalSector::with_center(center, Size::new(7, 5), 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            sector.offset(-2),
+            EllipticalSector::with_center(center, Size::new(1, 0), 0.0.deg(), 90.0.deg())
+        );
+    }
+}
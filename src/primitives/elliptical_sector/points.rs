@@ -0,0 +1,73 @@
+use crate::{
+    geometry::{Dimensions, Point},
+    primitives::{
+        common::{DistanceIterator, PlaneSector},
+        ellipse::EllipseContains,
+        elliptical_sector::EllipticalSector,
+    },
+};
+
+/// Iterator over all points inside the elliptical sector.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+
+    ellipse: EllipseContains,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(sector: &EllipticalSector) -> Self {
+        let plane_sector = PlaneSector::new(sector.angle_start, sector.angle_sweep);
+
+        Self {
+            // PERF: The distance iterator should use the smaller sector bounding box
+            iter: DistanceIterator::new(sector.center_2x(), &sector.bounding_box()),
+            plane_sector,
+            ellipse: EllipseContains::new(sector.size),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ellipse = self.ellipse;
+        let plane_sector = self.plane_sector;
+
+        self.iter
+            .find(|(_, delta, _)| ellipse.contains(*delta) && plane_sector.contains(*delta))
+            .map(|(point, ..)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Size},
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Pixel,
+    };
+
+    #[test]
+    fn points_equals_filled() {
+        let sector = EllipticalSector::with_center(
+            Point::new(10, 10),
+            Size::new(9, 5),
+            0.0.deg(),
+            90.0.deg(),
+        );
+
+        let styled_points = sector
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .pixels()
+            .map(|Pixel(p, _)| p);
+
+        assert!(sector.points().eq(styled_points));
+    }
+}
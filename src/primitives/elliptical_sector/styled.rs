@@ -0,0 +1,248 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{DistanceIterator, PointType, RadialStroke},
+        ellipse::EllipseContains,
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        EllipticalSector, PrimitiveStyle, Rectangle,
+    },
+    Pixel,
+};
+use az::SaturatingAs;
+
+/// Pixel iterator for each pixel in the elliptical sector border
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<C> {
+    iter: DistanceIterator,
+
+    radial_stroke: RadialStroke,
+
+    outer_ellipse: EllipseContains,
+    inner_ellipse: EllipseContains,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(primitive: &EllipticalSector, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = style.stroke_area(primitive);
+        let fill_area = style.fill_area(primitive);
+
+        let iter = if !style.is_transparent() {
+            // PERF: The distance iterator should use the smaller sector bounding box
+            DistanceIterator::new(stroke_area.center_2x(), &stroke_area.bounding_box())
+        } else {
+            DistanceIterator::empty()
+        };
+
+        let outer_ellipse = EllipseContains::new(stroke_area.size);
+        let inner_ellipse = EllipseContains::new(fill_area.size);
+
+        let radial_stroke =
+            RadialStroke::new(stroke_area.angle_start, stroke_area.angle_sweep, style);
+
+        Self {
+            iter,
+            radial_stroke,
+            outer_ellipse,
+            inner_ellipse,
+            stroke_color: style.stroke_color,
+            fill_color: style.fill_color,
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_ellipse = self.outer_ellipse;
+
+        loop {
+            let (point, delta, _) = self
+                .iter
+                .find(|(_, delta, _)| outer_ellipse.contains(*delta))?;
+
+            // Check if point is inside the radial stroke lines or the fill.
+            let mut point_type = match self.radial_stroke.point_type(delta) {
+                Some(point_type) => point_type,
+                None => continue,
+            };
+
+            // Add the outer elliptical stroke.
+            if point_type == PointType::Fill && !self.inner_ellipse.contains(delta) {
+                point_type = PointType::Stroke;
+            }
+
+            let color = match point_type {
+                PointType::Stroke => self.stroke_color,
+                PointType::Fill => self.fill_color,
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for EllipticalSector {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for EllipticalSector {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        target.draw_iter(StyledPixelsIterator::new(self, style))
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for EllipticalSector {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset = style.outside_stroke_width().saturating_as();
+
+        self.bounding_box().offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{Primitive, PrimitiveStyleBuilder, Sector, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn tiny_elliptical_sector() {
+        let mut display = MockDisplay::new();
+
+        EllipticalSector::new(Point::zero(), Size::new(15, 9), 210.0.deg(), 120.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    #######    ", //
+            "  ##       ##  ", //
+            "   ##     ##   ", //
+            "     ## ##     ", //
+            "      ###      ", //
+        ]);
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        EllipticalSector::new(Point::new(1, 1), Size::new(21, 11), 0.0.deg(), -90.0.deg())
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb888::RED)
+                    .stroke_width(3)
+                    .fill_color(Rgb888::GREEN)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "          RRRRRR       ",
+            "          RRRRRRRRR    ",
+            "          RRRRRRRRRRR  ",
+            "          RRRGGGRRRRRR ",
+            "          RRRGGGGGRRRR ",
+            "          RRRRRRRRRRRRR",
+            "          RRRRRRRRRRRRR",
+            "          RRRRRRRRRRRRR",
+        ]);
+    }
+
+    #[test]
+    fn matches_sector() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for (angle_start, angle_sweep) in [
+                (0.0.deg(), 90.0.deg()),
+                (30.0.deg(), 20.0.deg()),
+                (-45.0.deg(), -320.0.deg()),
+            ] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb888::RED)
+                    .stroke_width(3)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(Rgb888::GREEN)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Sector::new(Point::new(6, 6), 21, angle_start, angle_sweep)
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                EllipticalSector::new(
+                    Point::new(6, 6),
+                    Size::new(21, 21),
+                    angle_start,
+                    angle_sweep,
+                )
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+                display.assert_eq(&expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let sector =
+            EllipticalSector::new(Point::new(5, 5), Size::new(20, 10), 0.0.deg(), 360.0.deg());
+
+        for stroke_width in [1, 3, 4] {
+            let styled =
+                sector.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
+
+        let transparent = sector.into_styled(
+            PrimitiveStyleBuilder::<BinaryColor>::new()
+                .stroke_width(3)
+                .build(),
+        );
+        assert_eq!(
+            transparent.bounding_box(),
+            sector
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+                .bounding_box()
+        );
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
         line::thick_points::{ParallelLineType, ParallelsIterator},
         PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{Affine2, AffineTransform, Transform},
 };
 use az::SaturatingAs;
 
@@ -223,6 +223,29 @@ impl Transform for Line {
 }
 
 /// Pixel iterator for each pixel in the line
+impl AffineTransform for Line {
+    type Output = Self;
+
+    /// Transforms the start and end points of the line.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Line;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let line = Line::new(Point::new(5, 10), Point::new(15, 10));
+    /// let rotated = line.transform(&Affine2::rotation(90.0.deg()).around(Point::new(5, 10)));
+    ///
+    /// assert_eq!(rotated, Line::new(Point::new(5, 10), Point::new(5, 20)));
+    /// ```
+    fn transform(&self, affine: &Affine2) -> Self {
+        Self {
+            start: affine.transform_point(self.start),
+            end: affine.transform_point(self.end),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,25 +3,27 @@ use crate::{
     geometry::{Point, PointExt, Size},
     pixelcolor::PixelColor,
     primitives::{
+        common::{LineJoin, Scanline, ThickSegment, ThickStroke},
         line::{
             dotted_bresenham::DottedLinePoints,
             thick_points::{ThickPoints, HORIZONTAL_LINE},
             Line, Points, StrokeOffset,
         },
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
-        Circle, PrimitiveStyle, Rectangle, StrokeStyle,
+        Circle, LineCap, PrimitiveStyle, Rectangle, StrokeStyle,
     },
     transform::Transform,
     Pixel,
 };
 use az::SaturatingAs;
+use core::ops::Range;
 
 /// Styled line iterator.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(::defmt::Format))]
 pub struct StyledPixelsIterator<C> {
     stroke_color: Option<C>,
-    line_iter: ThickPoints,
+    line_iter: LinePoints,
 }
 
 impl<C: PixelColor> StyledPixelsIterator<C> {
@@ -30,13 +32,74 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let stroke_color = style.effective_stroke_color();
         let stroke_width = style.stroke_width.saturating_as();
 
+        let line_iter = match capped_segment(primitive, style) {
+            Some(segment) => LinePoints::Capped {
+                segment,
+                rows: segment.edges_bounding_box().rows(),
+                scanline: Scanline::new_empty(0),
+            },
+            None => LinePoints::Butt(ThickPoints::new(primitive, stroke_width)),
+        };
+
         Self {
             stroke_color,
-            line_iter: ThickPoints::new(primitive, stroke_width),
+            line_iter,
         }
     }
 }
 
+/// Points of a thick line.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+enum LinePoints {
+    /// Line with butt caps.
+    Butt(ThickPoints),
+
+    /// Line with square or round caps.
+    Capped {
+        segment: ThickSegment,
+        rows: Range<i32>,
+        scanline: Scanline,
+    },
+}
+
+impl Iterator for LinePoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Butt(points) => points.next(),
+            Self::Capped {
+                segment,
+                rows,
+                scanline,
+            } => loop {
+                if let Some(point) = scanline.next() {
+                    return Some(point);
+                }
+
+                *scanline = segment.intersection(rows.next()?);
+            },
+        }
+    }
+}
+
+/// Returns the thick segment for a line with square or round caps.
+///
+/// Returns `None` if the line should be drawn with butt caps.
+fn capped_segment<C: PixelColor>(line: &Line, style: &PrimitiveStyle<C>) -> Option<ThickSegment> {
+    if style.stroke_width <= 1 || style.line_cap == LineCap::Butt {
+        return None;
+    }
+
+    let stroke = ThickStroke::from_style(style, StrokeOffset::None);
+
+    Some(ThickSegment::new(
+        LineJoin::start(line.start, line.end, stroke),
+        LineJoin::end(line.start, line.end, stroke),
+    ))
+}
+
 impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
     type Item = Pixel<C>;
 
@@ -211,6 +274,12 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Line {
             }
         } else if style.stroke_style == StrokeStyle::Dotted && style.stroke_width == 1 {
             target.draw_iter(StyledPixelsIterator::new(self, style).step_by(2))
+        } else if let Some(segment) = capped_segment(self, style) {
+            for y in segment.edges_bounding_box().rows() {
+                target.fill_solid(&segment.intersection(y).to_rectangle(), stroke_color)?;
+            }
+
+            Ok(())
         } else {
             target.draw_iter(StyledPixelsIterator::new(self, style))
         }
@@ -221,6 +290,8 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Line {
     fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
         if style.stroke_style == StrokeStyle::Dotted {
             Rectangle::with_corners(self.start, self.end).offset((style.stroke_width / 2) as i32)
+        } else if let Some(segment) = capped_segment(self, style) {
+            segment.edges_bounding_box()
         } else {
             let (l, r) = self.extents(style.stroke_width, StrokeOffset::None);
 
@@ -244,6 +315,7 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Line {
 mod tests {
     use super::*;
     use crate::{
+        draw_target::DrawTarget,
         geometry::{Dimensions, Point},
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Gray2, Rgb888, RgbColor},
@@ -755,4 +827,140 @@ mod tests {
             assert_eq!(lines_display, rect_display);
         }
     }
+
+    #[test]
+    fn square_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_cap(LineCap::Square)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(3, 2), Point::new(9, 2))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            " ###########",
+            " ###########",
+            " ###########",
+            " ###########",
+            " ###########",
+        ]);
+    }
+
+    #[test]
+    fn round_cap() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_cap(LineCap::Round)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(3, 2), Point::new(9, 2))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "  #########  ",
+            " ########### ",
+            " ########### ",
+            " ########### ",
+            "  #########  ",
+        ]);
+    }
+
+    #[test]
+    fn round_cap_on_zero_length_line() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_cap(LineCap::Round)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Line::new(Point::new(4, 4), Point::new(4, 4))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut expected = MockDisplay::new();
+        Circle::with_center(Point::new(4, 4), 5)
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn square_cap_on_zero_length_line() {
+        for stroke_width in 2..10 {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(stroke_width)
+                .line_cap(LineCap::Square)
+                .build();
+
+            let styled = Line::new(Point::new(5, 5), Point::new(5, 5)).into_styled(style);
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+
+            // Squares with an even size are offset to the top left, like the stroke of a
+            // horizontal line.
+            let mut expected = MockDisplay::new();
+            let half_width = (stroke_width / 2) as i32;
+            Rectangle::new(
+                Point::new(5, 5) - Point::new_equal(half_width),
+                Size::new_equal(stroke_width),
+            )
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut expected)
+            .unwrap();
+
+            display.assert_eq_with_message(&expected, |f| write!(f, "{stroke_width} px"));
+        }
+    }
+
+    #[test]
+    fn capped_lines_match_bounding_box_and_pixels() {
+        let lines = [
+            Line::new(Point::new(10, 20), Point::new(10, 50)),
+            Line::new(Point::new(20, 20), Point::new(50, 20)),
+            Line::new(Point::new(20, 20), Point::new(45, 45)),
+            Line::new(Point::new(40, 40), Point::new(13, 14)),
+            Line::new(Point::new(30, 30), Point::new(12, 53)),
+        ];
+
+        for line in lines.iter() {
+            for cap in [LineCap::Square, LineCap::Round] {
+                for stroke_width in 2..10 {
+                    let style = PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .line_cap(cap)
+                        .build();
+                    let styled = line.into_styled(style);
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{line:?}, {cap:?}, {stroke_width} px"
+                    );
+
+                    let mut pixels_display = MockDisplay::new();
+                    pixels_display.draw_iter(styled.pixels()).unwrap();
+                    display.assert_eq(&pixels_display);
+                }
+            }
+        }
+    }
 }
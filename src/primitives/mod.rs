@@ -3,13 +3,25 @@
 pub mod arc;
 pub mod circle;
 mod common;
+pub mod cubic_bezier;
 pub mod ellipse;
+pub mod elliptical_arc;
+pub mod elliptical_sector;
 pub mod line;
+pub mod path;
+pub mod polygon;
 pub mod polyline;
 mod primitive_style;
+pub mod quadratic_bezier;
 pub mod rectangle;
+pub mod regular_polygon;
+pub mod ring_sector;
+pub mod rotated_ellipse;
+pub mod rotated_rectangle;
+pub mod rotated_rounded_rectangle;
 pub mod rounded_rectangle;
 pub mod sector;
+pub mod star;
 mod styled;
 pub mod triangle;
 
@@ -18,12 +30,26 @@ pub use self::rectangle::Rectangle;
 pub use self::{
     arc::Arc,
     circle::Circle,
+    cubic_bezier::CubicBezier,
     ellipse::Ellipse,
+    elliptical_arc::EllipticalArc,
+    elliptical_sector::EllipticalSector,
     line::Line,
+    path::{FillRule, Path, PathCommand},
+    polygon::Polygon,
     polyline::Polyline,
-    primitive_style::{PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle},
+    primitive_style::{
+        LineCap, LineJoin, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment, StrokeStyle,
+    },
+    quadratic_bezier::QuadraticBezier,
+    regular_polygon::RegularPolygon,
+    ring_sector::RingSector,
+    rotated_ellipse::RotatedEllipse,
+    rotated_rectangle::RotatedRectangle,
+    rotated_rounded_rectangle::RotatedRoundedRectangle,
     rounded_rectangle::{CornerRadii, CornerRadiiBuilder, RoundedRectangle},
     sector::Sector,
+    star::Star,
     triangle::Triangle,
 };
 use crate::geometry::{Dimensions, Point};
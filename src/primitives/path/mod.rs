@@ -0,0 +1,511 @@
+//! The path primitive
+
+use crate::{
+    geometry::{Dimensions, Point, Real, Size},
+    primitives::{Primitive, Rectangle},
+    transform::{Affine2, Transform},
+};
+
+mod styled;
+mod subpaths;
+
+pub use crate::primitives::common::scanline_renderer::{RowEvent, DEFAULT_ROW_EVENTS};
+
+/// Path command.
+///
+/// A [`Path`] is built from a slice of commands, which are similar to the commands used in SVG
+/// paths. All coordinates are absolute.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum PathCommand {
+    /// Starts a new subpath at the given point.
+    MoveTo(Point),
+
+    /// Draws a straight line to the given point.
+    LineTo(Point),
+
+    /// Draws a quadratic Bézier curve.
+    QuadTo {
+        /// Control point
+        control: Point,
+        /// End point
+        end: Point,
+    },
+
+    /// Draws a cubic Bézier curve.
+    CubicTo {
+        /// First control point
+        control1: Point,
+        /// Second control point
+        control2: Point,
+        /// End point
+        end: Point,
+    },
+
+    /// Draws an elliptical arc.
+    ///
+    /// The arc is part of an axis aligned ellipse with the given radii, which passes through the
+    /// current point and `end`. If the radii are too small to connect both points, they are scaled
+    /// up. Out of the four possible arcs, `large_arc` selects the arc that spans more than 180°
+    /// and `sweep` selects the arc that is drawn in clockwise direction.
+    ArcTo {
+        /// Radii of the ellipse
+        radii: Size,
+        /// Use the larger arc
+        large_arc: bool,
+        /// Draw the arc in clockwise direction
+        sweep: bool,
+        /// End point
+        end: Point,
+    },
+
+    /// Closes the current subpath by drawing a straight line to its start point.
+    Close,
+}
+
+/// Fill rule.
+///
+/// The fill rule determines which areas of a [`Path`] with overlapping or nested subpaths are
+/// filled.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum FillRule {
+    /// Non-zero fill rule.
+    ///
+    /// A point is inside the path if the outline winds around it at least once, taking the
+    /// direction into account.
+    #[default]
+    NonZero,
+
+    /// Even-odd fill rule.
+    ///
+    /// A point is inside the path if a ray from the point crosses the outline an odd number of
+    /// times.
+    EvenOdd,
+}
+
+/// Path primitive
+///
+/// A path is built from a slice of [`PathCommand`]s, which can contain straight lines, Bézier
+/// curves and elliptical arcs. A path can contain multiple subpaths, which are started by
+/// [`MoveTo`] commands. Subpaths that end with a [`Close`] command are stroked as closed outlines.
+///
+/// Filled paths use the [`FillRule`] of the path to determine the fill area. All subpaths are
+/// implicitly closed when they are filled. The stroke is drawn on top of the fill. If the stroke
+/// width is greater than one, closed subpaths respect the [`StrokeAlignment`] setting of the
+/// style.
+///
+/// Curves and arcs are flattened into line segments while the path is drawn. No memory
+/// allocation is required. The path is drawn one row at a time and the changes of the pixel color
+/// in each row are collected in a small buffer on the stack, which can be replaced by a caller
+/// provided buffer with [`Styled::draw_with_buffer`].
+///
+/// # Examples
+///
+/// ## Draw a rounded arrow
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Path, PathCommand, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// const ARROW: [PathCommand; 6] = [
+///     PathCommand::MoveTo(Point::new(5, 20)),
+///     PathCommand::LineTo(Point::new(30, 5)),
+///     PathCommand::LineTo(Point::new(55, 20)),
+///     PathCommand::LineTo(Point::new(40, 20)),
+///     PathCommand::QuadTo { control: Point::new(30, 50), end: Point::new(20, 20) },
+///     PathCommand::Close,
+/// ];
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::YELLOW)
+///     .build();
+///
+/// Path::new(&ARROW).into_styled(style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// ## Fill a shape with a hole
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{FillRule, Path, PathCommand, PrimitiveStyle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let radii = Size::new(20, 20);
+/// let commands = [
+///     // Outer circle
+///     PathCommand::MoveTo(Point::new(10, 30)),
+///     PathCommand::ArcTo { radii, large_arc: false, sweep: true, end: Point::new(50, 30) },
+///     PathCommand::ArcTo { radii, large_arc: false, sweep: true, end: Point::new(10, 30) },
+///     // Inner square
+///     PathCommand::MoveTo(Point::new(20, 20)),
+///     PathCommand::LineTo(Point::new(40, 20)),
+///     PathCommand::LineTo(Point::new(40, 40)),
+///     PathCommand::LineTo(Point::new(20, 40)),
+///     PathCommand::Close,
+/// ];
+///
+/// Path::new(&commands)
+///     .with_fill_rule(FillRule::EvenOdd)
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`MoveTo`]: PathCommand::MoveTo
+/// [`Close`]: PathCommand::Close
+/// [`StrokeAlignment`]: crate::primitives::StrokeAlignment
+/// [`Styled::draw_with_buffer`]: crate::primitives::Styled::draw_with_buffer
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Path<'a> {
+    /// An offset to apply to the path as a whole
+    pub translate: Point,
+
+    /// Path commands
+    pub commands: &'a [PathCommand],
+
+    /// Fill rule
+    pub fill_rule: FillRule,
+}
+
+impl<'a> Path<'a> {
+    /// Creates a new path from a list of commands.
+    ///
+    /// The path uses the [`FillRule::NonZero`] fill rule. Commands before the first
+    /// [`PathCommand::MoveTo`] start at the origin.
+    pub const fn new(commands: &'a [PathCommand]) -> Self {
+        Self {
+            translate: Point::zero(),
+            commands,
+            fill_rule: FillRule::NonZero,
+        }
+    }
+
+    /// Sets the fill rule.
+    pub const fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+
+        self
+    }
+
+    /// Applies an affine transformation to the path.
+    ///
+    /// A path doesn't own its commands, which is why the transformed commands are written into
+    /// `buffer`. The returned path borrows the first `self.commands.len()` commands of the buffer
+    /// and uses the same fill rule. The [`translate`] offset of the path is applied before the
+    /// transformation.
+    ///
+    /// Points and control points are transformed exactly, up to rounding. The ellipse of an
+    /// [`ArcTo`] command is always axis aligned, which is why the radii are replaced by the half
+    /// width and half height of the transformed ellipse and the sweep direction is reversed by
+    /// mirroring transformations. This is exact for transformations that keep the axes aligned,
+    /// like scaling, mirroring and rotations by multiples of 90°, and for circular arcs that are
+    /// rotated or uniformly scaled. Other transformations of elliptical arcs are approximated.
+    ///
+    /// Commands before the first [`MoveTo`] start at the origin, which isn't transformed. Paths
+    /// that are transformed should therefore start with a [`MoveTo`] command.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than the list of commands.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Path, PathCommand};
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let commands = [
+    ///     PathCommand::MoveTo(Point::new(0, 0)),
+    ///     PathCommand::QuadTo { control: Point::new(5, 10), end: Point::new(10, 0) },
+    ///     PathCommand::Close,
+    /// ];
+    ///
+    /// let mut buffer = [PathCommand::Close; 3];
+    /// let scaled = Path::new(&commands).transform_into(&Affine2::scale(2.0, 1.0), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     scaled.commands,
+    ///     &[
+    ///         PathCommand::MoveTo(Point::new(0, 0)),
+    ///         PathCommand::QuadTo { control: Point::new(10, 10), end: Point::new(20, 0) },
+    ///         PathCommand::Close,
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// [`translate`]: Path::translate
+    /// [`ArcTo`]: PathCommand::ArcTo
+    /// [`MoveTo`]: PathCommand::MoveTo
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [PathCommand]) -> Path<'b> {
+        let buffer = &mut buffer[0..self.commands.len()];
+
+        let point = |point: Point| affine.transform_point(point + self.translate);
+
+        let [[a, b], [c, d]] = affine.matrix();
+        let mirrored = a * d - b * c < Real::from(0);
+
+        for (output, command) in buffer.iter_mut().zip(self.commands.iter()) {
+            *output = match *command {
+                PathCommand::MoveTo(p) => PathCommand::MoveTo(point(p)),
+                PathCommand::LineTo(p) => PathCommand::LineTo(point(p)),
+                PathCommand::QuadTo { control, end } => PathCommand::QuadTo {
+                    control: point(control),
+                    end: point(end),
+                },
+                PathCommand::CubicTo {
+                    control1,
+                    control2,
+                    end,
+                } => PathCommand::CubicTo {
+                    control1: point(control1),
+                    control2: point(control2),
+                    end: point(end),
+                },
+                PathCommand::ArcTo {
+                    radii,
+                    large_arc,
+                    sweep,
+                    end,
+                } => {
+                    let rx = Real::from(radii.width);
+                    let ry = Real::from(radii.height);
+
+                    PathCommand::ArcTo {
+                        radii: Size::new(
+                            u32::from((a * rx).hypot(b * ry).round()),
+                            u32::from((c * rx).hypot(d * ry).round()),
+                        ),
+                        large_arc,
+                        sweep: sweep != mirrored,
+                        end: point(end),
+                    }
+                }
+                PathCommand::Close => PathCommand::Close,
+            };
+        }
+
+        Path {
+            translate: Point::zero(),
+            commands: buffer,
+            fill_rule: self.fill_rule,
+        }
+    }
+}
+
+impl Primitive for Path<'_> {}
+
+impl Dimensions for Path<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        subpaths::vertices_bounding_box(self.commands)
+            .map(|(min, max)| Rectangle::with_corners(min, max).translate(self.translate))
+            .unwrap_or_else(|| Rectangle::new(self.translate, Size::zero()))
+    }
+}
+
+impl Transform for Path<'_> {
+    /// Translate the path from its current position to a new position by (x, y) pixels, returning
+    /// a new `Path`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Path, PathCommand};
+    /// # use embedded_graphics::prelude::*;
+    /// let commands = [
+    ///     PathCommand::MoveTo(Point::new(5, 10)),
+    ///     PathCommand::LineTo(Point::new(15, 5)),
+    ///     PathCommand::LineTo(Point::new(10, 20)),
+    /// ];
+    ///
+    /// let path = Path::new(&commands);
+    /// let moved = path.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(path.bounding_box().top_left, Point::new(5, 5));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 17));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the path from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Path, PathCommand};
+    /// # use embedded_graphics::prelude::*;
+    /// let commands = [
+    ///     PathCommand::MoveTo(Point::new(5, 10)),
+    ///     PathCommand::LineTo(Point::new(15, 5)),
+    ///     PathCommand::LineTo(Point::new(10, 20)),
+    /// ];
+    ///
+    /// let mut path = Path::new(&commands);
+    /// path.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(path.bounding_box().top_left, Point::new(15, 17));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn bounding_box() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(10, 10)),
+            PathCommand::LineTo(Point::new(20, 10)),
+            PathCommand::CubicTo {
+                control1: Point::new(30, 10),
+                control2: Point::new(30, 30),
+                end: Point::new(20, 30),
+            },
+            PathCommand::Close,
+            PathCommand::MoveTo(Point::new(0, 40)),
+            PathCommand::LineTo(Point::new(5, 42)),
+        ];
+
+        assert_eq!(
+            Path::new(&commands).bounding_box(),
+            Rectangle::with_corners(Point::new(0, 10), Point::new(27, 42))
+        );
+
+        assert_eq!(
+            Path::new(&commands)
+                .translate(Point::new(-5, 3))
+                .bounding_box(),
+            Rectangle::with_corners(Point::new(-5, 13), Point::new(22, 45))
+        );
+    }
+
+    #[test]
+    fn empty_bounding_box() {
+        assert_eq!(
+            Path::new(&[]).bounding_box(),
+            Rectangle::new(Point::zero(), Size::zero())
+        );
+
+        assert_eq!(
+            Path::new(&[PathCommand::MoveTo(Point::new(3, 4))]).bounding_box(),
+            Rectangle::new(Point::zero(), Size::zero())
+        );
+    }
+
+    #[test]
+    fn with_fill_rule() {
+        assert_eq!(Path::new(&[]).fill_rule, FillRule::NonZero);
+        assert_eq!(
+            Path::new(&[]).with_fill_rule(FillRule::EvenOdd).fill_rule,
+            FillRule::EvenOdd
+        );
+    }
+
+    #[test]
+    fn transform_into() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::CubicTo {
+                control1: Point::new(15, 0),
+                control2: Point::new(15, 10),
+                end: Point::new(10, 10),
+            },
+            PathCommand::ArcTo {
+                radii: Size::new(5, 3),
+                large_arc: false,
+                sweep: true,
+                end: Point::new(0, 10),
+            },
+            PathCommand::Close,
+        ];
+        let path = Path::new(&commands)
+            .translate(Point::new(5, 5))
+            .with_fill_rule(FillRule::EvenOdd);
+
+        let mut buffer = [PathCommand::Close; 6];
+        let rotated = path.transform_into(&Affine2::rotation(90.0.deg()), &mut buffer);
+
+        assert_eq!(
+            rotated,
+            Path::new(&[
+                PathCommand::MoveTo(Point::new(-5, 5)),
+                PathCommand::LineTo(Point::new(-5, 15)),
+                PathCommand::CubicTo {
+                    control1: Point::new(-5, 20),
+                    control2: Point::new(-15, 20),
+                    end: Point::new(-15, 15),
+                },
+                PathCommand::ArcTo {
+                    radii: Size::new(3, 5),
+                    large_arc: false,
+                    sweep: true,
+                    end: Point::new(-15, 5),
+                },
+                PathCommand::Close,
+            ])
+            .with_fill_rule(FillRule::EvenOdd)
+        );
+    }
+
+    #[test]
+    fn transform_into_mirrored_arc() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::ArcTo {
+                radii: Size::new(10, 10),
+                large_arc: true,
+                sweep: true,
+                end: Point::new(10, 0),
+            },
+        ];
+
+        let mut buffer = [PathCommand::Close; 2];
+        let mirrored = Path::new(&commands).transform_into(
+            &Affine2::flip_horizontal().then(Affine2::scale(2.0, 2.0)),
+            &mut buffer,
+        );
+
+        assert_eq!(
+            mirrored.commands[1],
+            PathCommand::ArcTo {
+                radii: Size::new(20, 20),
+                large_arc: true,
+                sweep: false,
+                end: Point::new(-20, 0),
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn transform_into_buffer_too_short() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(10, 0)),
+        ];
+        let mut buffer = [PathCommand::Close; 1];
+
+        Path::new(&commands).transform_into(&Affine2::identity(), &mut buffer);
+    }
+}
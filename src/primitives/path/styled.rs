@@ -0,0 +1,686 @@
+use crate::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{
+            scanline_renderer::{
+                self, bresenham_intersection, crossing, Row, RowEvent, DEFAULT_ROW_EVENTS,
+            },
+            PointType, Scanline, StrokeOffset, ThickStroke,
+        },
+        path::{
+            subpaths::{vertices_bounding_box, Subpaths},
+            Path,
+        },
+        styled::{StyledDimensions, StyledDrawable},
+        PrimitiveStyle, Rectangle, Styled,
+    },
+    transform::Transform,
+};
+
+/// Maximum number of subpaths for which the stroke offset is cached.
+const CACHED_STROKE_OFFSETS: usize = 64;
+
+/// Scanline renderer for paths.
+///
+/// The stroke intervals and edge crossings of each row are determined in a single pass over all
+/// segments of the path, which are collected in a [`RowEvent`] buffer.
+///
+/// The stroke offsets of the first [`CACHED_STROKE_OFFSETS`] subpaths are determined once, because
+/// they depend on the winding direction of the whole subpath. The offsets of all other subpaths
+/// are recalculated in every pass.
+struct Renderer<'a, 'b> {
+    path: &'b Path<'a>,
+    stroke: ThickStroke,
+    has_stroke: bool,
+    fill: bool,
+    stroke_offsets: [StrokeOffset; CACHED_STROKE_OFFSETS],
+}
+
+impl<'a, 'b> Renderer<'a, 'b> {
+    fn new<C: PixelColor>(path: &'b Path<'a>, style: &PrimitiveStyle<C>) -> Self {
+        let stroke = ThickStroke::from_style(style, StrokeOffset::from(style.stroke_alignment));
+        let has_stroke = style.effective_stroke_color().is_some();
+
+        let mut stroke_offsets = [stroke.offset; CACHED_STROKE_OFFSETS];
+        if has_stroke && stroke.width > 1 && stroke.offset != StrokeOffset::None {
+            for (offset, subpath) in stroke_offsets.iter_mut().zip(Subpaths::new(path.commands)) {
+                *offset = subpath.stroke_offset(stroke.offset);
+            }
+        }
+
+        Self {
+            path,
+            stroke,
+            has_stroke,
+            fill: style.fill_color.is_some(),
+            stroke_offsets,
+        }
+    }
+
+    /// Adds the stroke intervals and edge crossings in a row.
+    fn add_events(&self, row: &mut Row) {
+        let y = row.y();
+
+        for (index, subpath) in Subpaths::new(self.path.commands).enumerate() {
+            if self.has_stroke && self.stroke.width == 1 {
+                subpath.for_each_edge(false, &mut |start, end| {
+                    row.add_stroke(&bresenham_intersection(start, end, y));
+                });
+            } else if self.has_stroke {
+                let offset = match self.stroke_offsets.get(index) {
+                    Some(offset) => *offset,
+                    None => subpath.stroke_offset(self.stroke.offset),
+                };
+                let subpath_stroke = self.stroke.with_offset(offset);
+
+                subpath.for_each_thick_segment(subpath_stroke, &mut |segment| {
+                    row.add_stroke(&segment.intersection(y));
+                });
+            }
+
+            if self.fill {
+                subpath.for_each_edge(true, &mut |start, end| {
+                    row.add_boundary(&bresenham_intersection(start, end, y));
+
+                    if let Some((crossing, direction)) = crossing(start, end, y) {
+                        row.add_crossing(crossing, direction);
+                    }
+                });
+            }
+        }
+    }
+
+    /// Calls `f` for each stroke and fill scanline in the given rows.
+    fn for_each_scanline<F, E>(
+        &self,
+        rows: core::ops::Range<i32>,
+        buffer: &mut [RowEvent],
+        f: F,
+    ) -> Result<(), E>
+    where
+        F: FnMut(Scanline, PointType) -> Result<(), E>,
+    {
+        scanline_renderer::for_each_row_scanline(
+            rows,
+            buffer,
+            self.path.fill_rule,
+            |row| self.add_events(row),
+            f,
+        )
+    }
+}
+
+/// Compute the bounding box of the non-translated path.
+fn untranslated_bounding_box<C: PixelColor>(path: &Path, style: &PrimitiveStyle<C>) -> Rectangle {
+    let (min, max) = match vertices_bounding_box(path.commands) {
+        Some(bounds) => bounds,
+        None => return Rectangle::zero(),
+    };
+
+    let stroke_color = style.effective_stroke_color();
+
+    if stroke_color.is_none() && style.fill_color.is_none() {
+        return Rectangle::new(Rectangle::with_corners(min, max).center(), Size::zero());
+    }
+
+    if stroke_color.is_none() || style.stroke_width <= 1 {
+        return Rectangle::with_corners(min, max);
+    }
+
+    // The fill area is always inside the outline of a thick stroke, unless the stroke is drawn
+    // on the inside.
+    let (mut min, mut max) = if style.fill_color.is_some() {
+        (min, max)
+    } else {
+        (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN))
+    };
+
+    let stroke = ThickStroke::from_style(style, StrokeOffset::from(style.stroke_alignment));
+
+    for subpath in Subpaths::new(path.commands) {
+        let stroke = stroke.with_offset(subpath.stroke_offset(stroke.offset));
+
+        subpath.for_each_thick_segment(stroke, &mut |segment| {
+            let bb = segment.edges_bounding_box();
+
+            min = min.component_min(bb.top_left);
+            max = max.component_max(bb.bottom_right().unwrap_or(bb.top_left));
+        });
+    }
+
+    if min.x > max.x {
+        return Rectangle::zero();
+    }
+
+    Rectangle::with_corners(min, max)
+}
+
+fn draw<D>(
+    path: &Path,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+    buffer: &mut [RowEvent],
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    if style.is_transparent() {
+        return Ok(());
+    }
+
+    let rows = untranslated_bounding_box(path, style).rows();
+
+    Renderer::new(path, style).for_each_scanline(rows, buffer, |scanline, point_type| {
+        let color = match point_type {
+            PointType::Stroke => style.effective_stroke_color(),
+            PointType::Fill => style.fill_color,
+        };
+
+        match color {
+            Some(color) => target.fill_solid(&scanline.to_rectangle(), color),
+            None => Ok(()),
+        }
+    })
+}
+
+fn draw_translated<D>(
+    path: &Path,
+    style: &PrimitiveStyle<D::Color>,
+    target: &mut D,
+    buffer: &mut [RowEvent],
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    if path.translate != Point::zero() {
+        draw(path, style, &mut target.translated(path.translate), buffer)
+    } else {
+        draw(path, style, target, buffer)
+    }
+}
+
+impl<C: PixelColor> Styled<Path<'_>, PrimitiveStyle<C>> {
+    /// Draws the path using a caller provided row event buffer.
+    ///
+    /// The path is drawn one row at a time. The x coordinates at which the pixel color changes in
+    /// a row are collected in `buffer` and rows that require more events than `buffer` can hold are
+    /// processed in multiple passes over all segments of the path. The edges of the flattened path
+    /// add up to three events to each row they cross and thick segments add up to two events.
+    /// Events at the same x coordinate share a buffer entry.
+    ///
+    /// [`draw`] uses a buffer with [`DEFAULT_ROW_EVENTS`] entries on the stack. A larger buffer can
+    /// speed up drawing paths with many segments and a smaller buffer reduces the stack usage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{path::RowEvent, Path, PathCommand, PrimitiveStyle},
+    /// };
+    /// # use embedded_graphics::mock_display::MockDisplay;
+    /// # let mut display = MockDisplay::default();
+    ///
+    /// let commands = [
+    ///     PathCommand::MoveTo(Point::new(5, 5)),
+    ///     PathCommand::CubicTo {
+    ///         control1: Point::new(60, 0),
+    ///         control2: Point::new(0, 60),
+    ///         end: Point::new(55, 55),
+    ///     },
+    /// ];
+    ///
+    /// let mut buffer = [RowEvent::default(); 64];
+    ///
+    /// Path::new(&commands)
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+    ///     .draw_with_buffer(&mut display, &mut buffer)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`draw`]: crate::Drawable::draw
+    pub fn draw_with_buffer<D>(
+        &self,
+        target: &mut D,
+        buffer: &mut [RowEvent],
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        draw_translated(&self.primitive, &self.style, target, buffer)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Path<'_> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut buffer = [RowEvent::default(); DEFAULT_ROW_EVENTS];
+
+        draw_translated(self, style, target, &mut buffer)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Path<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        untranslated_bounding_box(self, style).translate(self.translate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::Dimensions,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{
+            FillRule, LineCap, LineJoin, PathCommand, Polyline, Primitive, PrimitiveStyleBuilder,
+            StrokeAlignment, Triangle,
+        },
+        Drawable,
+    };
+
+    const SQUARE: [PathCommand; 5] = [
+        PathCommand::MoveTo(Point::new(1, 1)),
+        PathCommand::LineTo(Point::new(6, 1)),
+        PathCommand::LineTo(Point::new(6, 6)),
+        PathCommand::LineTo(Point::new(1, 6)),
+        PathCommand::Close,
+    ];
+
+    const SQUARE_COUNTERCLOCKWISE: [PathCommand; 5] = [
+        PathCommand::MoveTo(Point::new(1, 1)),
+        PathCommand::LineTo(Point::new(1, 6)),
+        PathCommand::LineTo(Point::new(6, 6)),
+        PathCommand::LineTo(Point::new(6, 1)),
+        PathCommand::Close,
+    ];
+
+    fn square_with_hole(inner_clockwise: bool) -> [PathCommand; 10] {
+        let (a, b) = if inner_clockwise {
+            (Point::new(7, 3), Point::new(3, 7))
+        } else {
+            (Point::new(3, 7), Point::new(7, 3))
+        };
+
+        [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::LineTo(Point::new(10, 10)),
+            PathCommand::LineTo(Point::new(0, 10)),
+            PathCommand::Close,
+            PathCommand::MoveTo(Point::new(3, 3)),
+            PathCommand::LineTo(a),
+            PathCommand::LineTo(Point::new(7, 7)),
+            PathCommand::LineTo(b),
+            PathCommand::Close,
+        ]
+    }
+
+    fn assert_matches_rectangle(commands: &[PathCommand], style: PrimitiveStyle<BinaryColor>) {
+        let mut expected = MockDisplay::new();
+        Rectangle::with_corners(Point::new(1, 1), Point::new(6, 6))
+            .into_styled(style)
+            .draw(&mut expected.translated(Point::new(3, 3)))
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Path::new(commands)
+            .into_styled(style)
+            .draw(&mut display.translated(Point::new(3, 3)))
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn matches_rectangle() {
+        for style in [
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+        ] {
+            assert_matches_rectangle(&SQUARE, style);
+            assert_matches_rectangle(&SQUARE_COUNTERCLOCKWISE, style);
+        }
+    }
+
+    #[test]
+    fn matches_triangle() {
+        let triangle = Triangle::new(Point::new(2, 2), Point::new(20, 6), Point::new(8, 16));
+
+        let clockwise = [
+            PathCommand::MoveTo(triangle.vertices[0]),
+            PathCommand::LineTo(triangle.vertices[1]),
+            PathCommand::LineTo(triangle.vertices[2]),
+            PathCommand::Close,
+        ];
+        let counterclockwise = [
+            PathCommand::MoveTo(triangle.vertices[0]),
+            PathCommand::LineTo(triangle.vertices[2]),
+            PathCommand::LineTo(triangle.vertices[1]),
+            PathCommand::Close,
+        ];
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for stroke_width in [2, 3, 4] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(stroke_width)
+                    .stroke_alignment(alignment)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                triangle
+                    .into_styled(style)
+                    .draw(&mut expected.translated(Point::new(5, 5)))
+                    .unwrap();
+
+                for commands in [&clockwise, &counterclockwise] {
+                    // Centered strokes follow the direction of the path and aren't normalized
+                    // to clockwise winding like triangles.
+                    if alignment == StrokeAlignment::Center && commands == &counterclockwise {
+                        continue;
+                    }
+
+                    let mut display = MockDisplay::new();
+                    Path::new(commands)
+                        .into_styled(style)
+                        .draw(&mut display.translated(Point::new(5, 5)))
+                        .unwrap();
+
+                    display.assert_eq(&expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fill_rules() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        Path::new(&square_with_hole(true))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&[
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+        ]);
+
+        let expected = [
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+            "####   ####",
+            "####   ####",
+            "####   ####",
+            "###########",
+            "###########",
+            "###########",
+            "###########",
+        ];
+
+        let mut display = MockDisplay::new();
+        Path::new(&square_with_hole(true))
+            .with_fill_rule(FillRule::EvenOdd)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&expected);
+
+        // The inner subpath cancels the winding number of the outer subpath if it is drawn in
+        // the opposite direction.
+        for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let mut display = MockDisplay::new();
+            Path::new(&square_with_hole(false))
+                .with_fill_rule(fill_rule)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+            display.assert_pattern(&expected);
+        }
+    }
+
+    #[test]
+    fn open_thick_stroke() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Path::new(&SQUARE[..4])
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        // Open subpaths are stroked without a closing segment, but filled as if closed.
+        display.assert_pattern(&[
+            " #######", " #######", " #######", " ....###", " ....###", " #######", " #######",
+            " #######",
+        ]);
+    }
+
+    #[test]
+    fn open_subpath_matches_polyline() {
+        let points = [Point::new(2, 12), Point::new(8, 2), Point::new(14, 12)];
+        let commands = [
+            PathCommand::MoveTo(points[0]),
+            PathCommand::LineTo(points[1]),
+            PathCommand::LineTo(points[2]),
+        ];
+
+        for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+            for line_join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(BinaryColor::On)
+                    .stroke_width(5)
+                    .line_cap(line_cap)
+                    .line_join(line_join)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Polyline::new(&points)
+                    .into_styled(style)
+                    .draw(&mut expected.translated(Point::new(5, 5)))
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                Path::new(&commands)
+                    .into_styled(style)
+                    .draw(&mut display.translated(Point::new(5, 5)))
+                    .unwrap();
+
+                display.assert_eq(&expected);
+
+                assert_eq!(
+                    Path::new(&commands).into_styled(style).bounding_box(),
+                    Polyline::new(&points).into_styled(style).bounding_box(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn translate() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        Path::new(&SQUARE)
+            .into_styled(style)
+            .draw(&mut expected.translated(Point::new(5, 3)))
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Path::new(&SQUARE)
+            .translate(Point::new(5, 3))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(5, 13)),
+            PathCommand::ArcTo {
+                radii: Size::new(8, 8),
+                large_arc: false,
+                sweep: true,
+                end: Point::new(21, 13),
+            },
+            PathCommand::QuadTo {
+                control: Point::new(13, 30),
+                end: Point::new(5, 13),
+            },
+            PathCommand::MoveTo(Point::new(25, 10)),
+            PathCommand::CubicTo {
+                control1: Point::new(30, 0),
+                control2: Point::new(40, 20),
+                end: Point::new(45, 10),
+            },
+        ];
+
+        for (stroke_width, alignment, fill) in [
+            (1, StrokeAlignment::Center, false),
+            (1, StrokeAlignment::Center, true),
+            (0, StrokeAlignment::Center, true),
+            (5, StrokeAlignment::Center, false),
+            (5, StrokeAlignment::Inside, true),
+            (5, StrokeAlignment::Outside, true),
+        ] {
+            let mut builder = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(stroke_width)
+                .stroke_alignment(alignment);
+
+            if fill {
+                builder = builder.fill_color(BinaryColor::Off);
+            }
+
+            let styled = Path::new(&commands)
+                .translate(Point::new(3, 7))
+                .into_styled(builder.build());
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                styled.bounding_box(),
+                "{stroke_width}, {alignment:?}, {fill}"
+            );
+        }
+    }
+
+    #[test]
+    fn transparent_style() {
+        let styled = Path::new(&SQUARE).into_styled(PrimitiveStyle::<BinaryColor>::new());
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+        display.assert_pattern(&[]);
+
+        assert_eq!(
+            styled.bounding_box(),
+            Rectangle::new(Point::new(3, 3), Size::zero())
+        );
+    }
+
+    #[test]
+    fn empty_path() {
+        let styled = Path::new(&[]).into_styled(PrimitiveStyle::with_fill(BinaryColor::On));
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+        display.assert_pattern(&[]);
+
+        assert_eq!(styled.bounding_box(), Rectangle::zero());
+    }
+
+    #[test]
+    fn small_buffers() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(2, 20)),
+            PathCommand::CubicTo {
+                control1: Point::new(10, -10),
+                control2: Point::new(40, 50),
+                end: Point::new(50, 10),
+            },
+            PathCommand::ArcTo {
+                radii: Size::new(12, 8),
+                large_arc: false,
+                sweep: false,
+                end: Point::new(30, 35),
+            },
+            PathCommand::Close,
+            PathCommand::MoveTo(Point::new(10, 5)),
+            PathCommand::LineTo(Point::new(45, 30)),
+            PathCommand::LineTo(Point::new(10, 30)),
+            PathCommand::Close,
+        ];
+
+        for (fill_rule, stroke_width) in [(FillRule::NonZero, 1), (FillRule::EvenOdd, 3)] {
+            let styled = Path::new(&commands)
+                .with_fill_rule(fill_rule)
+                .translate(Point::new(4, 4))
+                .into_styled(
+                    PrimitiveStyleBuilder::new()
+                        .stroke_color(Rgb888::RED)
+                        .stroke_width(stroke_width)
+                        .fill_color(Rgb888::GREEN)
+                        .build(),
+                );
+
+            let mut expected = MockDisplay::new();
+            styled.draw(&mut expected).unwrap();
+
+            for len in 0..=8 {
+                let mut buffer = [RowEvent::default(); 8];
+
+                let mut display = MockDisplay::new();
+                styled
+                    .draw_with_buffer(&mut display, &mut buffer[0..len])
+                    .unwrap();
+
+                display.assert_eq_with_message(&expected, |f| {
+                    write!(f, "{fill_rule:?}, {stroke_width}, {len}")
+                });
+            }
+        }
+    }
+}
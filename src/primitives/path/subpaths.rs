@@ -0,0 +1,367 @@
+//! Subpath iteration and flattening.
+
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{outline, FlattenedCurve, StrokeOffset, ThickSegment, ThickStroke},
+        path::PathCommand,
+    },
+};
+
+/// Subpath.
+///
+/// A subpath contains a start point and the drawing commands until the next `MoveTo` or `Close`
+/// command.
+#[derive(Copy, Clone, Debug)]
+pub struct Subpath<'a> {
+    start: Point,
+    commands: &'a [PathCommand],
+    closed: bool,
+}
+
+impl<'a> Subpath<'a> {
+    /// Returns an iterator over the flattened vertices.
+    pub fn vertices(&self) -> Vertices<'a> {
+        Vertices {
+            commands: self.commands.iter(),
+            position: self.start,
+            curve: None,
+            last: None,
+        }
+    }
+
+    /// Calls `f` for each line segment in the flattened subpath.
+    ///
+    /// If `close` is `true` or the subpath is closed, the closing segment is included.
+    pub fn for_each_edge<F>(&self, close: bool, f: &mut F)
+    where
+        F: FnMut(Point, Point),
+    {
+        outline::for_each_edge(self.vertices(), close || self.closed, f);
+    }
+
+    /// Calls `f` for each thick segment in the flattened subpath.
+    pub fn for_each_thick_segment<F>(&self, stroke: ThickStroke, f: &mut F)
+    where
+        F: FnMut(ThickSegment),
+    {
+        outline::for_each_thick_segment(self.vertices(), self.closed, stroke, f);
+    }
+
+    /// Returns the stroke offset for this subpath.
+    ///
+    /// The offset is swapped for counterclockwise subpaths to make the stroke alignment
+    /// independent of the winding direction.
+    pub fn stroke_offset(&self, stroke_offset: StrokeOffset) -> StrokeOffset {
+        outline::stroke_offset(self.vertices(), stroke_offset)
+    }
+}
+
+/// Iterator over the subpaths in a path.
+#[derive(Clone, Debug)]
+pub struct Subpaths<'a> {
+    commands: &'a [PathCommand],
+    position: Point,
+}
+
+impl<'a> Subpaths<'a> {
+    /// Creates a new subpath iterator.
+    pub const fn new(commands: &'a [PathCommand]) -> Self {
+        Self {
+            commands,
+            position: Point::zero(),
+        }
+    }
+}
+
+impl<'a> Iterator for Subpaths<'a> {
+    type Item = Subpath<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.commands.split_first()? {
+                (PathCommand::MoveTo(point), rest) => {
+                    self.position = *point;
+                    self.commands = rest;
+                }
+                (PathCommand::Close, rest) => {
+                    self.commands = rest;
+                }
+                _ => break,
+            }
+        }
+
+        let start = self.position;
+
+        let len = self
+            .commands
+            .iter()
+            .position(|command| matches!(command, PathCommand::MoveTo(_) | PathCommand::Close))
+            .unwrap_or(self.commands.len());
+
+        let (commands, rest) = self.commands.split_at(len);
+
+        let closed = matches!(rest.first(), Some(PathCommand::Close));
+        self.commands = if closed { &rest[1..] } else { rest };
+
+        self.position = if closed {
+            start
+        } else {
+            commands.last().and_then(end_point).unwrap_or(start)
+        };
+
+        Some(Subpath {
+            start,
+            commands,
+            closed,
+        })
+    }
+}
+
+/// Returns the end point of a drawing command.
+const fn end_point(command: &PathCommand) -> Option<Point> {
+    match *command {
+        PathCommand::LineTo(end)
+        | PathCommand::QuadTo { end, .. }
+        | PathCommand::CubicTo { end, .. }
+        | PathCommand::ArcTo { end, .. } => Some(end),
+        PathCommand::MoveTo(_) | PathCommand::Close => None,
+    }
+}
+
+/// Iterator over the flattened vertices of a subpath.
+///
+/// Consecutive duplicate vertices are skipped.
+#[derive(Clone, Debug)]
+pub struct Vertices<'a> {
+    commands: core::slice::Iter<'a, PathCommand>,
+    position: Point,
+    curve: Option<(FlattenedCurve, usize)>,
+    last: Option<Point>,
+}
+
+impl Vertices<'_> {
+    fn next_vertex(&mut self) -> Option<Point> {
+        let last = match self.last {
+            Some(last) => last,
+            None => return Some(self.position),
+        };
+
+        if let Some((curve, index)) = &mut self.curve {
+            if let Some(vertex) = curve.vertices().get(*index) {
+                *index += 1;
+                return Some(*vertex);
+            }
+
+            self.curve = None;
+        }
+
+        let curve = match *self.commands.next()? {
+            PathCommand::LineTo(end) => return Some(end),
+            PathCommand::QuadTo { control, end } => FlattenedCurve::quadratic(last, control, end),
+            PathCommand::CubicTo {
+                control1,
+                control2,
+                end,
+            } => FlattenedCurve::cubic(last, control1, control2, end),
+            PathCommand::ArcTo {
+                radii,
+                large_arc,
+                sweep,
+                end,
+            } => FlattenedCurve::arc(last, radii, large_arc, sweep, end),
+            PathCommand::MoveTo(_) | PathCommand::Close => return None,
+        };
+
+        // The first vertex of the curve is equal to the last vertex and can be skipped.
+        self.curve = Some((curve, 1));
+
+        self.next_vertex()
+    }
+}
+
+impl Iterator for Vertices<'_> {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let vertex = self.next_vertex()?;
+
+            if self.last != Some(vertex) {
+                self.last = Some(vertex);
+
+                return Some(vertex);
+            }
+        }
+    }
+}
+
+/// Returns the minimum and maximum coordinates of all flattened vertices.
+///
+/// Returns `None` if the path doesn't contain any drawing commands.
+pub fn vertices_bounding_box(commands: &[PathCommand]) -> Option<(Point, Point)> {
+    Subpaths::new(commands)
+        .flat_map(|subpath| subpath.vertices())
+        .fold(None, |bounds, vertex| {
+            Some(match bounds {
+                Some((min, max)) => (
+                    Point::component_min(min, vertex),
+                    Point::component_max(max, vertex),
+                ),
+                None => (vertex, vertex),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Size;
+
+    #[test]
+    fn subpaths() {
+        let commands = [
+            PathCommand::LineTo(Point::new(1, 2)),
+            PathCommand::MoveTo(Point::new(10, 10)),
+            PathCommand::MoveTo(Point::new(20, 20)),
+            PathCommand::LineTo(Point::new(30, 20)),
+            PathCommand::LineTo(Point::new(30, 30)),
+            PathCommand::Close,
+            PathCommand::LineTo(Point::new(5, 5)),
+            PathCommand::Close,
+            PathCommand::Close,
+            PathCommand::MoveTo(Point::new(1, 1)),
+        ];
+
+        let mut subpaths = Subpaths::new(&commands);
+
+        let subpath = subpaths.next().unwrap();
+        assert_eq!(subpath.start, Point::zero());
+        assert_eq!(subpath.commands, &commands[0..1]);
+        assert!(!subpath.closed);
+
+        let subpath = subpaths.next().unwrap();
+        assert_eq!(subpath.start, Point::new(20, 20));
+        assert_eq!(subpath.commands, &commands[3..5]);
+        assert!(subpath.closed);
+
+        let subpath = subpaths.next().unwrap();
+        assert_eq!(subpath.start, Point::new(20, 20));
+        assert_eq!(subpath.commands, &commands[6..7]);
+        assert!(subpath.closed);
+
+        assert!(subpaths.next().is_none());
+    }
+
+    #[test]
+    fn vertices() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::QuadTo {
+                control: Point::new(10, 0),
+                end: Point::new(10, 0),
+            },
+            PathCommand::ArcTo {
+                radii: Size::new(5, 5),
+                large_arc: false,
+                sweep: true,
+                end: Point::new(10, 10),
+            },
+        ];
+
+        let subpath = Subpaths::new(&commands).next().unwrap();
+        let mut vertices = subpath.vertices();
+
+        assert_eq!(vertices.next(), Some(Point::new(0, 0)));
+        assert_eq!(vertices.next(), Some(Point::new(10, 0)));
+        assert_eq!(vertices.clone().last(), Some(Point::new(10, 10)));
+        assert!(vertices.all(|v| v.x >= 10 && v.x <= 15));
+    }
+
+    #[test]
+    fn edges() {
+        let commands = [
+            PathCommand::MoveTo(Point::new(0, 0)),
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::LineTo(Point::new(10, 10)),
+        ];
+
+        let subpath = Subpaths::new(&commands).next().unwrap();
+
+        let mut count = 0;
+        subpath.for_each_edge(false, &mut |_, _| count += 1);
+        assert_eq!(count, 2);
+
+        let mut count = 0;
+        subpath.for_each_edge(true, &mut |_, _| count += 1);
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn winding() {
+        let clockwise = [
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::LineTo(Point::new(10, 10)),
+        ];
+        let counterclockwise = [
+            PathCommand::LineTo(Point::new(0, 10)),
+            PathCommand::LineTo(Point::new(10, 10)),
+        ];
+
+        for offset in [StrokeOffset::None, StrokeOffset::Left, StrokeOffset::Right] {
+            assert_eq!(
+                Subpaths::new(&clockwise)
+                    .next()
+                    .unwrap()
+                    .stroke_offset(offset),
+                offset
+            );
+        }
+
+        let counterclockwise = Subpaths::new(&counterclockwise).next().unwrap();
+        assert_eq!(
+            counterclockwise.stroke_offset(StrokeOffset::None),
+            StrokeOffset::None
+        );
+        assert_eq!(
+            counterclockwise.stroke_offset(StrokeOffset::Left),
+            StrokeOffset::Right
+        );
+        assert_eq!(
+            counterclockwise.stroke_offset(StrokeOffset::Right),
+            StrokeOffset::Left
+        );
+    }
+
+    #[test]
+    fn thick_segments() {
+        let open = [
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::LineTo(Point::new(10, 10)),
+            PathCommand::LineTo(Point::new(0, 10)),
+        ];
+        let closed = [
+            PathCommand::LineTo(Point::new(10, 0)),
+            PathCommand::LineTo(Point::new(10, 10)),
+            PathCommand::LineTo(Point::new(0, 10)),
+            PathCommand::LineTo(Point::new(0, 0)),
+            PathCommand::Close,
+        ];
+
+        let mut count = 0;
+        Subpaths::new(&open)
+            .next()
+            .unwrap()
+            .for_each_thick_segment(ThickStroke::new(3, StrokeOffset::None), &mut |_| count += 1);
+        assert_eq!(count, 3);
+
+        let mut count = 0;
+        Subpaths::new(&closed)
+            .next()
+            .unwrap()
+            .for_each_thick_segment(ThickStroke::new(3, StrokeOffset::None), &mut |_| count += 1);
+        assert_eq!(count, 4);
+    }
+}
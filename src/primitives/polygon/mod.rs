@@ -0,0 +1,249 @@
+//! The polygon primitive
+
+use crate::{
+    geometry::{Dimensions, Point, Size},
+    primitives::{common::polygon, ContainsPoint, Primitive, Rectangle},
+    transform::{Affine2, Transform},
+};
+
+mod styled;
+
+/// Polygon primitive
+///
+/// A polygon is a closed shape which is defined by a slice of vertices. The last vertex is
+/// connected to the first vertex. Polygons are the result of applying an affine transformation to
+/// a [`RegularPolygon`], a [`Star`] or a [`RotatedRectangle`], but can also be created from
+/// arbitrary vertices.
+///
+/// The polygon is filled using the non-zero fill rule. Thick strokes use the line join settings of
+/// the style and respect the [`StrokeAlignment`] setting. No memory allocation is required to draw
+/// a polygon.
+///
+/// # Examples
+///
+/// ## Draw a filled arrow
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Polygon, PrimitiveStyleBuilder},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let points = [
+///     Point::new(5, 20),
+///     Point::new(30, 5),
+///     Point::new(55, 20),
+///     Point::new(40, 20),
+///     Point::new(40, 40),
+///     Point::new(20, 40),
+///     Point::new(20, 20),
+/// ];
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::YELLOW)
+///     .build();
+///
+/// Polygon::new(&points).into_styled(style).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`RegularPolygon`]: crate::primitives::RegularPolygon
+/// [`RotatedRectangle`]: crate::primitives::RotatedRectangle
+/// [`Star`]: crate::primitives::Star
+/// [`StrokeAlignment`]: crate::primitives::StrokeAlignment
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Polygon<'a> {
+    /// An offset to apply to the polygon as a whole
+    pub translate: Point,
+
+    /// All vertices in the polygon
+    pub vertices: &'a [Point],
+}
+
+impl<'a> Polygon<'a> {
+    /// Creates a new polygon from a list of vertices.
+    pub const fn new(vertices: &'a [Point]) -> Self {
+        Self {
+            vertices,
+            translate: Point::zero(),
+        }
+    }
+
+    /// Applies an affine transformation to the polygon.
+    ///
+    /// A polygon doesn't own its vertices, which is why the transformed vertices are written
+    /// into `buffer`. The returned polygon borrows the first `self.vertices.len()` points of the
+    /// buffer. The [`translate`] offset of the polygon is applied before the transformation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than the list of vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+    /// let polygon = Polygon::new(&points);
+    ///
+    /// let mut buffer = [Point::zero(); 3];
+    /// let scaled = polygon.transform_into(&Affine2::scale(2.0, 2.0), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     scaled.vertices,
+    ///     &[Point::new(0, 0), Point::new(20, 0), Point::new(20, 10)]
+    /// );
+    /// ```
+    ///
+    /// [`translate`]: Polygon::translate
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [Point]) -> Polygon<'b> {
+        transform_vertices(self.vertices(), affine, buffer)
+    }
+
+    /// Returns an iterator over the translated vertices.
+    fn vertices(&self) -> impl ExactSizeIterator<Item = Point> + Clone + 'a {
+        let translate = self.translate;
+
+        self.vertices.iter().map(move |vertex| *vertex + translate)
+    }
+}
+
+/// Transforms the vertices and returns a polygon that borrows the transformed vertices.
+///
+/// # Panics
+///
+/// Panics if `buffer` is shorter than the list of vertices.
+pub(in crate::primitives) fn transform_vertices<'b, I>(
+    vertices: I,
+    affine: &Affine2,
+    buffer: &'b mut [Point],
+) -> Polygon<'b>
+where
+    I: ExactSizeIterator<Item = Point>,
+{
+    let buffer = &mut buffer[0..vertices.len()];
+
+    for (output, vertex) in buffer.iter_mut().zip(vertices) {
+        *output = affine.transform_point(vertex);
+    }
+
+    Polygon::new(buffer)
+}
+
+impl Primitive for Polygon<'_> {}
+
+impl ContainsPoint for Polygon<'_> {
+    fn contains(&self, point: Point) -> bool {
+        polygon::contains(self.vertices(), point)
+    }
+}
+
+impl Dimensions for Polygon<'_> {
+    fn bounding_box(&self) -> Rectangle {
+        match polygon::vertices_bounding_box(self.vertices()) {
+            Some((min, max)) => Rectangle::with_corners(min, max),
+            None => Rectangle::new(self.translate, Size::zero()),
+        }
+    }
+}
+
+impl Transform for Polygon<'_> {
+    /// Translate the polygon from its current position to a new position by (x, y) pixels,
+    /// returning a new `Polygon`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(15, 5), Point::new(10, 20)];
+    ///
+    /// let polygon = Polygon::new(&points);
+    /// let moved = polygon.translate(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(5, 5));
+    /// assert_eq!(moved.bounding_box().top_left, Point::new(15, 17));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            translate: self.translate + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polygon from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let points = [Point::new(5, 10), Point::new(15, 5), Point::new(10, 20)];
+    ///
+    /// let mut polygon = Polygon::new(&points);
+    /// polygon.translate_mut(Point::new(10, 12));
+    ///
+    /// assert_eq!(polygon.bounding_box().top_left, Point::new(15, 17));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.translate += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn empty_bounding_box() {
+        let polygon = Polygon::new(&[]).translate(Point::new(3, 4));
+
+        assert_eq!(
+            polygon.bounding_box(),
+            Rectangle::new(Point::new(3, 4), Size::zero())
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let points = [Point::new(0, 0), Point::new(10, 0), Point::new(0, 10)];
+        let polygon = Polygon::new(&points).translate(Point::new(5, 5));
+
+        assert!(polygon.contains(Point::new(6, 6)));
+        assert!(polygon.contains(Point::new(15, 5)));
+        assert!(!polygon.contains(Point::new(1, 1)));
+        assert!(!polygon.contains(Point::new(14, 14)));
+    }
+
+    #[test]
+    fn transform_into() {
+        let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+        let polygon = Polygon::new(&points).translate(Point::new(5, 5));
+
+        let mut buffer = [Point::zero(); 4];
+        let rotated = polygon.transform_into(&Affine2::rotation(90.0.deg()), &mut buffer);
+
+        assert_eq!(
+            rotated.vertices,
+            &[Point::new(-5, 5), Point::new(-5, 15), Point::new(-10, 15)]
+        );
+        assert_eq!(rotated.translate, Point::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn transform_into_buffer_too_short() {
+        let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+        let mut buffer = [Point::zero(); 2];
+
+        Polygon::new(&points).transform_into(&Affine2::identity(), &mut buffer);
+    }
+}
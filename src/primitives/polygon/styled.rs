@@ -0,0 +1,88 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::polygon::PolygonRenderer,
+        styled::{StyledDimensions, StyledDrawable},
+        Polygon, PrimitiveStyle, Rectangle,
+    },
+};
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Polygon<'_> {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.is_transparent() {
+            return Ok(());
+        }
+
+        PolygonRenderer::new(self.vertices(), style).draw(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Polygon<'_> {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        if self.vertices.is_empty() {
+            return Rectangle::new(self.translate, Size::zero());
+        }
+
+        PolygonRenderer::new(self.vertices(), style).bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions, Point},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Primitive, PrimitiveStyleBuilder, RegularPolygon},
+        transform::Transform,
+        Drawable,
+    };
+
+    #[test]
+    fn matches_regular_polygon() {
+        let polygon = RegularPolygon::new(Point::new(20, 20), 15, 5, -90.0.deg());
+
+        let mut points = [Point::zero(); 5];
+        for (point, vertex) in points.iter_mut().zip(polygon.vertices()) {
+            *point = vertex - Point::new(5, 5);
+        }
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(3)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut expected = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut expected).unwrap();
+
+        let mut display = MockDisplay::new();
+        Polygon::new(&points)
+            .translate(Point::new(5, 5))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+        assert_eq!(
+            Polygon::new(&points)
+                .translate(Point::new(5, 5))
+                .into_styled(style)
+                .bounding_box(),
+            polygon.into_styled(style).bounding_box()
+        );
+    }
+}
@@ -3,7 +3,7 @@
 use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::{PointsIter, Primitive, Rectangle},
-    transform::Transform,
+    transform::{Affine2, Transform},
 };
 
 mod points;
@@ -73,6 +73,46 @@ impl<'a> Polyline<'a> {
             translate: Point::zero(),
         }
     }
+
+    /// Applies an affine transformation to the polyline.
+    ///
+    /// A polyline doesn't own its vertices, which is why the transformed vertices are written
+    /// into `buffer`. The returned polyline borrows the first `self.vertices.len()` points of the
+    /// buffer. The [`translate`] offset of the polyline is applied before the transformation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than the list of vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Polyline;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+    /// let polyline = Polyline::new(&points);
+    ///
+    /// let mut buffer = [Point::zero(); 3];
+    /// let mirrored = polyline.transform_into(&Affine2::flip_horizontal(), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     mirrored.vertices,
+    ///     &[Point::new(0, 0), Point::new(-10, 0), Point::new(-10, 5)]
+    /// );
+    /// ```
+    ///
+    /// [`translate`]: Polyline::translate
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [Point]) -> Polyline<'b> {
+        let buffer = &mut buffer[0..self.vertices.len()];
+
+        for (output, vertex) in buffer.iter_mut().zip(self.vertices.iter()) {
+            *output = affine.transform_point(*vertex + self.translate);
+        }
+
+        Polyline::new(buffer)
+    }
 }
 
 impl Primitive for Polyline<'_> {}
@@ -166,7 +206,7 @@ impl Transform for Polyline<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::geometry::{Point, Size};
+    use crate::geometry::{AngleUnit, Point, Size};
 
     // A "heartbeat" shaped polyline
     pub(in crate::primitives::polyline) const HEARTBEAT: [Point; 10] = [
@@ -279,4 +319,27 @@ mod tests {
             polyline.bounding_box().translate(Point::new(20, 24))
         );
     }
+
+    #[test]
+    fn transform_into() {
+        let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+        let polyline = Polyline::new(&points).translate(Point::new(5, 5));
+
+        let mut buffer = [Point::zero(); 4];
+        let rotated = polyline.transform_into(&Affine2::rotation(90.0.deg()), &mut buffer);
+
+        assert_eq!(
+            rotated,
+            Polyline::new(&[Point::new(-5, 5), Point::new(-5, 15), Point::new(-10, 15)])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn transform_into_buffer_too_short() {
+        let points = [Point::new(0, 0), Point::new(10, 0), Point::new(10, 5)];
+        let mut buffer = [Point::zero(); 2];
+
+        Polyline::new(&points).transform_into(&Affine2::identity(), &mut buffer);
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::{
     geometry::Point,
-    primitives::common::{LineJoin, Scanline, StrokeOffset, ThickSegment},
+    primitives::common::{LineJoin, Scanline, StrokeOffset, ThickSegment, ThickStroke},
 };
 
 /// Scanline intersections iterator.
@@ -17,7 +17,7 @@ pub struct ScanlineIntersections<'a> {
     points: &'a [Point],
     remaining_points: &'a [Point],
     next_start_join: Option<LineJoin>,
-    width: u32,
+    stroke: ThickStroke,
     scanline: Scanline,
 }
 
@@ -25,17 +25,15 @@ const EMPTY: &[Point; 3] = &[Point::zero(); 3];
 
 impl<'a> ScanlineIntersections<'a> {
     /// New
-    pub fn new(points: &'a [Point], width: u32, scanline_y: i32) -> Self {
+    pub fn new(points: &'a [Point], stroke: ThickStroke, scanline_y: i32) -> Self {
         let next_start_join = match points {
-            [first, second, ..] => {
-                Some(LineJoin::start(*first, *second, width, StrokeOffset::None))
-            }
+            [first, second, ..] => Some(LineJoin::start(*first, *second, stroke)),
             _ => None,
         };
 
         Self {
             next_start_join,
-            width,
+            stroke,
             points,
             remaining_points: points,
             scanline: Scanline::new_empty(scanline_y),
@@ -46,7 +44,7 @@ impl<'a> ScanlineIntersections<'a> {
     pub(in crate::primitives) const fn empty() -> Self {
         Self {
             next_start_join: None,
-            width: 0,
+            stroke: ThickStroke::new(0, StrokeOffset::None),
             points: EMPTY,
             remaining_points: EMPTY,
             scanline: Scanline::new_empty(0),
@@ -55,17 +53,15 @@ impl<'a> ScanlineIntersections<'a> {
 
     /// Reset scanline iterator with a new scanline.
     pub(in crate::primitives) fn reset_with_new_scanline(&mut self, scanline_y: i32) {
-        *self = Self::new(self.points, self.width, scanline_y);
+        *self = Self::new(self.points, self.stroke, scanline_y);
     }
 
     fn next_segment(&mut self) -> Option<ThickSegment> {
         let start_join = self.next_start_join?;
 
         let end_join = match self.remaining_points {
-            [start, mid, end, ..] => {
-                LineJoin::from_points(*start, *mid, *end, self.width, StrokeOffset::None)
-            }
-            [start, end] => LineJoin::end(*start, *end, self.width, StrokeOffset::None),
+            [start, mid, end, ..] => LineJoin::from_points(*start, *mid, *end, self.stroke),
+            [start, end] => LineJoin::end(*start, *end, self.stroke),
             _ => return None,
         };
 
@@ -5,7 +5,7 @@ use core::ops::Range;
 use crate::{
     pixelcolor::PixelColor,
     primitives::{
-        common::Scanline,
+        common::{Scanline, StrokeOffset, ThickStroke},
         polyline::{
             scanline_intersections::ScanlineIntersections, styled::untranslated_bounding_box,
         },
@@ -35,8 +35,11 @@ impl<'a> ScanlineIterator<'a> {
         let mut rows = untranslated_bounding_box(primitive, style).rows();
 
         if let Some(scanline_y) = rows.next() {
-            let intersections =
-                ScanlineIntersections::new(primitive.vertices, style.stroke_width, scanline_y);
+            let intersections = ScanlineIntersections::new(
+                primitive.vertices,
+                ThickStroke::from_style(style, StrokeOffset::None),
+                scanline_y,
+            );
 
             Self {
                 rows,
@@ -3,7 +3,7 @@ use crate::{
     geometry::{Dimensions, Point, Size},
     pixelcolor::PixelColor,
     primitives::{
-        common::{Scanline, StrokeOffset, ThickSegmentIter},
+        common::{Scanline, StrokeOffset, ThickSegmentIter, ThickStroke},
         polyline::{self, scanline_iterator::ScanlineIterator, Polyline},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PointsIter, PrimitiveStyle, Rectangle,
@@ -18,18 +18,21 @@ pub(in crate::primitives::polyline) fn untranslated_bounding_box<C: PixelColor>(
     style: &PrimitiveStyle<C>,
 ) -> Rectangle {
     if style.effective_stroke_color().is_some() && primitive.vertices.len() > 1 {
-        let (min, max) =
-            ThickSegmentIter::new(primitive.vertices, style.stroke_width, StrokeOffset::None).fold(
-                (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
-                |(min, max), segment| {
-                    let bb = segment.edges_bounding_box();
-
-                    (
-                        min.component_min(bb.top_left),
-                        max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
-                    )
-                },
-            );
+        let (min, max) = ThickSegmentIter::new(
+            primitive.vertices,
+            ThickStroke::from_style(style, StrokeOffset::None),
+        )
+        .fold(
+            (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
+            |(min, max), segment| {
+                let bb = segment.edges_bounding_box();
+
+                (
+                    min.component_min(bb.top_left),
+                    max.component_max(bb.bottom_right().unwrap_or(bb.top_left)),
+                )
+            },
+        );
 
         Rectangle::with_corners(min, max)
     } else {
@@ -188,7 +191,9 @@ mod tests {
         iterator::PixelIteratorExt,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, RgbColor},
-        primitives::{Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{
+            LineCap, LineJoin, Primitive, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+        },
         Drawable,
     };
 
@@ -630,4 +635,100 @@ mod tests {
         // Check no pixels are drawn outside bounding box
         assert_eq!(display.affected_area(), bb);
     }
+
+    #[test]
+    fn round_join() {
+        let points = [Point::new(2, 12), Point::new(8, 2), Point::new(14, 12)];
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_join(LineJoin::Round)
+            .build();
+
+        let mut display = MockDisplay::new();
+        Polyline::new(&points)
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "       ###       ",
+            "      #####      ",
+            "     #######     ",
+            "     #######     ",
+            "    #########    ",
+            "    #########    ",
+            "   ##### #####   ",
+            "  ###### ######  ",
+            "  #####   #####  ",
+            " #####     ##### ",
+            " #####     ##### ",
+            "#####       #####",
+            " ####       #### ",
+            "   #         #   ",
+        ]);
+    }
+
+    #[test]
+    fn miter_limit() {
+        let points = [Point::new(5, 40), Point::new(15, 15), Point::new(25, 40)];
+
+        let style = |line_join, miter_limit| {
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(5)
+                .line_join(line_join)
+                .miter_limit(miter_limit)
+                .build()
+        };
+
+        let draw = |style| {
+            let mut display = MockDisplay::new();
+            Polyline::new(&points)
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+            display
+        };
+
+        let bevel = draw(style(LineJoin::Bevel, 4));
+
+        // The miter length of this corner is about 2.7 times the stroke width.
+        draw(style(LineJoin::Miter, 1)).assert_eq(&bevel);
+        draw(style(LineJoin::Miter, 2)).assert_eq(&bevel);
+        assert_ne!(draw(style(LineJoin::Miter, 3)), bevel);
+        assert_ne!(draw(style(LineJoin::Miter, 4)), bevel);
+    }
+
+    #[test]
+    fn caps_and_joins() {
+        for line_cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+            for line_join in [LineJoin::Miter, LineJoin::Bevel, LineJoin::Round] {
+                for stroke_width in 2..8 {
+                    let style = PrimitiveStyleBuilder::new()
+                        .stroke_color(BinaryColor::On)
+                        .stroke_width(stroke_width)
+                        .line_cap(line_cap)
+                        .line_join(line_join)
+                        .build();
+                    let styled = Polyline::new(&PATTERN)
+                        .translate(Point::new(5, 5))
+                        .into_styled(style);
+
+                    let mut display = MockDisplay::new();
+                    styled.draw(&mut display).unwrap();
+                    assert_eq!(
+                        display.affected_area(),
+                        styled.bounding_box(),
+                        "{line_cap:?}, {line_join:?}, {stroke_width} px"
+                    );
+
+                    let mut pixels_display = MockDisplay::new();
+                    styled.pixels().draw(&mut pixels_display).unwrap();
+                    display.assert_eq(&pixels_display);
+                }
+            }
+        }
+    }
 }
@@ -50,6 +50,36 @@ where
     /// [`StrokeStyle::Dotted`] is only implemented for the [`Rectangle`](crate::primitives::Rectangle)
     /// and the [`Line`](crate::primitives::Line) primitive, the default will be used for all other primitives.
     pub stroke_style: StrokeStyle,
+
+    /// Line cap.
+    ///
+    /// The line cap sets the shape of the ends of open shapes (line, polyline, arc, ...) with a
+    /// stroke width greater than one. Closed shapes ignore this setting.
+    pub line_cap: LineCap,
+
+    /// Line join.
+    ///
+    /// The line join sets the shape of the corners between the segments of polylines,
+    /// triangles, paths and curves with a stroke width greater than one.
+    ///
+    /// The corners of the [`Sector`](crate::primitives::Sector),
+    /// [`EllipticalSector`](crate::primitives::EllipticalSector) and
+    /// [`RingSector`](crate::primitives::RingSector) primitives ignore this setting. They are
+    /// beveled if the sweep angle is less than 55° or greater than 305° and mitered otherwise.
+    /// The corners of [`Rectangle`](crate::primitives::Rectangle) strokes are always mitered and
+    /// the corners of [`RoundedRectangle`](crate::primitives::RoundedRectangle) strokes always
+    /// follow the corner radii.
+    pub line_join: LineJoin,
+
+    /// Miter limit.
+    ///
+    /// A [`LineJoin::Miter`] join is drawn as a [`LineJoin::Bevel`] join if the length of the
+    /// miter exceeds `miter_limit` times the stroke width. The length of the miter is measured
+    /// across the full width of a centered stroke, like the `stroke-miterlimit` property in SVG.
+    ///
+    /// Like [`line_join`](Self::line_join), this setting is ignored by the sector primitives
+    /// and by rectangles.
+    pub miter_limit: u32,
 }
 
 impl<C> PrimitiveStyle<C>
@@ -146,6 +176,9 @@ where
             stroke_width: 0,
             stroke_alignment: StrokeAlignment::Center,
             stroke_style: StrokeStyle::const_default(),
+            line_cap: LineCap::Butt,
+            line_join: LineJoin::Miter,
+            miter_limit: 4,
         }
     }
 }
@@ -279,6 +312,31 @@ where
         self
     }
 
+    /// Sets the line cap.
+    ///
+    /// See [`PrimitiveStyle::line_cap`] for more information.
+    pub const fn line_cap(mut self, line_cap: LineCap) -> Self {
+        self.style.line_cap = line_cap;
+
+        self
+    }
+
+    /// Sets the line join.
+    pub const fn line_join(mut self, line_join: LineJoin) -> Self {
+        self.style.line_join = line_join;
+
+        self
+    }
+
+    /// Sets the miter limit.
+    ///
+    /// See [`PrimitiveStyle::miter_limit`] for more information.
+    pub const fn miter_limit(mut self, miter_limit: u32) -> Self {
+        self.style.miter_limit = miter_limit;
+
+        self
+    }
+
     /// Builds the primitive style.
     pub const fn build(self) -> PrimitiveStyle<C> {
         self.style
@@ -325,6 +383,66 @@ impl StrokeStyle {
     }
 }
 
+/// Line cap.
+///
+/// The line cap is used for the start and end points of [`Line`]s, [`Polyline`]s, curves, open
+/// subpaths of [`Path`]s, [`Arc`]s and [`EllipticalArc`]s with a stroke width greater than one.
+///
+/// [`Arc`]: crate::primitives::Arc
+/// [`EllipticalArc`]: crate::primitives::EllipticalArc
+/// [`Line`]: crate::primitives::Line
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Path`]: crate::primitives::Path
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum LineCap {
+    /// Butt.
+    ///
+    /// The stroke ends at the end point of the line.
+    #[default]
+    Butt,
+
+    /// Square.
+    ///
+    /// The stroke is extended by half the stroke width beyond the end point.
+    Square,
+
+    /// Round.
+    ///
+    /// The end point is covered by a circle with a diameter equal to the stroke width.
+    Round,
+}
+
+/// Line join.
+///
+/// The line join is used for the corners of [`Polyline`]s, [`Triangle`]s, curves and [`Path`]s
+/// with a stroke width greater than one.
+///
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Triangle`]: crate::primitives::Triangle
+/// [`Path`]: crate::primitives::Path
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum LineJoin {
+    /// Miter.
+    ///
+    /// The outer edges of the segments are extended until they meet in a sharp corner. Corners
+    /// that exceed the [`miter_limit`](PrimitiveStyle::miter_limit) are drawn as bevel joins.
+    #[default]
+    Miter,
+
+    /// Bevel.
+    ///
+    /// The corner is cut off by a straight line between the outer edges of the segments.
+    Bevel,
+
+    /// Round.
+    ///
+    /// The corner is covered by a circle with a diameter equal to the stroke width. Round joins
+    /// are drawn as bevel joins if the stroke isn't centered on the outline of a closed shape.
+    Round,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -340,6 +458,9 @@ mod tests {
                 stroke_width: 0,
                 stroke_alignment: StrokeAlignment::Center,
                 stroke_style: StrokeStyle::Solid,
+                line_cap: LineCap::Butt,
+                line_join: LineJoin::Miter,
+                miter_limit: 4,
             }
         );
 
@@ -430,6 +551,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn builder_line_cap_and_join() {
+        let style = PrimitiveStyleBuilder::<BinaryColor>::new()
+            .line_cap(LineCap::Round)
+            .line_join(LineJoin::Bevel)
+            .miter_limit(10)
+            .build();
+
+        assert_eq!(style.line_cap, LineCap::Round);
+        assert_eq!(style.line_join, LineJoin::Bevel);
+        assert_eq!(style.miter_limit, 10);
+    }
+
     #[test]
     fn builder_fill() {
         assert_eq!(
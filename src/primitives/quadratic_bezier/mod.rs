@@ -0,0 +1,276 @@
+//! The quadratic Bézier curve primitive
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point},
+    pixelcolor::PixelColor,
+    primitives::{
+        common::FlattenedCurve,
+        styled::{StyledDimensions, StyledDrawable},
+        Primitive, PrimitiveStyle, Rectangle,
+    },
+    transform::{Affine2, AffineTransform, Transform},
+};
+
+/// Quadratic Bézier curve primitive
+///
+/// A quadratic Bézier curve starts at `start`, ends at `end` and is bent towards the `control`
+/// point. The curve is drawn by flattening it into a polyline, which means that thick strokes use
+/// the same line joins as [`Polyline`]. The fill color of the style is ignored, because a curve
+/// isn't a closed shape.
+///
+/// The number of line segments is chosen adaptively, based on the curvature, and limited to 64
+/// segments.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, QuadraticBezier},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Red 3 pixel wide arch from (5, 50) to (55, 50)
+/// QuadraticBezier::new(Point::new(5, 50), Point::new(30, 0), Point::new(55, 50))
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::RED, 3))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Polyline`]: crate::primitives::Polyline
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct QuadraticBezier {
+    /// Start point
+    pub start: Point,
+
+    /// Control point
+    pub control: Point,
+
+    /// End point
+    pub end: Point,
+}
+
+impl QuadraticBezier {
+    /// Creates a quadratic Bézier curve.
+    pub const fn new(start: Point, control: Point, end: Point) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    /// Returns the curve flattened into line segments.
+    pub(in crate::primitives) fn flatten(&self) -> FlattenedCurve {
+        FlattenedCurve::quadratic(self.start, self.control, self.end)
+    }
+}
+
+impl Primitive for QuadraticBezier {}
+
+impl Dimensions for QuadraticBezier {
+    fn bounding_box(&self) -> Rectangle {
+        self.flatten().polyline().bounding_box()
+    }
+}
+
+impl Transform for QuadraticBezier {
+    /// Translate the curve from its current position to a new position by (x, y) pixels,
+    /// returning a new `QuadraticBezier`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let curve = QuadraticBezier::new(Point::new(5, 10), Point::new(10, 0), Point::new(15, 10));
+    /// let moved = curve.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.start, Point::new(15, 20));
+    /// assert_eq!(moved.control, Point::new(20, 10));
+    /// assert_eq!(moved.end, Point::new(25, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            start: self.start + by,
+            control: self.control + by,
+            end: self.end + by,
+        }
+    }
+
+    /// Translate the curve from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut curve = QuadraticBezier::new(Point::new(5, 10), Point::new(10, 0), Point::new(15, 10));
+    /// curve.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(curve.start, Point::new(15, 20));
+    /// assert_eq!(curve.control, Point::new(20, 10));
+    /// assert_eq!(curve.end, Point::new(25, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.start += by;
+        self.control += by;
+        self.end += by;
+
+        self
+    }
+}
+
+impl AffineTransform for QuadraticBezier {
+    type Output = Self;
+
+    /// Transforms the start, end and control points of the curve.
+    ///
+    /// Affine transformations map Bézier curves onto Bézier curves, which is why the result is
+    /// exact up to the rounding of the points.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::QuadraticBezier;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let curve = QuadraticBezier::new(Point::new(0, 10), Point::new(5, 0), Point::new(10, 10));
+    /// let scaled = curve.transform(&Affine2::scale(2.0, 3.0));
+    ///
+    /// assert_eq!(
+    ///     scaled,
+    ///     QuadraticBezier::new(Point::new(0, 30), Point::new(10, 0), Point::new(20, 30))
+    /// );
+    /// ```
+    fn transform(&self, affine: &Affine2) -> Self {
+        Self {
+            start: affine.transform_point(self.start),
+            control: affine.transform_point(self.control),
+            end: affine.transform_point(self.end),
+        }
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for QuadraticBezier {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.flatten().polyline().draw_styled(style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for QuadraticBezier {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        self.flatten().polyline().styled_bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        draw_target::DrawTargetExt,
+        geometry::Size,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{Line, PrimitiveStyleBuilder},
+        Drawable,
+    };
+
+    #[test]
+    fn thin_stroke() {
+        let mut display = MockDisplay::new();
+
+        QuadraticBezier::new(Point::new(0, 6), Point::new(6, -6), Point::new(12, 6))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "     ###     ",
+            "   ##   ##   ",
+            "  #       #  ",
+            " #        #  ",
+            " #         # ",
+            "#          # ",
+            "#           #",
+        ]);
+    }
+
+    #[test]
+    fn thick_stroke() {
+        let mut display = MockDisplay::new();
+
+        let curve = QuadraticBezier::new(Point::new(2, 10), Point::new(10, -6), Point::new(18, 10))
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+
+        curve.draw(&mut display).unwrap();
+
+        assert_eq!(display.affected_area(), curve.bounding_box());
+    }
+
+    #[test]
+    fn straight_curve_matches_line() {
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+
+        let mut expected = MockDisplay::new();
+        Line::new(Point::new(1, 2), Point::new(21, 10))
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        QuadraticBezier::new(Point::new(1, 2), Point::new(11, 6), Point::new(21, 10))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let curve = QuadraticBezier::new(Point::new(0, 6), Point::new(6, -6), Point::new(12, 6));
+
+        assert_eq!(
+            curve.bounding_box(),
+            Rectangle::new(Point::new(0, 0), Size::new(13, 7))
+        );
+
+        assert_eq!(
+            curve
+                .into_styled(PrimitiveStyleBuilder::<BinaryColor>::new().build())
+                .bounding_box(),
+            Rectangle::new(Point::new(6, 3), Size::zero())
+        );
+    }
+
+    #[test]
+    fn translate() {
+        let curve = QuadraticBezier::new(Point::new(0, 6), Point::new(6, -6), Point::new(12, 6));
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 2);
+
+        let mut expected = MockDisplay::new();
+        curve
+            .into_styled(style)
+            .draw(&mut expected.translated(Point::new(3, 4)))
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        curve
+            .translate(Point::new(3, 4))
+            .into_styled(style)
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+}
@@ -0,0 +1,244 @@
+//! The regular polygon primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Size},
+    primitives::{
+        common::polygon,
+        polygon::{transform_vertices, Polygon},
+        ContainsPoint, Primitive, Rectangle,
+    },
+    transform::{Affine2, Transform},
+};
+
+mod styled;
+mod vertices;
+
+pub use vertices::Vertices;
+
+/// Regular polygon primitive
+///
+/// A regular polygon has `sides` vertices which are evenly distributed on a circle around the
+/// `center` point. The first vertex is located at the `rotation` angle, which is measured
+/// clockwise from the positive x axis. Polygons with fewer than three sides aren't drawn.
+///
+/// Vertex coordinates are rounded to the nearest integer and the polygon is filled using the
+/// non-zero fill rule. Thick strokes use the line join settings of the style and respect the
+/// [`StrokeAlignment`] setting. No memory allocation is required to draw a regular polygon.
+///
+/// # Examples
+///
+/// ## Draw a hexagon and a pentagon with different styles
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, PrimitiveStyleBuilder, RegularPolygon},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Hexagon with a flat top edge and a 1 pixel wide white stroke
+/// RegularPolygon::new(Point::new(16, 16), 12, 6, 0.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Pentagon with the first vertex pointing up, a red stroke and a green fill
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// RegularPolygon::new(Point::new(45, 30), 14, 5, -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`StrokeAlignment`]: crate::primitives::StrokeAlignment
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RegularPolygon {
+    /// Center point
+    pub center: Point,
+
+    /// Distance between the center and the vertices
+    pub circumradius: u32,
+
+    /// Number of sides
+    pub sides: u32,
+
+    /// Angle of the first vertex
+    pub rotation: Angle,
+}
+
+impl RegularPolygon {
+    /// Creates a new regular polygon.
+    pub const fn new(center: Point, circumradius: u32, sides: u32, rotation: Angle) -> Self {
+        Self {
+            center,
+            circumradius,
+            sides,
+            rotation,
+        }
+    }
+
+    /// Returns an iterator over the vertices of the polygon.
+    ///
+    /// The iterator is empty if the polygon has fewer than three sides.
+    pub fn vertices(&self) -> Vertices {
+        Vertices::new(self)
+    }
+
+    /// Applies an affine transformation to the polygon.
+    ///
+    /// Scaling and skewing can't be represented by a `RegularPolygon`, which is why the transformed
+    /// vertices are written into `buffer` and a [`Polygon`] that borrows them is returned. The
+    /// buffer must be large enough to hold all `self.vertices().len()` vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than the list of vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RegularPolygon;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let polygon = RegularPolygon::new(Point::new(20, 20), 10, 4, 0.0.deg());
+    ///
+    /// let mut buffer = [Point::zero(); 4];
+    /// let scaled = polygon.transform_into(&Affine2::scale(2.0, 1.0), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     scaled.vertices,
+    ///     &[
+    ///         Point::new(60, 20),
+    ///         Point::new(40, 30),
+    ///         Point::new(20, 20),
+    ///         Point::new(40, 10),
+    ///     ]
+    /// );
+    /// ```
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [Point]) -> Polygon<'b> {
+        transform_vertices(self.vertices(), affine, buffer)
+    }
+}
+
+impl Primitive for RegularPolygon {}
+
+impl ContainsPoint for RegularPolygon {
+    fn contains(&self, point: Point) -> bool {
+        polygon::contains(self.vertices(), point)
+    }
+}
+
+impl Dimensions for RegularPolygon {
+    fn bounding_box(&self) -> Rectangle {
+        match polygon::vertices_bounding_box(self.vertices()) {
+            Some((min, max)) => Rectangle::with_corners(min, max),
+            None => Rectangle::new(self.center, Size::zero()),
+        }
+    }
+}
+
+impl Transform for RegularPolygon {
+    /// Translate the polygon from its current position to a new position by (x, y) pixels,
+    /// returning a new `RegularPolygon`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RegularPolygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let polygon = RegularPolygon::new(Point::new(5, 10), 10, 6, 0.0.deg());
+    /// let moved = polygon.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the polygon from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RegularPolygon;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut polygon = RegularPolygon::new(Point::new(5, 10), 10, 6, 0.0.deg());
+    /// polygon.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(polygon.center, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::PointsIter};
+
+    #[test]
+    fn vertices() {
+        let square = RegularPolygon::new(Point::new(10, 20), 5, 4, 0.0.deg());
+
+        assert!(square.vertices().eq([
+            Point::new(15, 20),
+            Point::new(10, 25),
+            Point::new(5, 20),
+            Point::new(10, 15),
+        ]));
+        assert_eq!(square.vertices().len(), 4);
+
+        let rotated = RegularPolygon {
+            rotation: 180.0.deg(),
+            ..square
+        };
+        assert_eq!(rotated.vertices().next(), Some(Point::new(5, 20)));
+    }
+
+    #[test]
+    fn too_few_sides() {
+        for sides in 0..3 {
+            let polygon = RegularPolygon::new(Point::new(10, 20), 5, sides, 0.0.deg());
+
+            assert_eq!(polygon.vertices().next(), None);
+            assert_eq!(
+                polygon.bounding_box(),
+                Rectangle::new(Point::new(10, 20), Size::zero())
+            );
+        }
+    }
+
+    #[test]
+    fn dimensions() {
+        let hexagon = RegularPolygon::new(Point::new(10, 20), 10, 6, 0.0.deg());
+
+        assert_eq!(
+            hexagon.bounding_box(),
+            Rectangle::with_corners(Point::new(0, 11), Point::new(20, 29))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let square = RegularPolygon::new(Point::new(10, 20), 5, 4, 45.0.deg());
+        let expected = Rectangle::with_corners(Point::new(6, 16), Point::new(14, 24));
+
+        for point in expected.offset(2).points() {
+            assert_eq!(
+                square.contains(point),
+                expected.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::polygon::{self, PolygonRenderer},
+        styled::{StyledDimensions, StyledDrawable},
+        PrimitiveStyle, Rectangle, RegularPolygon,
+    },
+};
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RegularPolygon {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.is_transparent() {
+            return Ok(());
+        }
+
+        polygon::draw(self.vertices(), style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for RegularPolygon {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        if self.vertices().len() == 0 {
+            return Rectangle::new(self.center, Size::zero());
+        }
+
+        PolygonRenderer::new(self.vertices(), style).bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions, Point},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            ContainsPoint, LineJoin, PointsIter, Polyline, Primitive, PrimitiveStyleBuilder,
+            StrokeAlignment, Triangle,
+        },
+        Drawable,
+    };
+
+    #[test]
+    fn square() {
+        let mut display = MockDisplay::new();
+        RegularPolygon::new(Point::new(4, 4), 4, 4, 45.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "         ", //
+            " ####### ", //
+            " #     # ", //
+            " #     # ", //
+            " #     # ", //
+            " #     # ", //
+            " #     # ", //
+            " ####### ", //
+        ]);
+    }
+
+    #[test]
+    fn triangle_matches_triangle_primitive() {
+        let polygon = RegularPolygon::new(Point::new(20, 20), 15, 3, 30.0.deg());
+
+        let mut vertices = polygon.vertices();
+        let triangle = Triangle::new(
+            vertices.next().unwrap(),
+            vertices.next().unwrap(),
+            vertices.next().unwrap(),
+        );
+
+        for style in [
+            PrimitiveStyle::with_fill(BinaryColor::On),
+            PrimitiveStyle::with_stroke(BinaryColor::On, 1),
+        ] {
+            let mut expected = MockDisplay::new();
+            triangle.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            polygon.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn thick_stroke_matches_closed_polyline() {
+        let polygon = RegularPolygon::new(Point::new(30, 30), 20, 6, 0.0.deg());
+
+        let mut vertices = [Point::zero(); 7];
+        for (vertex, point) in vertices.iter_mut().zip(polygon.vertices()) {
+            *vertex = point;
+        }
+        vertices[6] = vertices[0];
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(5)
+            .line_join(LineJoin::Round)
+            .build();
+
+        let mut display = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut display).unwrap();
+
+        // Only the closing vertex differs, which is joined by the polygon but capped by the
+        // polyline, so the pixels of the polyline must be a subset of the polygon.
+        let mut expected = MockDisplay::new();
+        expected.set_allow_overdraw(true);
+        Polyline::new(&vertices)
+            .into_styled(style)
+            .draw(&mut expected)
+            .unwrap();
+
+        for point in expected.affected_area().points() {
+            if expected.get_pixel(point).is_some() {
+                assert_eq!(display.get_pixel(point), Some(BinaryColor::On), "{point:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn fill_matches_contains() {
+        let polygon = RegularPolygon::new(Point::new(20, 20), 17, 7, 10.0.deg());
+
+        let mut display = MockDisplay::new();
+        polygon
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        for point in polygon.bounding_box().offset(2).points() {
+            assert_eq!(
+                display.get_pixel(point).is_some(),
+                polygon.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn stroke_alignment() {
+        let polygon = RegularPolygon::new(Point::new(30, 30), 20, 5, -90.0.deg());
+
+        let fill_areas = [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ]
+        .map(|alignment| {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(alignment)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            let mut display = MockDisplay::new();
+            polygon.into_styled(style).draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                polygon.into_styled(style).bounding_box(),
+                "{alignment:?}"
+            );
+
+            display
+                .affected_area()
+                .points()
+                .filter(|p| display.get_pixel(*p) == Some(BinaryColor::Off))
+                .count()
+        });
+
+        // The stroke covers more of the fill area if it's drawn on the inside.
+        assert!(fill_areas[0] < fill_areas[1], "{fill_areas:?}");
+        assert!(fill_areas[1] < fill_areas[2], "{fill_areas:?}");
+    }
+
+    #[test]
+    fn transparent_style() {
+        let polygon = RegularPolygon::new(Point::new(10, 10), 5, 6, 0.0.deg());
+        let style = PrimitiveStyle::<BinaryColor>::new();
+
+        let mut display = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut display).unwrap();
+        assert!(display.affected_area().is_zero_sized());
+
+        assert_eq!(
+            polygon.into_styled(style).bounding_box(),
+            Rectangle::new(polygon.bounding_box().center(), Default::default())
+        );
+    }
+
+    #[test]
+    fn too_few_sides() {
+        let polygon = RegularPolygon::new(Point::new(10, 10), 5, 2, 0.0.deg());
+        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 3);
+
+        let mut display = MockDisplay::new();
+        polygon.into_styled(style).draw(&mut display).unwrap();
+        assert!(display.affected_area().is_zero_sized());
+
+        assert_eq!(
+            polygon.into_styled(style).bounding_box(),
+            Rectangle::new(Point::new(10, 10), Default::default())
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use core::f32::consts::TAU;
+
+use crate::{
+    geometry::{Angle, Point},
+    primitives::{common::polygon::polar_point, RegularPolygon},
+};
+
+/// Iterator over the vertices of a regular polygon.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Vertices {
+    polygon: RegularPolygon,
+    index: u32,
+}
+
+impl Vertices {
+    pub(super) fn new(polygon: &RegularPolygon) -> Self {
+        // Polygons with fewer than three sides are treated as empty.
+        let index = if polygon.sides < 3 { polygon.sides } else { 0 };
+
+        Self {
+            polygon: *polygon,
+            index,
+        }
+    }
+}
+
+impl Iterator for Vertices {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.polygon.sides {
+            return None;
+        }
+
+        let step = TAU * self.index as f32 / self.polygon.sides as f32;
+        let angle = self.polygon.rotation + Angle::from_radians(step);
+
+        self.index += 1;
+
+        Some(polar_point(
+            self.polygon.center,
+            self.polygon.circumradius,
+            angle,
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.polygon.sides - self.index) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Vertices {}
@@ -0,0 +1,342 @@
+//! The ring sector primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, PointExt, Size},
+    primitives::{
+        common::PlaneSector, Circle, ContainsPoint, OffsetOutline, PointsIter, Primitive, Rectangle,
+    },
+    transform::Transform,
+};
+
+mod points;
+mod styled;
+
+pub use points::Points;
+pub use styled::StyledPixelsIterator;
+
+/// Ring sector primitive
+///
+/// A ring sector is the part of a [`Sector`] that lies outside of a smaller concentric circle,
+/// which is also known as an annular sector. Ring sectors with a sweep angle of 360° or more are
+/// drawn as complete rings (annuli). If the inner diameter is zero the ring sector is drawn like a
+/// [`Sector`].
+///
+/// The outline of a ring sector consists of the outer arc, the inner arc and the two radial lines
+/// that connect both arcs.
+///
+/// # Examples
+///
+/// ## Create some ring sectors with different styles
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, PrimitiveStyleBuilder, RingSector},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+/// # display.set_allow_overdraw(true);
+///
+/// // Ring sector with 1 pixel wide white stroke with top-left point at (10, 20), an outer diameter
+/// // of 30 and an inner diameter of 20
+/// RingSector::new(Point::new(10, 20), 30, 20, 0.0.deg(), 90.0.deg())
+///     .into_styled(PrimitiveStyle::with_stroke(Rgb565::WHITE, 1))
+///     .draw(&mut display)?;
+///
+/// // Ring sector with styled stroke and fill
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// RingSector::new(Point::new(10, 20), 30, 12, 180.0.deg(), -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+///
+/// // Complete ring with blue fill and no stroke
+/// RingSector::with_center(Point::new(40, 30), 40, 30, 0.0.deg(), 360.0.deg())
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::BLUE))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Sector`]: crate::primitives::Sector
+#[doc(alias = "annulus")]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RingSector {
+    /// Top-left point of the bounding-box of the outer circle
+    pub top_left: Point,
+
+    /// Diameter of the outer circle
+    pub outer_diameter: u32,
+
+    /// Diameter of the inner circle
+    pub inner_diameter: u32,
+
+    /// Angle at which the ring sector starts
+    pub angle_start: Angle,
+
+    /// Angle defining the ring sector sweep starting at angle_start
+    pub angle_sweep: Angle,
+}
+
+impl RingSector {
+    /// Create a new ring sector delimited with a top-left point with specific diameters and start
+    /// and sweep angles
+    pub const fn new(
+        top_left: Point,
+        outer_diameter: u32,
+        inner_diameter: u32,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        RingSector {
+            top_left,
+            outer_diameter,
+            inner_diameter,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Create a new ring sector centered around a given point with specific diameters and start
+    /// and sweep angles
+    pub const fn with_center(
+        center: Point,
+        outer_diameter: u32,
+        inner_diameter: u32,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        let top_left = Rectangle::with_center(center, Size::new_equal(outer_diameter)).top_left;
+
+        RingSector {
+            top_left,
+            outer_diameter,
+            inner_diameter,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Creates a ring sector based on a circle.
+    ///
+    /// The `top_left` and `outer_diameter` of the resulting ring sector will match the base circle.
+    pub const fn from_circle(
+        circle: Circle,
+        inner_diameter: u32,
+        angle_start: Angle,
+        angle_sweep: Angle,
+    ) -> Self {
+        RingSector {
+            top_left: circle.top_left,
+            outer_diameter: circle.diameter,
+            inner_diameter,
+            angle_start,
+            angle_sweep,
+        }
+    }
+
+    /// Returns the outer circle of this ring sector.
+    pub const fn to_circle(&self) -> Circle {
+        Circle::new(self.top_left, self.outer_diameter)
+    }
+
+    /// Return the center point of the ring sector
+    pub fn center(&self) -> Point {
+        self.bounding_box().center()
+    }
+
+    /// Returns the threshold for the inner circle.
+    fn inner_threshold(&self) -> u32 {
+        Circle::new(Point::zero(), self.inner_diameter).threshold()
+    }
+}
+
+impl OffsetOutline for RingSector {
+    fn offset(&self, offset: i32) -> Self {
+        let outer_circle = self.to_circle().offset(offset);
+
+        // An inner diameter of zero is kept to draw the ring sector like a sector.
+        let inner_diameter = if self.inner_diameter > 0 {
+            Circle::new(Point::zero(), self.inner_diameter)
+                .offset(offset.saturating_neg())
+                .diameter
+        } else {
+            0
+        };
+
+        Self {
+            top_left: outer_circle.top_left,
+            outer_diameter: outer_circle.diameter,
+            inner_diameter,
+            ..*self
+        }
+    }
+}
+
+impl Primitive for RingSector {}
+
+impl PointsIter for RingSector {
+    type Iter = Points;
+
+    fn points(&self) -> Self::Iter {
+        Points::new(self)
+    }
+}
+
+impl ContainsPoint for RingSector {
+    fn contains(&self, point: Point) -> bool {
+        let circle = self.to_circle();
+
+        if circle.contains(point) {
+            let delta = point * 2 - circle.center_2x();
+
+            delta.length_squared() as u32 >= self.inner_threshold()
+                && PlaneSector::new(self.angle_start, self.angle_sweep).contains(delta)
+        } else {
+            false
+        }
+    }
+}
+
+impl Dimensions for RingSector {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(self.top_left, Size::new_equal(self.outer_diameter))
+    }
+}
+
+impl Transform for RingSector {
+    /// Translate the ring sector from its current position to a new position by (x, y) pixels,
+    /// returning a new `RingSector`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RingSector;
+    /// # use embedded_graphics::prelude::*;
+    /// let ring_sector = RingSector::new(Point::new(5, 10), 10, 6, 0.0.deg(), 90.0.deg());
+    /// let moved = ring_sector.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            top_left: self.top_left + by,
+            ..*self
+        }
+    }
+
+    /// Translate the ring sector from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RingSector;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut ring_sector = RingSector::new(Point::new(5, 10), 10, 6, 0.0.deg(), 90.0.deg());
+    /// ring_sector.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(ring_sector.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.top_left += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::AngleUnit, primitives::Sector};
+
+    #[test]
+    fn dimensions() {
+        let ring_sector = RingSector::new(Point::new(5, 15), 10, 4, 0.0.deg(), 90.0.deg());
+
+        assert_eq!(
+            ring_sector.bounding_box(),
+            Rectangle::new(Point::new(5, 15), Size::new(10, 10))
+        );
+    }
+
+    #[test]
+    fn center_is_correct() {
+        // odd diameter
+        let ring_sector = RingSector::new(Point::new(10, 10), 5, 3, 0.0.deg(), 90.0.deg());
+        assert_eq!(ring_sector.center(), Point::new(12, 12));
+
+        // even diameter
+        let ring_sector = RingSector::new(Point::new(10, 10), 6, 2, 0.0.deg(), 90.0.deg());
+        assert_eq!(ring_sector.center(), Point::new(12, 12));
+
+        // odd diameter
+        let ring_sector = RingSector::with_center(Point::new(10, 10), 5, 3, 0.0.deg(), 90.0.deg());
+        assert_eq!(ring_sector.center(), Point::new(10, 10));
+
+        // even diameter
+        let ring_sector = RingSector::with_center(Point::new(10, 10), 6, 2, 0.0.deg(), 90.0.deg());
+        assert_eq!(ring_sector.center(), Point::new(10, 10));
+    }
+
+    #[test]
+    fn contains() {
+        let ring_sector = RingSector::new(Point::zero(), 20, 9, 30.0.deg(), 200.0.deg());
+
+        let contained_points = Rectangle::new(Point::new(-10, -10), Size::new(40, 40))
+            .points()
+            .filter(|p| ring_sector.contains(*p));
+
+        assert!(contained_points.eq(ring_sector.points()));
+    }
+
+    #[test]
+    fn annulus_contains() {
+        let outer = Circle::with_center(Point::new(10, 10), 15);
+        let inner = Circle::with_center(Point::new(10, 10), 7);
+        let annulus = RingSector::from_circle(outer, 7, 0.0.deg(), 360.0.deg());
+
+        for point in outer.bounding_box().offset(1).points() {
+            assert_eq!(
+                annulus.contains(point),
+                outer.contains(point) && !inner.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn zero_inner_diameter_matches_sector() {
+        let sector = Sector::new(Point::new(1, 2), 15, 30.0.deg(), 200.0.deg());
+        let ring_sector = RingSector::new(Point::new(1, 2), 15, 0, 30.0.deg(), 200.0.deg());
+
+        assert!(sector.points().eq(ring_sector.points()));
+    }
+
+    #[test]
+    fn offset() {
+        let center = Point::new(5, 7);
+        let ring_sector = RingSector::with_center(center, 11, 5, 0.0.deg(), 90.0.deg());
+
+        assert_eq!(ring_sector.offset(0), ring_sector);
+
+        assert_eq!(
+            ring_sector.offset(1),
+            RingSector::with_center(center, 13, 3, 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            ring_sector.offset(-2),
+            RingSector::with_center(center, 7, 9, 0.0.deg(), 90.0.deg())
+        );
+        assert_eq!(
+            ring_sector.offset(3),
+            RingSector::with_center(center, 17, 0, 0.0.deg(), 90.0.deg())
+        );
+
+        let sector_like = RingSector::with_center(center, 11, 0, 0.0.deg(), 90.0.deg());
+        assert_eq!(
+            sector_like.offset(-2),
+            RingSector::with_center(center, 7, 0, 0.0.deg(), 90.0.deg())
+        );
+    }
+}
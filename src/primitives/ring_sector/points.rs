@@ -0,0 +1,76 @@
+use crate::{
+    geometry::Point,
+    primitives::{
+        common::{DistanceIterator, PlaneSector},
+        ring_sector::RingSector,
+    },
+};
+
+/// Iterator over all points inside the ring sector.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Points {
+    iter: DistanceIterator,
+
+    plane_sector: PlaneSector,
+
+    outer_threshold: u32,
+    inner_threshold: u32,
+}
+
+impl Points {
+    pub(in crate::primitives) fn new(ring_sector: &RingSector) -> Self {
+        let circle = ring_sector.to_circle();
+
+        let plane_sector = PlaneSector::new(ring_sector.angle_start, ring_sector.angle_sweep);
+
+        Self {
+            // PERF: The distance iterator should use the smaller ring sector bounding box
+            iter: circle.distances(),
+            plane_sector,
+            outer_threshold: circle.threshold(),
+            inner_threshold: ring_sector.inner_threshold(),
+        }
+    }
+}
+
+impl Iterator for Points {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_threshold = self.outer_threshold;
+        let inner_threshold = self.inner_threshold;
+        let plane_sector = self.plane_sector;
+
+        self.iter
+            .find(|(_, delta, distance)| {
+                *distance < outer_threshold
+                    && *distance >= inner_threshold
+                    && plane_sector.contains(*delta)
+            })
+            .map(|(point, ..)| point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, Primitive, PrimitiveStyle},
+        Pixel,
+    };
+
+    #[test]
+    fn points_equals_filled() {
+        let ring_sector = RingSector::with_center(Point::new(10, 10), 15, 7, 0.0.deg(), 90.0.deg());
+
+        let styled_points = ring_sector
+            .into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .pixels()
+            .map(|Pixel(p, _)| p);
+
+        assert!(ring_sector.points().eq(styled_points));
+    }
+}
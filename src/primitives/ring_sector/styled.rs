@@ -0,0 +1,308 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::{DistanceIterator, PointType, RadialStroke, Scanline},
+        styled::{StyledDimensions, StyledDrawable, StyledPixels},
+        PrimitiveStyle, Rectangle, RingSector,
+    },
+    Pixel,
+};
+use az::SaturatingAs;
+
+/// Pixel iterator for each pixel in the ring sector border
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct StyledPixelsIterator<C> {
+    iter: DistanceIterator,
+
+    radial_stroke: RadialStroke,
+
+    outer_threshold: u32,
+    inner_threshold: u32,
+
+    fill_outer_threshold: u32,
+    fill_inner_threshold: u32,
+
+    stroke_color: Option<C>,
+    fill_color: Option<C>,
+}
+
+impl<C: PixelColor> StyledPixelsIterator<C> {
+    fn new(primitive: &RingSector, style: &PrimitiveStyle<C>) -> Self {
+        let stroke_area = style.stroke_area(primitive);
+        let fill_area = style.fill_area(primitive);
+
+        let stroke_area_circle = stroke_area.to_circle();
+
+        let iter = if !style.is_transparent() {
+            // PERF: The distance iterator should use the smaller ring sector bounding box
+            stroke_area_circle.distances()
+        } else {
+            DistanceIterator::empty()
+        };
+
+        let outer_threshold = stroke_area_circle.threshold();
+        let inner_threshold = stroke_area.inner_threshold();
+        let fill_outer_threshold = fill_area.to_circle().threshold();
+        let fill_inner_threshold = fill_area.inner_threshold();
+
+        let radial_stroke =
+            RadialStroke::new(stroke_area.angle_start, stroke_area.angle_sweep, style);
+
+        Self {
+            iter,
+            radial_stroke,
+            outer_threshold,
+            inner_threshold,
+            fill_outer_threshold,
+            fill_inner_threshold,
+            stroke_color: style.stroke_color,
+            fill_color: style.fill_color,
+        }
+    }
+}
+
+impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
+    type Item = Pixel<C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let outer_threshold = self.outer_threshold;
+        let inner_threshold = self.inner_threshold;
+
+        loop {
+            let (point, delta, distance) = self.iter.find(|(_, _, distance)| {
+                *distance < outer_threshold && *distance >= inner_threshold
+            })?;
+
+            // Check if point is inside the radial stroke lines or the fill.
+            let mut point_type = match self.radial_stroke.point_type(delta) {
+                Some(point_type) => point_type,
+                None => continue,
+            };
+
+            // Add the outer and inner circular strokes.
+            if point_type == PointType::Fill
+                && (distance >= self.fill_outer_threshold || distance < self.fill_inner_threshold)
+            {
+                point_type = PointType::Stroke;
+            }
+
+            let color = match point_type {
+                PointType::Stroke => self.stroke_color,
+                PointType::Fill => self.fill_color,
+            };
+
+            if let Some(color) = color {
+                return Some(Pixel(point, color));
+            }
+        }
+    }
+}
+
+impl<C: PixelColor> StyledPixels<PrimitiveStyle<C>> for RingSector {
+    type Iter = StyledPixelsIterator<C>;
+
+    fn pixels(&self, style: &PrimitiveStyle<C>) -> Self::Iter {
+        StyledPixelsIterator::new(self, style)
+    }
+}
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RingSector {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        Scanline::draw_pixels(StyledPixelsIterator::new(self, style), target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for RingSector {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        let offset = style.outside_stroke_width().saturating_as();
+
+        self.bounding_box().offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Point},
+        iterator::PixelIteratorExt,
+        mock_display::MockDisplay,
+        pixelcolor::{BinaryColor, Rgb888, RgbColor},
+        primitives::{Circle, Primitive, PrimitiveStyleBuilder, Sector, StrokeAlignment},
+        Drawable,
+    };
+
+    #[test]
+    fn tiny_ring_sector() {
+        let mut display = MockDisplay::new();
+
+        RingSector::new(Point::zero(), 13, 5, 210.0.deg(), 120.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "    #####    ", //
+            "  ##     ##  ", //
+            " ##       ## ", //
+            " #   ###   # ", //
+            "  ###   ###  ", //
+        ]);
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let mut display = MockDisplay::<Rgb888>::new();
+
+        RingSector::new(Point::new(-6, 1), 21, 7, 0.0.deg(), -90.0.deg())
+            .into_styled(
+                PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb888::RED)
+                    .stroke_width(3)
+                    .fill_color(Rgb888::GREEN)
+                    .build(),
+            )
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_pattern(&[
+            "   RRRRR        ",
+            "   RRRRRRR      ",
+            "   RRRRRRRRR    ",
+            "   RRRGRRRRRR   ",
+            "   RRRGGGRRRRR  ",
+            "   RRRGGGGGRRR  ",
+            "   RRRRGGGGRRRR ",
+            "   RRRRRGGGGRRR ",
+            "   RRRRRRGGGRRRR",
+            "      RRRRGGGRRR",
+            "       RRRRRRRRR",
+            "       RRRRRRRRR",
+            "       RRRRRRRRR",
+        ]);
+    }
+
+    #[test]
+    fn annulus_stroke() {
+        let mut display = MockDisplay::new();
+        RingSector::with_center(Point::new(15, 15), 21, 11, 0.0.deg(), 360.0.deg())
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut display)
+            .unwrap();
+
+        // The stroke of the inner circle is drawn on the outside of the hole.
+        let mut expected = MockDisplay::new();
+        for diameter in [21, 13] {
+            Circle::with_center(Point::new(15, 15), diameter)
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+                .draw(&mut expected)
+                .unwrap();
+        }
+
+        display.assert_eq(&expected);
+    }
+
+    #[test]
+    fn zero_inner_diameter_matches_sector() {
+        for stroke_alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            for (angle_start, angle_sweep) in [
+                (0.0.deg(), 90.0.deg()),
+                (30.0.deg(), 20.0.deg()),
+                (-45.0.deg(), -320.0.deg()),
+                (10.0.deg(), 360.0.deg()),
+            ] {
+                let style = PrimitiveStyleBuilder::new()
+                    .stroke_color(Rgb888::RED)
+                    .stroke_width(3)
+                    .stroke_alignment(stroke_alignment)
+                    .fill_color(Rgb888::GREEN)
+                    .build();
+
+                let mut expected = MockDisplay::new();
+                Sector::new(Point::new(6, 6), 21, angle_start, angle_sweep)
+                    .into_styled(style)
+                    .draw(&mut expected)
+                    .unwrap();
+
+                let mut display = MockDisplay::new();
+                RingSector::new(Point::new(6, 6), 21, 0, angle_start, angle_sweep)
+                    .into_styled(style)
+                    .draw(&mut display)
+                    .unwrap();
+
+                display.assert_eq(&expected);
+            }
+        }
+    }
+
+    #[test]
+    fn draw_matches_pixels() {
+        for stroke_width in [0, 1, 4] {
+            for (angle_start, angle_sweep) in [
+                (0.0.deg(), 90.0.deg()),
+                (200.0.deg(), -250.0.deg()),
+                (0.0.deg(), 360.0.deg()),
+            ] {
+                let styled = RingSector::new(Point::new(5, 5), 31, 13, angle_start, angle_sweep)
+                    .into_styled(
+                        PrimitiveStyleBuilder::new()
+                            .stroke_color(Rgb888::RED)
+                            .stroke_width(stroke_width)
+                            .fill_color(Rgb888::GREEN)
+                            .build(),
+                    );
+
+                let mut expected = MockDisplay::new();
+                styled.pixels().draw(&mut expected).unwrap();
+
+                let mut display = MockDisplay::new();
+                styled.draw(&mut display).unwrap();
+
+                display.assert_eq(&expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let ring_sector = RingSector::new(Point::new(5, 5), 21, 9, 0.0.deg(), 360.0.deg());
+
+        for stroke_width in [1, 3, 4] {
+            let styled =
+                ring_sector.into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, stroke_width));
+
+            let mut display = MockDisplay::new();
+            styled.draw(&mut display).unwrap();
+            assert_eq!(display.affected_area(), styled.bounding_box());
+        }
+
+        let transparent = ring_sector.into_styled(
+            PrimitiveStyleBuilder::<BinaryColor>::new()
+                .stroke_width(3)
+                .build(),
+        );
+        assert_eq!(
+            transparent.bounding_box(),
+            ring_sector
+                .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3))
+                .bounding_box()
+        );
+    }
+}
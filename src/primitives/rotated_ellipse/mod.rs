@@ -0,0 +1,479 @@
+//! The rotated ellipse primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Real, Size, Trigonometry},
+    primitives::{
+        common::rotation::{
+            LocalEllipse, LocalShape, RotatedPrimitive, RotatedShape, Rotation, LOCAL_SCALE,
+        },
+        ellipse::{self, EllipseContains},
+        ContainsPoint, Ellipse, OffsetOutline, Primitive, Rectangle,
+    },
+    transform::{Affine2, AffineTransform, Transform},
+};
+
+mod styled;
+
+/// Rotated ellipse primitive
+///
+/// A rotated ellipse is an [`Ellipse`] that is rotated around its center. Positive angles rotate
+/// the ellipse clockwise. A pixel is part of the rotated ellipse if its center lies inside the
+/// rotated outline. Ellipses with a rotation of 0° and circles cover the same pixels as the base
+/// ellipse.
+///
+/// # Examples
+///
+/// ## Draw a tilted ellipse
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{Ellipse, PrimitiveStyleBuilder, RotatedEllipse},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(3)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// let ellipse = Ellipse::with_center(Point::new(32, 32), Size::new(50, 20));
+///
+/// RotatedEllipse::new(ellipse, -30.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RotatedEllipse {
+    /// The unrotated base ellipse
+    pub ellipse: Ellipse,
+
+    /// Rotation around the center of the ellipse
+    pub rotation: Angle,
+}
+
+impl RotatedEllipse {
+    /// Creates a new rotated ellipse.
+    pub const fn new(ellipse: Ellipse, rotation: Angle) -> Self {
+        Self { ellipse, rotation }
+    }
+
+    /// Creates a new rotated ellipse centered around a given point.
+    pub const fn with_center(center: Point, size: Size, rotation: Angle) -> Self {
+        Self::new(Ellipse::with_center(center, size), rotation)
+    }
+
+    /// Returns the center point of the rotated ellipse.
+    pub fn center(&self) -> Point {
+        self.ellipse.center()
+    }
+
+    /// Returns the rotation around the center of the ellipse.
+    fn local_rotation(&self) -> Rotation {
+        Rotation::new(
+            ellipse::center_2x(self.ellipse.top_left, self.ellipse.size),
+            self.rotation,
+        )
+    }
+}
+
+impl OffsetOutline for RotatedEllipse {
+    fn offset(&self, offset: i32) -> Self {
+        Self {
+            ellipse: self.ellipse.offset(offset),
+            ..*self
+        }
+    }
+}
+
+impl Primitive for RotatedEllipse {}
+
+/// Local shape of a rotated ellipse.
+#[derive(Copy, Clone, Debug)]
+pub(in crate::primitives) enum LocalRotatedEllipse {
+    /// Circle, which uses the same points as an unrotated [`Ellipse`].
+    Circle(EllipseContains, LocalEllipse),
+    /// Ellipse.
+    Ellipse(LocalEllipse),
+}
+
+impl LocalShape for LocalRotatedEllipse {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        match self {
+            // The local coordinates of circles aren't rotated and are multiples of LOCAL_SCALE.
+            Self::Circle(contains, _) => contains.contains(Point::new(
+                (x / LOCAL_SCALE) as i32,
+                (y / LOCAL_SCALE) as i32,
+            )),
+            Self::Ellipse(ellipse) => ellipse.contains(x, y),
+        }
+    }
+
+    fn chord(&self, rotation: &Rotation, dy: Real, limit: Real) -> (Real, Real) {
+        match self {
+            Self::Circle(_, ellipse) | Self::Ellipse(ellipse) => ellipse.chord(rotation, dy, limit),
+        }
+    }
+}
+
+impl RotatedPrimitive for RotatedEllipse {
+    type Shape = LocalRotatedEllipse;
+
+    fn rotated_shape(&self) -> RotatedShape<Self::Shape> {
+        let size = self.ellipse.size;
+        let ellipse = LocalEllipse::new(size);
+
+        // Circles aren't affected by the rotation.
+        if size.width == size.height {
+            let rotation = Rotation::unrotated(ellipse::center_2x(self.ellipse.top_left, size));
+
+            RotatedShape::new(
+                rotation,
+                LocalRotatedEllipse::Circle(EllipseContains::new(size), ellipse),
+            )
+        } else {
+            RotatedShape::new(self.local_rotation(), LocalRotatedEllipse::Ellipse(ellipse))
+        }
+    }
+}
+
+impl ContainsPoint for RotatedEllipse {
+    fn contains(&self, point: Point) -> bool {
+        self.rotated_shape().contains(point)
+    }
+}
+
+impl Dimensions for RotatedEllipse {
+    fn bounding_box(&self) -> Rectangle {
+        if self.ellipse.size.width == 0 || self.ellipse.size.height == 0 {
+            return Rectangle::new(self.center(), Size::zero());
+        }
+
+        let rotation = self.local_rotation();
+
+        rotation.bounding_box(rotation.ellipse_extents(self.ellipse.size))
+    }
+}
+
+impl Transform for RotatedEllipse {
+    /// Translate the rotated ellipse from its current position to a new position by (x, y)
+    /// pixels, returning a new `RotatedEllipse`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Ellipse, RotatedEllipse};
+    /// # use embedded_graphics::prelude::*;
+    /// let ellipse = Ellipse::new(Point::new(5, 10), Size::new(10, 4));
+    /// let rotated = RotatedEllipse::new(ellipse, 45.0.deg());
+    /// let moved = rotated.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.ellipse.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            ellipse: self.ellipse.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the rotated ellipse from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Ellipse, RotatedEllipse};
+    /// # use embedded_graphics::prelude::*;
+    /// let ellipse = Ellipse::new(Point::new(5, 10), Size::new(10, 4));
+    /// let mut rotated = RotatedEllipse::new(ellipse, 45.0.deg());
+    /// rotated.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(rotated.ellipse.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.ellipse.translate_mut(by);
+
+        self
+    }
+}
+
+impl AffineTransform for RotatedEllipse {
+    type Output = Self;
+
+    /// Transforms the rotated ellipse.
+    ///
+    /// The size and rotation of the result are determined by decomposing the linear part of the
+    /// transformation into a rotation, a scaling along the axes and a second rotation. The center
+    /// of the result is rounded to the nearest position that is compatible with its size.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::RotatedEllipse;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let rotated = RotatedEllipse::with_center(Point::new(20, 20), Size::new(21, 11), 0.0.deg());
+    /// let scaled = rotated.transform(&Affine2::scale(1.0, 3.0).around(Point::new(20, 20)));
+    ///
+    /// assert_eq!(scaled.ellipse.size, Size::new(33, 21));
+    /// assert_eq!(scaled.center(), Point::new(20, 20));
+    /// ```
+    fn transform(&self, affine: &Affine2) -> Self {
+        let size = self.ellipse.size;
+        let center_2x = ellipse::center_2x(self.ellipse.top_left, size);
+
+        let two = Real::from(2);
+        let (center_x, center_y) =
+            affine.transform_real(Real::from(center_2x.x) / two, Real::from(center_2x.y) / two);
+
+        // Linear part of the transformation applied to the rotated semi axes of the ellipse.
+        let [[a, b], [c, d]] = affine.matrix();
+        let cos = self.rotation.cos();
+        let sin = self.rotation.sin();
+        let w = Real::from(size.width) / two;
+        let h = Real::from(size.height) / two;
+
+        let n00 = (a * cos + b * sin) * w;
+        let n01 = (b * cos - a * sin) * h;
+        let n10 = (c * cos + d * sin) * w;
+        let n11 = (d * cos - c * sin) * h;
+
+        // Singular value decomposition of the 2x2 matrix.
+        let e = (n00 + n11) / two;
+        let f = (n00 - n11) / two;
+        let g = (n10 + n01) / two;
+        let h = (n10 - n01) / two;
+
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+
+        let size = Size::new(
+            u32::from((two * (q + r)).round()),
+            u32::from((two * (q - r).abs()).round()),
+        );
+
+        let rotation = if size.width == size.height {
+            Angle::zero()
+        } else {
+            Angle::from_radians_real((h.atan2(e) + g.atan2(f)) / two)
+        };
+
+        // The doubled center coordinates must have the same parity as `size - 1`.
+        let round_center = |center: Real, size: u32| {
+            let parity = (size as i32 - 1).rem_euclid(2);
+            let center_2x =
+                2 * i32::from(((center * two - Real::from(parity)) / two).round()) + parity;
+
+            (center_2x - (size as i32 - 1)) / 2
+        };
+
+        let top_left = Point::new(
+            round_center(center_x, size.width),
+            round_center(center_y, size.height),
+        );
+
+        Self::new(Ellipse::new(top_left, size), rotation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, PointExt},
+        primitives::{Circle, PointsIter},
+    };
+
+    #[test]
+    fn unrotated_matches_ellipse() {
+        for size in [Size::new(15, 8), Size::new(6, 11), Size::new(9, 9)] {
+            let ellipse = Ellipse::new(Point::new(2, 3), size);
+            let rotated = RotatedEllipse::new(ellipse, 0.0.deg());
+
+            assert_eq!(rotated.bounding_box(), ellipse.bounding_box());
+
+            for point in ellipse.bounding_box().offset(2).points() {
+                assert_eq!(
+                    rotated.contains(point),
+                    ellipse.contains(point),
+                    "{point:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_circle_matches_circle() {
+        let ellipse = Ellipse::new(Point::new(2, 3), Size::new(12, 12));
+        let rotated = RotatedEllipse::new(ellipse, 33.0.deg());
+
+        for point in ellipse.bounding_box().offset(2).points() {
+            assert_eq!(
+                rotated.contains(point),
+                ellipse.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn quarter_turn_swaps_width_and_height() {
+        let center = Point::new(10, 20);
+        let rotated = RotatedEllipse::with_center(center, Size::new(15, 7), 90.0.deg());
+        let expected = Ellipse::with_center(center, Size::new(7, 15));
+
+        assert_eq!(rotated.bounding_box(), expected.bounding_box());
+
+        for point in expected.bounding_box().offset(2).points() {
+            assert_eq!(
+                rotated.contains(point),
+                expected.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box_contains_all_points() {
+        for angle in [10.0, 30.0, 45.0, 100.0, 200.0, -60.0] {
+            let rotated =
+                RotatedEllipse::with_center(Point::new(20, 20), Size::new(24, 9), angle.deg());
+            let bounding_box = rotated.bounding_box();
+
+            let mut touches = [false; 4];
+            for point in bounding_box.offset(3).points() {
+                if rotated.contains(point) {
+                    assert!(bounding_box.contains(point), "{angle} {point:?}");
+
+                    let bottom_right = bounding_box.bottom_right().unwrap();
+                    touches[0] |= point.x == bounding_box.top_left.x;
+                    touches[1] |= point.y == bounding_box.top_left.y;
+                    touches[2] |= point.x == bottom_right.x;
+                    touches[3] |= point.y == bottom_right.y;
+                }
+            }
+
+            // The bounding box shouldn't be larger than necessary.
+            assert_eq!(touches, [true; 4], "{angle}");
+        }
+    }
+
+    #[test]
+    fn zero_sized() {
+        let rotated = RotatedEllipse::new(Ellipse::new(Point::new(1, 2), Size::zero()), 30.0.deg());
+
+        assert_eq!(
+            rotated.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero())
+        );
+        assert!(!rotated.contains(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn offset() {
+        let rotated = RotatedEllipse::with_center(Point::new(10, 10), Size::new(8, 4), 30.0.deg());
+
+        assert_eq!(
+            rotated.offset(2),
+            RotatedEllipse::with_center(Point::new(10, 10), Size::new(12, 8), 30.0.deg())
+        );
+    }
+
+    /// Returns the rotation in degrees, normalized to the range `[0, 180)`.
+    fn axis_angle(rotated: &RotatedEllipse) -> f32 {
+        rotated.rotation.to_degrees().rem_euclid(180.0)
+    }
+
+    #[test]
+    fn transform_identity() {
+        let rotated =
+            RotatedEllipse::with_center(Point::new(20, 20), Size::new(21, 10), 30.0.deg());
+        let transformed = rotated.transform(&Affine2::identity());
+
+        assert_eq!(transformed.ellipse, rotated.ellipse);
+        assert!((axis_angle(&transformed) - 30.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn transform_rotation() {
+        let center = Point::new(20, 20);
+        let rotated = RotatedEllipse::with_center(center, Size::new(21, 11), 10.0.deg());
+        let transformed = rotated.transform(&Affine2::rotation(50.0.deg()).around(center));
+
+        assert_eq!(transformed.ellipse, rotated.ellipse);
+        assert!((axis_angle(&transformed) - 60.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn transform_scale() {
+        let ellipse = Ellipse::with_center(Point::new(10, 10), Size::new(11, 7));
+
+        let transformed = ellipse.transform(&Affine2::scale(3.0, 3.0));
+        assert_eq!(
+            transformed,
+            RotatedEllipse::with_center(Point::new(30, 30), Size::new(33, 21), Angle::zero())
+        );
+
+        let transformed = ellipse.transform(&Affine2::scale(1.0, 3.0).around(Point::new(10, 10)));
+        assert_eq!(
+            transformed.ellipse,
+            Ellipse::with_center(Point::new(10, 10), Size::new(21, 11))
+        );
+        assert!((axis_angle(&transformed) - 90.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn transform_flip() {
+        let center = Point::new(20, 20);
+        let rotated = RotatedEllipse::with_center(center, Size::new(21, 11), 30.0.deg());
+
+        let transformed = rotated.transform(&Affine2::flip_horizontal().around(center));
+        assert_eq!(transformed.ellipse, rotated.ellipse);
+        assert!((axis_angle(&transformed) - 150.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn transform_large_ellipse() {
+        let center = Point::new(0, 0);
+        let rotated = RotatedEllipse::with_center(center, Size::new(401, 201), 30.0.deg());
+
+        let transformed = rotated.transform(&Affine2::scale(3.0, 3.0));
+        assert_eq!(
+            transformed.ellipse,
+            Ellipse::with_center(center, Size::new(1203, 603))
+        );
+        assert!((axis_angle(&transformed) - 30.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn transform_skew_preserves_area() {
+        let center = Point::new(20, 20);
+        let ellipse = Ellipse::with_center(center, Size::new(21, 11));
+        let transformed = ellipse.transform(&Affine2::skew(30.0.deg(), 0.0.deg()).around(center));
+
+        assert!((transformed.center() - center).length_squared() <= 2);
+        assert_ne!(transformed.rotation, Angle::zero());
+
+        let area = transformed.ellipse.size.width * transformed.ellipse.size.height;
+        assert!((220..=242).contains(&area), "{area}");
+    }
+
+    #[test]
+    fn transform_circle() {
+        let circle = Circle::with_center(Point::new(10, 10), 9);
+
+        let transformed =
+            circle.transform(&Affine2::rotation(45.0.deg()).around(Point::new(10, 10)));
+        assert_eq!(
+            transformed,
+            RotatedEllipse::with_center(Point::new(10, 10), Size::new(9, 9), Angle::zero())
+        );
+
+        let transformed = circle.transform(&Affine2::scale(3.0, 3.0));
+        assert_eq!(
+            transformed,
+            RotatedEllipse::with_center(Point::new(30, 30), Size::new(27, 27), Angle::zero())
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! The rotated rectangle primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Real, Size, Trigonometry},
+    primitives::{
+        common::rotation::{LocalRectangle, RotatedPrimitive, RotatedShape, Rotation},
+        ellipse, ContainsPoint, OffsetOutline, Polygon, Primitive, Rectangle,
+    },
+    transform::{Affine2, Transform},
+};
+
+mod styled;
+
+/// Rotated rectangle primitive
+///
+/// A rotated rectangle is a [`Rectangle`] that is rotated around its center. Positive angles
+/// rotate the rectangle clockwise. A pixel is part of the rotated rectangle if its center lies
+/// inside the rotated outline, which means that a rectangle with a rotation of 0° covers the
+/// same pixels as the base rectangle.
+///
+/// # Examples
+///
+/// ## Draw a tilted indicator
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyleBuilder, Rectangle, RotatedRectangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::RED)
+///     .stroke_width(2)
+///     .fill_color(Rgb565::GREEN)
+///     .build();
+///
+/// let indicator = Rectangle::with_center(Point::new(32, 32), Size::new(40, 12));
+///
+/// RotatedRectangle::new(indicator, 30.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RotatedRectangle {
+    /// The unrotated base rectangle
+    pub rectangle: Rectangle,
+
+    /// Rotation around the center of the rectangle
+    pub rotation: Angle,
+}
+
+impl RotatedRectangle {
+    /// Creates a new rotated rectangle.
+    pub const fn new(rectangle: Rectangle, rotation: Angle) -> Self {
+        Self {
+            rectangle,
+            rotation,
+        }
+    }
+
+    /// Creates a new rotated rectangle centered around a given point.
+    pub const fn with_center(center: Point, size: Size, rotation: Angle) -> Self {
+        Self::new(Rectangle::with_center(center, size), rotation)
+    }
+
+    /// Returns the center point of the rotated rectangle.
+    pub fn center(&self) -> Point {
+        self.rectangle.center()
+    }
+
+    /// Applies an affine transformation to the rotated rectangle.
+    ///
+    /// Scaling and skewing can turn a rectangle into an arbitrary parallelogram, which can't be
+    /// represented by a `RotatedRectangle`. The four transformed corners are therefore written into
+    /// `buffer` and a [`Polygon`] that borrows them is returned. The corners are the centers of the
+    /// corner pixels, which means that the polygon covers the same pixels as the rectangle if the
+    /// transformation is the identity. Zero sized rectangles return a polygon without vertices.
+    ///
+    /// A [`Rectangle`] can be transformed by converting it into a `RotatedRectangle` with a
+    /// rotation of 0° first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than 4 points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Rectangle, RotatedRectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let rectangle = Rectangle::new(Point::new(10, 10), Size::new(11, 5));
+    /// let rotated = RotatedRectangle::new(rectangle, 0.0.deg());
+    ///
+    /// let mut buffer = [Point::zero(); 4];
+    /// let skewed = rotated.transform_into(&Affine2::skew(45.0.deg(), 0.0.deg()), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     skewed.vertices,
+    ///     &[
+    ///         Point::new(20, 10),
+    ///         Point::new(30, 10),
+    ///         Point::new(34, 14),
+    ///         Point::new(24, 14),
+    ///     ]
+    /// );
+    /// ```
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [Point]) -> Polygon<'b> {
+        let buffer = &mut buffer[0..4];
+
+        if self.rectangle.is_zero_sized() {
+            return Polygon::new(&buffer[0..0]);
+        }
+
+        let two = Real::from(2);
+        let center_2x = ellipse::center_2x(self.rectangle.top_left, self.rectangle.size);
+        let center_x = Real::from(center_2x.x) / two;
+        let center_y = Real::from(center_2x.y) / two;
+
+        let size = self.rectangle.size - Size::new(1, 1);
+        let half_width = Real::from(size.width) / two;
+        let half_height = Real::from(size.height) / two;
+
+        let (cos, sin) = (self.rotation.cos(), self.rotation.sin());
+
+        let corners = [
+            (-half_width, -half_height),
+            (half_width, -half_height),
+            (half_width, half_height),
+            (-half_width, half_height),
+        ];
+
+        for (output, (dx, dy)) in buffer.iter_mut().zip(corners) {
+            let (x, y) = affine.transform_real(
+                center_x + dx * cos - dy * sin,
+                center_y + dx * sin + dy * cos,
+            );
+
+            *output = Point::new(
+                i32::from(x.saturating_round()),
+                i32::from(y.saturating_round()),
+            );
+        }
+
+        Polygon::new(buffer)
+    }
+
+    /// Returns the rotation around the center of the rectangle.
+    fn local_rotation(&self) -> Rotation {
+        Rotation::new(
+            ellipse::center_2x(self.rectangle.top_left, self.rectangle.size),
+            self.rotation,
+        )
+    }
+}
+
+impl OffsetOutline for RotatedRectangle {
+    fn offset(&self, offset: i32) -> Self {
+        Self {
+            rectangle: self.rectangle.offset(offset),
+            ..*self
+        }
+    }
+}
+
+impl Primitive for RotatedRectangle {}
+
+impl RotatedPrimitive for RotatedRectangle {
+    type Shape = LocalRectangle;
+
+    fn rotated_shape(&self) -> RotatedShape<Self::Shape> {
+        RotatedShape::new(
+            self.local_rotation(),
+            LocalRectangle::new(self.rectangle.size),
+        )
+    }
+}
+
+impl ContainsPoint for RotatedRectangle {
+    fn contains(&self, point: Point) -> bool {
+        self.rotated_shape().contains(point)
+    }
+}
+
+impl Dimensions for RotatedRectangle {
+    fn bounding_box(&self) -> Rectangle {
+        if self.rectangle.is_zero_sized() {
+            return Rectangle::new(self.center(), Size::zero());
+        }
+
+        let rotation = self.local_rotation();
+
+        rotation.bounding_box(rotation.rectangle_extents(self.rectangle.size))
+    }
+}
+
+impl Transform for RotatedRectangle {
+    /// Translate the rotated rectangle from its current position to a new position by (x, y)
+    /// pixels, returning a new `RotatedRectangle`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Rectangle, RotatedRectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let rectangle = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let rotated = RotatedRectangle::new(rectangle, 45.0.deg());
+    /// let moved = rotated.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.rectangle.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            rectangle: self.rectangle.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the rotated rectangle from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Rectangle, RotatedRectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let rectangle = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let mut rotated = RotatedRectangle::new(rectangle, 45.0.deg());
+    /// rotated.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(rotated.rectangle.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rectangle.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{PointsIter, PrimitiveStyle},
+        Drawable,
+    };
+
+    #[test]
+    fn unrotated_matches_rectangle() {
+        for size in [Size::new(5, 8), Size::new(6, 6), Size::new(1, 3)] {
+            let rectangle = Rectangle::new(Point::new(2, 3), size);
+            let rotated = RotatedRectangle::new(rectangle, 0.0.deg());
+
+            assert_eq!(rotated.bounding_box(), rectangle);
+
+            for point in rectangle.offset(2).points() {
+                assert_eq!(
+                    rotated.contains(point),
+                    rectangle.contains(point),
+                    "{point:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn quarter_turn_swaps_width_and_height() {
+        let center = Point::new(10, 20);
+        let rotated = RotatedRectangle::with_center(center, Size::new(9, 5), 90.0.deg());
+        let expected = Rectangle::with_center(center, Size::new(5, 9));
+
+        assert_eq!(rotated.bounding_box(), expected);
+
+        for point in expected.offset(2).points() {
+            assert_eq!(
+                rotated.contains(point),
+                expected.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounding_box_contains_all_points() {
+        for angle in [10.0, 30.0, 45.0, 100.0, 200.0, -60.0] {
+            let rotated =
+                RotatedRectangle::with_center(Point::new(20, 20), Size::new(16, 7), angle.deg());
+            let bounding_box = rotated.bounding_box();
+
+            for point in bounding_box.offset(3).points() {
+                if rotated.contains(point) {
+                    assert!(bounding_box.contains(point), "{angle} {point:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn diamond() {
+        let rotated =
+            RotatedRectangle::with_center(Point::new(10, 10), Size::new(9, 9), 45.0.deg());
+
+        assert!(rotated.contains(Point::new(10, 10)));
+        assert!(rotated.contains(Point::new(16, 10)));
+        assert!(rotated.contains(Point::new(10, 4)));
+        assert!(!rotated.contains(Point::new(14, 14)));
+        assert!(!rotated.contains(Point::new(17, 10)));
+    }
+
+    #[test]
+    fn zero_sized() {
+        let rotated =
+            RotatedRectangle::new(Rectangle::new(Point::new(1, 2), Size::zero()), 30.0.deg());
+
+        assert_eq!(
+            rotated.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero())
+        );
+        assert!(!rotated.contains(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn transform_into() {
+        let rotated =
+            RotatedRectangle::with_center(Point::new(10, 10), Size::new(9, 5), 90.0.deg());
+
+        let mut buffer = [Point::zero(); 4];
+        let polygon = rotated.transform_into(&Affine2::scale(2.0, 1.0), &mut buffer);
+
+        assert_eq!(
+            polygon.vertices,
+            &[
+                Point::new(24, 6),
+                Point::new(24, 14),
+                Point::new(16, 14),
+                Point::new(16, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn identity_transform_matches_rectangle() {
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        for size in [Size::new(5, 8), Size::new(6, 6), Size::new(1, 3)] {
+            let rectangle = Rectangle::new(Point::new(2, 3), size);
+
+            let mut buffer = [Point::zero(); 4];
+            let polygon = RotatedRectangle::new(rectangle, 0.0.deg())
+                .transform_into(&Affine2::identity(), &mut buffer);
+
+            let mut expected = MockDisplay::new();
+            rectangle.into_styled(style).draw(&mut expected).unwrap();
+
+            let mut display = MockDisplay::new();
+            polygon.into_styled(style).draw(&mut display).unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn transform_zero_sized() {
+        let rotated =
+            RotatedRectangle::new(Rectangle::new(Point::new(1, 2), Size::zero()), 30.0.deg());
+
+        let mut buffer = [Point::zero(); 4];
+        let polygon = rotated.transform_into(&Affine2::scale(2.0, 2.0), &mut buffer);
+
+        assert!(polygon.vertices.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn transform_into_buffer_too_short() {
+        let rotated =
+            RotatedRectangle::with_center(Point::new(10, 10), Size::new(9, 5), 30.0.deg());
+        let mut buffer = [Point::zero(); 3];
+
+        rotated.transform_into(&Affine2::identity(), &mut buffer);
+    }
+
+    #[test]
+    fn offset() {
+        let rotated =
+            RotatedRectangle::with_center(Point::new(10, 10), Size::new(8, 4), 30.0.deg());
+
+        assert_eq!(
+            rotated.offset(2),
+            RotatedRectangle::with_center(Point::new(10, 10), Size::new(12, 8), 30.0.deg())
+        );
+    }
+}
@@ -0,0 +1,432 @@
+//! The rotated rounded rectangle primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Real, Size},
+    primitives::{
+        common::rotation::{
+            self, LocalShape, RotatedPrimitive, RotatedShape, Rotation, LOCAL_SCALE,
+        },
+        ellipse, ContainsPoint, CornerRadii, OffsetOutline, Primitive, Rectangle, RoundedRectangle,
+    },
+    transform::Transform,
+};
+
+mod styled;
+
+/// Rotated rounded rectangle primitive
+///
+/// A rotated rounded rectangle is a [`RoundedRectangle`] that is rotated around its center.
+/// Positive angles rotate the rounded rectangle clockwise. A pixel is part of the rotated rounded
+/// rectangle if its center lies inside the rotated outline. Corner radii that are too large to fit
+/// into the base rectangle are confined like in [`RoundedRectangle::confine_radii`].
+///
+/// The bounding box of a rotated rounded rectangle is the bounding box of the rotated base
+/// rectangle, which doesn't take the rounded corners into account.
+///
+/// # Examples
+///
+/// ## Draw a rotated needle with rounded ends
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{PrimitiveStyle, Rectangle, RotatedRoundedRectangle, RoundedRectangle},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// let needle = RoundedRectangle::with_equal_corners(
+///     Rectangle::with_center(Point::new(32, 32), Size::new(50, 6)),
+///     Size::new(3, 3),
+/// );
+///
+/// RotatedRoundedRectangle::new(needle, 120.0.deg())
+///     .into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RotatedRoundedRectangle {
+    /// The unrotated base rounded rectangle
+    pub rounded_rectangle: RoundedRectangle,
+
+    /// Rotation around the center of the rounded rectangle
+    pub rotation: Angle,
+}
+
+impl RotatedRoundedRectangle {
+    /// Creates a new rotated rounded rectangle.
+    pub const fn new(rounded_rectangle: RoundedRectangle, rotation: Angle) -> Self {
+        Self {
+            rounded_rectangle,
+            rotation,
+        }
+    }
+
+    /// Creates a new rotated rounded rectangle centered around a given point.
+    pub const fn with_center(
+        center: Point,
+        size: Size,
+        corners: CornerRadii,
+        rotation: Angle,
+    ) -> Self {
+        Self::new(
+            RoundedRectangle::new(Rectangle::with_center(center, size), corners),
+            rotation,
+        )
+    }
+
+    /// Returns the center point of the rotated rounded rectangle.
+    pub fn center(&self) -> Point {
+        self.rounded_rectangle.rectangle.center()
+    }
+
+    /// Returns the rotation around the center of the rounded rectangle.
+    fn local_rotation(&self) -> Rotation {
+        let rectangle = &self.rounded_rectangle.rectangle;
+
+        Rotation::new(
+            ellipse::center_2x(rectangle.top_left, rectangle.size),
+            self.rotation,
+        )
+    }
+}
+
+impl OffsetOutline for RotatedRoundedRectangle {
+    fn offset(&self, offset: i32) -> Self {
+        Self {
+            rounded_rectangle: self.rounded_rectangle.offset(offset),
+            ..*self
+        }
+    }
+}
+
+impl Primitive for RotatedRoundedRectangle {}
+
+/// Local shape of a rotated rounded rectangle.
+#[derive(Copy, Clone, Debug)]
+pub(in crate::primitives) struct LocalRoundedRectangle {
+    /// Rounded rectangle with confined corner radii.
+    rounded_rectangle: RoundedRectangle,
+}
+
+impl LocalRoundedRectangle {
+    /// Returns the corner radius and the signs of the local coordinates for each corner.
+    fn corners(&self) -> [(Size, i32, i32); 4] {
+        let corners = self.rounded_rectangle.corners;
+
+        [
+            (corners.top_left, -1, -1),
+            (corners.top_right, 1, -1),
+            (corners.bottom_right, 1, 1),
+            (corners.bottom_left, -1, 1),
+        ]
+    }
+}
+
+impl LocalShape for LocalRoundedRectangle {
+    fn contains(&self, x: i64, y: i64) -> bool {
+        let width = i64::from(self.rounded_rectangle.rectangle.size.width) * LOCAL_SCALE;
+        let height = i64::from(self.rounded_rectangle.rectangle.size.height) * LOCAL_SCALE;
+
+        if x.abs() >= width || y.abs() >= height {
+            return false;
+        }
+
+        // A point is outside the shape if it is inside the box of a corner, but outside of the
+        // corner ellipse. Large corner boxes can extend into other quadrants, which is why all
+        // corners are checked.
+        self.corners().iter().all(|&(radius, sign_x, sign_y)| {
+            // Position relative to the center of the corner ellipse.
+            let dx = x * i64::from(sign_x) - (width - i64::from(radius.width) * 2 * LOCAL_SCALE);
+            let dy = y * i64::from(sign_y) - (height - i64::from(radius.height) * 2 * LOCAL_SCALE);
+
+            dx <= 0 || dy <= 0 || rotation::ellipse_contains(dx, dy, radius * 2)
+        })
+    }
+
+    fn chord(&self, rotation: &Rotation, dy: Real, limit: Real) -> (Real, Real) {
+        let size = self.rounded_rectangle.rectangle.size;
+        let width = Real::from(size.width);
+        let height = Real::from(size.height);
+        let zero = Real::from(0);
+
+        let (mut start, mut end) =
+            rotation.rectangle_chord(dy, (zero, zero), (width, height), limit);
+        if start > end {
+            return (start, end);
+        }
+
+        // The shape is convex, which means that the parts of the row which are inside a corner
+        // box, but outside the corner ellipse, are located at the ends of the intersection.
+        for (radius, sign_x, sign_y) in self.corners() {
+            if radius.width == 0 || radius.height == 0 {
+                continue;
+            }
+
+            let rx = Real::from(radius.width);
+            let ry = Real::from(radius.height);
+            let sign_x = Real::from(sign_x);
+            let sign_y = Real::from(sign_y);
+
+            let box_center = (sign_x * (width - rx), sign_y * (height - ry));
+            let (mut box_start, mut box_end) =
+                rotation.rectangle_chord(dy, box_center, (rx, ry), limit);
+            if box_start < start {
+                box_start = start;
+            }
+            if box_end > end {
+                box_end = end;
+            }
+            if box_start >= box_end {
+                continue;
+            }
+
+            let two = Real::from(2);
+            let ellipse_center = (sign_x * (width - rx * two), sign_y * (height - ry * two));
+            let (ellipse_start, ellipse_end) =
+                rotation.ellipse_chord(dy, ellipse_center, (rx * two, ry * two), limit);
+
+            if ellipse_start > ellipse_end || ellipse_end <= box_start || ellipse_start >= box_end {
+                // The whole box part is outside the ellipse.
+                if box_start - start < end - box_end {
+                    start = box_end;
+                } else {
+                    end = box_start;
+                }
+            } else {
+                if ellipse_start > start {
+                    start = ellipse_start;
+                }
+                if ellipse_end < end {
+                    end = ellipse_end;
+                }
+            }
+        }
+
+        (start, end)
+    }
+}
+
+impl RotatedPrimitive for RotatedRoundedRectangle {
+    type Shape = LocalRoundedRectangle;
+
+    fn rotated_shape(&self) -> RotatedShape<Self::Shape> {
+        RotatedShape::new(
+            self.local_rotation(),
+            LocalRoundedRectangle {
+                rounded_rectangle: self.rounded_rectangle.confine_radii(),
+            },
+        )
+    }
+}
+
+impl ContainsPoint for RotatedRoundedRectangle {
+    fn contains(&self, point: Point) -> bool {
+        self.rotated_shape().contains(point)
+    }
+}
+
+impl Dimensions for RotatedRoundedRectangle {
+    fn bounding_box(&self) -> Rectangle {
+        let rectangle = &self.rounded_rectangle.rectangle;
+
+        if rectangle.is_zero_sized() {
+            return Rectangle::new(self.center(), Size::zero());
+        }
+
+        let rotation = self.local_rotation();
+
+        rotation.bounding_box(rotation.rectangle_extents(rectangle.size))
+    }
+}
+
+impl Transform for RotatedRoundedRectangle {
+    /// Translate the rotated rounded rectangle from its current position to a new position by (x, y)
+    /// pixels, returning a new `RotatedRoundedRectangle`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Rectangle, RotatedRoundedRectangle, RoundedRectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let rectangle = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let rounded_rectangle = RoundedRectangle::with_equal_corners(rectangle, Size::new(2, 2));
+    /// let rotated = RotatedRoundedRectangle::new(rounded_rectangle, 45.0.deg());
+    /// let moved = rotated.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.rounded_rectangle.rectangle.top_left, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            rounded_rectangle: self.rounded_rectangle.translate(by),
+            ..*self
+        }
+    }
+
+    /// Translate the rotated rounded rectangle from its current position to a new position by (x, y)
+    /// pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::{Rectangle, RotatedRoundedRectangle, RoundedRectangle};
+    /// # use embedded_graphics::prelude::*;
+    /// let rectangle = Rectangle::new(Point::new(5, 10), Size::new(10, 4));
+    /// let rounded_rectangle = RoundedRectangle::with_equal_corners(rectangle, Size::new(2, 2));
+    /// let mut rotated = RotatedRoundedRectangle::new(rounded_rectangle, 45.0.deg());
+    /// rotated.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(rotated.rounded_rectangle.rectangle.top_left, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.rounded_rectangle.translate_mut(by);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        primitives::{PointsIter, RotatedRectangle},
+    };
+
+    #[test]
+    fn zero_radii_match_rotated_rectangle() {
+        for angle in [0.0, 20.0, 45.0, 110.0] {
+            let rectangle = Rectangle::new(Point::new(2, 3), Size::new(15, 8));
+            let rotated = RotatedRoundedRectangle::new(
+                RoundedRectangle::with_equal_corners(rectangle, Size::zero()),
+                angle.deg(),
+            );
+            let expected = RotatedRectangle::new(rectangle, angle.deg());
+
+            assert_eq!(rotated.bounding_box(), expected.bounding_box());
+
+            for point in expected.bounding_box().offset(2).points() {
+                assert_eq!(
+                    rotated.contains(point),
+                    expected.contains(point),
+                    "{angle} {point:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unrotated_corners() {
+        let rounded_rectangle = RoundedRectangle::new(
+            Rectangle::new(Point::new(0, 0), Size::new(20, 10)),
+            CornerRadii {
+                top_left: Size::new(5, 5),
+                top_right: Size::zero(),
+                bottom_right: Size::new(2, 4),
+                bottom_left: Size::new(1, 1),
+            },
+        );
+        let rotated = RotatedRoundedRectangle::new(rounded_rectangle, 0.0.deg());
+
+        assert_eq!(rotated.bounding_box(), rounded_rectangle.rectangle);
+
+        for point in rounded_rectangle.bounding_box().offset(2).points() {
+            assert_eq!(
+                rotated.contains(point),
+                rounded_rectangle.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unrotated_matches_rounded_rectangle() {
+        let rounded_rectangle = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(3, 4), Size::new(25, 16)),
+            Size::new(6, 4),
+        );
+        let rotated = RotatedRoundedRectangle::new(rounded_rectangle, 0.0.deg());
+
+        for point in rounded_rectangle.bounding_box().offset(2).points() {
+            assert_eq!(
+                rotated.contains(point),
+                rounded_rectangle.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn scanlines_match_contains() {
+        let corners = CornerRadii {
+            top_left: Size::new(8, 3),
+            top_right: Size::new(0, 0),
+            bottom_right: Size::new(4, 4),
+            bottom_left: Size::new(20, 10),
+        };
+
+        for size in [Size::new(30, 14), Size::new(6, 28), Size::new(40, 3)] {
+            for angle in (0..360).step_by(11) {
+                let rotated = RotatedRoundedRectangle::with_center(
+                    Point::new(25, 25),
+                    size,
+                    corners,
+                    (angle as f32).deg(),
+                );
+                let shape = rotated.rotated_shape();
+
+                let area = rotated.bounding_box().offset(2);
+                let columns = area.columns();
+
+                for y in area.rows() {
+                    let scanline = shape.scanline(y, &columns);
+
+                    for x in columns.clone() {
+                        assert_eq!(
+                            scanline.x.contains(&x),
+                            rotated.contains(Point::new(x, y)),
+                            "{size:?} {angle}° ({x}, {y})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_sized() {
+        let rotated = RotatedRoundedRectangle::new(
+            RoundedRectangle::with_equal_corners(
+                Rectangle::new(Point::new(1, 2), Size::zero()),
+                Size::new(2, 2),
+            ),
+            30.0.deg(),
+        );
+
+        assert_eq!(
+            rotated.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero())
+        );
+        assert!(!rotated.contains(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn offset() {
+        let corners = CornerRadii::new(Size::new(2, 3));
+        let rotated = RotatedRoundedRectangle::with_center(
+            Point::new(10, 10),
+            Size::new(8, 6),
+            corners,
+            30.0.deg(),
+        );
+
+        assert_eq!(
+            rotated.offset(2),
+            RotatedRoundedRectangle::with_center(
+                Point::new(10, 10),
+                Size::new(12, 10),
+                CornerRadii::new(Size::new(4, 5)),
+                30.0.deg()
+            )
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::rotation,
+        styled::{StyledDimensions, StyledDrawable},
+        PrimitiveStyle, Rectangle, RotatedRoundedRectangle,
+    },
+};
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for RotatedRoundedRectangle {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        rotation::draw_styled(self, style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for RotatedRoundedRectangle {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        style.stroke_area(self).bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Point, Size},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{
+            ContainsPoint, CornerRadii, PointsIter, Primitive, PrimitiveStyleBuilder,
+            RoundedRectangle, StrokeAlignment,
+        },
+        Drawable,
+    };
+
+    #[test]
+    fn unrotated_matches_rounded_rectangle() {
+        let rounded_rectangle = RoundedRectangle::with_equal_corners(
+            Rectangle::new(Point::new(5, 5), Size::new(30, 18)),
+            Size::new(7, 5),
+        );
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(3)
+                .stroke_alignment(alignment)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            let mut expected = MockDisplay::new();
+            rounded_rectangle
+                .into_styled(style)
+                .draw(&mut expected)
+                .unwrap();
+
+            let mut display = MockDisplay::new();
+            RotatedRoundedRectangle::new(rounded_rectangle, 0.0.deg())
+                .into_styled(style)
+                .draw(&mut display)
+                .unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let rotated = RotatedRoundedRectangle::with_center(
+            Point::new(30, 30),
+            Size::new(36, 16),
+            CornerRadii::new(Size::new(5, 4)),
+            30.0.deg(),
+        );
+
+        for alignment in [
+            StrokeAlignment::Inside,
+            StrokeAlignment::Center,
+            StrokeAlignment::Outside,
+        ] {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(4)
+                .stroke_alignment(alignment)
+                .fill_color(BinaryColor::Off)
+                .build();
+
+            let mut display = MockDisplay::new();
+            rotated.into_styled(style).draw(&mut display).unwrap();
+
+            let stroke_area = style.stroke_area(&rotated);
+            let fill_area = style.fill_area(&rotated);
+
+            for point in display.affected_area().offset(2).points() {
+                let expected = if fill_area.contains(point) {
+                    Some(BinaryColor::Off)
+                } else if stroke_area.contains(point) {
+                    Some(BinaryColor::On)
+                } else {
+                    None
+                };
+
+                assert_eq!(
+                    display.get_pixel(point),
+                    expected,
+                    "{alignment:?} {point:?}"
+                );
+            }
+
+            let styled_bounding_box = rotated.into_styled(style).bounding_box();
+            assert_eq!(
+                styled_bounding_box.intersection(&display.affected_area()),
+                display.affected_area(),
+                "{alignment:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn transparent_style() {
+        let rotated = RotatedRoundedRectangle::with_center(
+            Point::new(10, 10),
+            Size::new(8, 4),
+            CornerRadii::new(Size::new(2, 2)),
+            30.0.deg(),
+        );
+
+        let mut display = MockDisplay::<BinaryColor>::new();
+        rotated
+            .into_styled(PrimitiveStyle::new())
+            .draw(&mut display)
+            .unwrap();
+
+        assert!(display.affected_area().is_zero_sized());
+    }
+}
@@ -1,12 +1,9 @@
 use crate::{
     draw_target::DrawTarget,
-    geometry::angle_consts::ANGLE_90DEG,
-    geometry::{Angle, Dimensions},
+    geometry::Dimensions,
     pixelcolor::PixelColor,
     primitives::{
-        common::{
-            DistanceIterator, LineSide, LinearEquation, PlaneSector, PointType, NORMAL_VECTOR_SCALE,
-        },
+        common::{DistanceIterator, PointType, RadialStroke},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         PrimitiveStyle, Rectangle, Sector,
     },
@@ -20,16 +17,11 @@ use az::SaturatingAs;
 pub struct StyledPixelsIterator<C> {
     iter: DistanceIterator,
 
-    plane_sector: PlaneSector,
+    radial_stroke: RadialStroke,
 
     outer_threshold: u32,
     inner_threshold: u32,
 
-    stroke_threshold_inside: i32,
-    stroke_threshold_outside: i32,
-
-    bevel: Option<(BevelKind, LinearEquation)>,
-
     stroke_color: Option<C>,
     fill_color: Option<C>,
 }
@@ -51,50 +43,14 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
         let outer_threshold = stroke_area_circle.threshold();
         let inner_threshold = fill_area.to_circle().threshold();
 
-        let plane_sector = PlaneSector::new(stroke_area.angle_start, stroke_area.angle_sweep);
-
-        let inside_stroke_width: i32 = style.inside_stroke_width().saturating_as();
-        let outside_stroke_width: i32 = style.outside_stroke_width().saturating_as();
-
-        let stroke_threshold_inside =
-            inside_stroke_width * NORMAL_VECTOR_SCALE * 2 - NORMAL_VECTOR_SCALE;
-        let stroke_threshold_outside =
-            outside_stroke_width * NORMAL_VECTOR_SCALE * 2 + NORMAL_VECTOR_SCALE;
-
-        // TODO: Polylines and sectors should use the same miter limit.
-        let angle_sweep_abs = primitive.angle_sweep.abs();
-        let exterior_bevel = angle_sweep_abs < Angle::from_degrees(55.0);
-        let interior_bevel = angle_sweep_abs > Angle::from_degrees(360.0 - 55.0)
-            && angle_sweep_abs < Angle::from_degrees(360.0);
-
-        let bevel = if exterior_bevel || interior_bevel {
-            let half_sweep = primitive.angle_start
-                + Angle::from_radians(primitive.angle_sweep.to_radians() / 2.0);
-            let threshold = -outside_stroke_width * NORMAL_VECTOR_SCALE * 4;
-
-            if interior_bevel {
-                Some((
-                    BevelKind::Interior,
-                    LinearEquation::with_angle_and_distance(half_sweep + ANGLE_90DEG, threshold),
-                ))
-            } else {
-                Some((
-                    BevelKind::Exterior,
-                    LinearEquation::with_angle_and_distance(half_sweep - ANGLE_90DEG, threshold),
-                ))
-            }
-        } else {
-            None
-        };
+        let radial_stroke =
+            RadialStroke::new(stroke_area.angle_start, stroke_area.angle_sweep, style);
 
         Self {
             iter,
-            plane_sector,
+            radial_stroke,
             outer_threshold,
             inner_threshold,
-            stroke_threshold_inside,
-            stroke_threshold_outside,
-            bevel,
             stroke_color: style.stroke_color,
             fill_color: style.fill_color,
         }
@@ -113,27 +69,11 @@ impl<C: PixelColor> Iterator for StyledPixelsIterator<C> {
                 .find(|(_, _, distance)| *distance < outer_threshold)?;
 
             // Check if point is inside the radial stroke lines or the fill.
-            let mut point_type = match self.plane_sector.point_type(
-                delta,
-                self.stroke_threshold_inside,
-                self.stroke_threshold_outside,
-            ) {
+            let mut point_type = match self.radial_stroke.point_type(delta) {
                 Some(point_type) => point_type,
                 None => continue,
             };
 
-            // Bevel the line join.
-            if point_type == PointType::Stroke {
-                if let Some((kind, equation)) = self.bevel {
-                    if equation.check_side(delta, LineSide::Left) {
-                        match kind {
-                            BevelKind::Interior => point_type = PointType::Fill,
-                            BevelKind::Exterior => continue,
-                        }
-                    }
-                }
-            }
-
             // Add the outer circular stroke.
             if point_type == PointType::Fill && distance >= self.inner_threshold {
                 point_type = PointType::Stroke;
@@ -184,13 +124,6 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Sector {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
-#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
-enum BevelKind {
-    Interior,
-    Exterior,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
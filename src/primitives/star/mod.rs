@@ -0,0 +1,258 @@
+//! The star primitive
+
+use crate::{
+    geometry::{Angle, Dimensions, Point, Size},
+    primitives::{
+        common::polygon,
+        polygon::{transform_vertices, Polygon},
+        ContainsPoint, Primitive, Rectangle,
+    },
+    transform::{Affine2, Transform},
+};
+
+mod styled;
+mod vertices;
+
+pub use vertices::Vertices;
+
+/// Star primitive
+///
+/// A star is a polygon whose vertices alternate between an outer and an inner circle around the
+/// `center` point. The first outer vertex is located at the `rotation` angle, which is measured
+/// clockwise from the positive x axis, and the inner vertices lie halfway between two points.
+/// Stars with fewer than two points aren't drawn.
+///
+/// Vertex coordinates are rounded to the nearest integer and the star is filled using the non-zero
+/// fill rule. Thick strokes use the line join settings of the style and respect the
+/// [`StrokeAlignment`] setting. No memory allocation is required to draw a star.
+///
+/// # Examples
+///
+/// ## Draw a five pointed star
+///
+/// ```rust
+/// use embedded_graphics::{
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     primitives::{LineJoin, PrimitiveStyleBuilder, Star},
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display = MockDisplay::default();
+///
+/// // Star with the top point pointing up, a yellow fill and a white stroke with round joins
+/// let style = PrimitiveStyleBuilder::new()
+///     .stroke_color(Rgb565::WHITE)
+///     .stroke_width(2)
+///     .line_join(LineJoin::Round)
+///     .fill_color(Rgb565::YELLOW)
+///     .build();
+///
+/// Star::new(Point::new(32, 32), 5, 25, 10, -90.0.deg())
+///     .into_styled(style)
+///     .draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`StrokeAlignment`]: crate::primitives::StrokeAlignment
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Star {
+    /// Center point
+    pub center: Point,
+
+    /// Number of points
+    pub points: u32,
+
+    /// Distance between the center and the outer vertices
+    pub outer_radius: u32,
+
+    /// Distance between the center and the inner vertices
+    pub inner_radius: u32,
+
+    /// Angle of the first outer vertex
+    pub rotation: Angle,
+}
+
+impl Star {
+    /// Creates a new star.
+    pub const fn new(
+        center: Point,
+        points: u32,
+        outer_radius: u32,
+        inner_radius: u32,
+        rotation: Angle,
+    ) -> Self {
+        Self {
+            center,
+            points,
+            outer_radius,
+            inner_radius,
+            rotation,
+        }
+    }
+
+    /// Returns an iterator over the vertices of the star.
+    ///
+    /// The iterator returns the outer and inner vertices in alternating order, starting with an
+    /// outer vertex. It is empty if the star has fewer than two points.
+    pub fn vertices(&self) -> Vertices {
+        Vertices::new(self)
+    }
+
+    /// Applies an affine transformation to the star.
+    ///
+    /// Scaling and skewing can't be represented by a `Star`, which is why the transformed
+    /// vertices are written into `buffer` and a [`Polygon`] that borrows them is returned. The
+    /// buffer must be large enough to hold all `self.vertices().len()` vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than the list of vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Star;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::Affine2;
+    ///
+    /// let star = Star::new(Point::new(20, 20), 2, 10, 5, 0.0.deg());
+    ///
+    /// let mut buffer = [Point::zero(); 4];
+    /// let scaled = star.transform_into(&Affine2::scale(2.0, 1.0), &mut buffer);
+    ///
+    /// assert_eq!(
+    ///     scaled.vertices,
+    ///     &[
+    ///         Point::new(60, 20),
+    ///         Point::new(40, 25),
+    ///         Point::new(20, 20),
+    ///         Point::new(40, 15),
+    ///     ]
+    /// );
+    /// ```
+    pub fn transform_into<'b>(&self, affine: &Affine2, buffer: &'b mut [Point]) -> Polygon<'b> {
+        transform_vertices(self.vertices(), affine, buffer)
+    }
+}
+
+impl Primitive for Star {}
+
+impl ContainsPoint for Star {
+    fn contains(&self, point: Point) -> bool {
+        polygon::contains(self.vertices(), point)
+    }
+}
+
+impl Dimensions for Star {
+    fn bounding_box(&self) -> Rectangle {
+        match polygon::vertices_bounding_box(self.vertices()) {
+            Some((min, max)) => Rectangle::with_corners(min, max),
+            None => Rectangle::new(self.center, Size::zero()),
+        }
+    }
+}
+
+impl Transform for Star {
+    /// Translate the star from its current position to a new position by (x, y) pixels,
+    /// returning a new `Star`. For a mutating transform, see `translate_mut`.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Star;
+    /// # use embedded_graphics::prelude::*;
+    /// let star = Star::new(Point::new(5, 10), 5, 10, 4, 0.0.deg());
+    /// let moved = star.translate(Point::new(10, 10));
+    ///
+    /// assert_eq!(moved.center, Point::new(15, 20));
+    /// ```
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            center: self.center + by,
+            ..*self
+        }
+    }
+
+    /// Translate the star from its current position to a new position by (x, y) pixels.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Star;
+    /// # use embedded_graphics::prelude::*;
+    /// let mut star = Star::new(Point::new(5, 10), 5, 10, 4, 0.0.deg());
+    /// star.translate_mut(Point::new(10, 10));
+    ///
+    /// assert_eq!(star.center, Point::new(15, 20));
+    /// ```
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.center += by;
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::AngleUnit,
+        primitives::{PointsIter, RegularPolygon},
+    };
+
+    #[test]
+    fn vertices() {
+        let star = Star::new(Point::new(10, 20), 2, 10, 5, 0.0.deg());
+
+        assert!(star.vertices().eq([
+            Point::new(20, 20),
+            Point::new(10, 25),
+            Point::new(0, 20),
+            Point::new(10, 15),
+        ]));
+        assert_eq!(star.vertices().len(), 4);
+    }
+
+    #[test]
+    fn too_few_points() {
+        for points in 0..2 {
+            let star = Star::new(Point::new(10, 20), points, 10, 5, 0.0.deg());
+
+            assert_eq!(star.vertices().next(), None);
+            assert_eq!(
+                star.bounding_box(),
+                Rectangle::new(Point::new(10, 20), Size::zero())
+            );
+        }
+    }
+
+    #[test]
+    fn outer_vertices_match_regular_polygon() {
+        let star = Star::new(Point::new(10, 20), 5, 10, 4, -90.0.deg());
+        let polygon = RegularPolygon::new(Point::new(10, 20), 10, 5, -90.0.deg());
+
+        assert!(star.vertices().step_by(2).eq(polygon.vertices()));
+    }
+
+    #[test]
+    fn equal_radii_match_regular_polygon() {
+        let star = Star::new(Point::new(20, 20), 4, 12, 12, 0.0.deg());
+        let polygon = RegularPolygon::new(Point::new(20, 20), 12, 8, 0.0.deg());
+
+        assert!(star.vertices().eq(polygon.vertices()));
+    }
+
+    #[test]
+    fn contains() {
+        let star = Star::new(Point::new(20, 20), 5, 15, 6, -90.0.deg());
+        let inner = RegularPolygon::new(Point::new(20, 20), 4, 5, 90.0.deg());
+
+        for point in star.bounding_box().offset(2).points() {
+            if inner.contains(point) {
+                assert!(star.contains(point), "{point:?}");
+            }
+        }
+
+        assert!(star.contains(Point::new(20, 5)));
+        assert!(star.contains(Point::new(20, 8)));
+        assert!(!star.contains(Point::new(14, 10)));
+        assert!(!star.contains(Point::new(20, 33)));
+    }
+}
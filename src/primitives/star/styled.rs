@@ -0,0 +1,144 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    pixelcolor::PixelColor,
+    primitives::{
+        common::polygon::{self, PolygonRenderer},
+        styled::{StyledDimensions, StyledDrawable},
+        PrimitiveStyle, Rectangle, Star,
+    },
+};
+
+impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Star {
+    type Color = C;
+    type Output = ();
+
+    fn draw_styled<D>(
+        &self,
+        style: &PrimitiveStyle<C>,
+        target: &mut D,
+    ) -> Result<Self::Output, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if style.is_transparent() {
+            return Ok(());
+        }
+
+        polygon::draw(self.vertices(), style, target)
+    }
+}
+
+impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Star {
+    fn styled_bounding_box(&self, style: &PrimitiveStyle<C>) -> Rectangle {
+        if self.vertices().len() == 0 {
+            return Rectangle::new(self.center, Size::zero());
+        }
+
+        PolygonRenderer::new(self.vertices(), style).bounding_box(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{AngleUnit, Dimensions, Point},
+        mock_display::MockDisplay,
+        pixelcolor::BinaryColor,
+        primitives::{ContainsPoint, LineJoin, PointsIter, Primitive, PrimitiveStyleBuilder},
+        Drawable,
+    };
+
+    #[test]
+    fn fill_matches_contains() {
+        let star = Star::new(Point::new(20, 20), 5, 18, 7, -90.0.deg());
+
+        let mut display = MockDisplay::new();
+        star.into_styled(PrimitiveStyle::with_fill(BinaryColor::On))
+            .draw(&mut display)
+            .unwrap();
+
+        for point in star.bounding_box().offset(2).points() {
+            assert_eq!(
+                display.get_pixel(point).is_some(),
+                star.contains(point),
+                "{point:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn stroke_and_fill() {
+        let star = Star::new(Point::new(20, 20), 6, 15, 8, 0.0.deg());
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(BinaryColor::On)
+            .stroke_width(1)
+            .fill_color(BinaryColor::Off)
+            .build();
+
+        let mut display = MockDisplay::new();
+        star.into_styled(style).draw(&mut display).unwrap();
+
+        assert_eq!(display.affected_area(), star.bounding_box());
+
+        for vertex in star.vertices() {
+            assert_eq!(
+                display.get_pixel(vertex),
+                Some(BinaryColor::On),
+                "{vertex:?}"
+            );
+        }
+        assert_eq!(display.get_pixel(star.center), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn line_joins() {
+        let star = Star::new(Point::new(32, 32), 5, 20, 8, -90.0.deg());
+
+        let bounding_box = |line_join| {
+            let style = PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(5)
+                .line_join(line_join)
+                .miter_limit(10)
+                .build();
+
+            let mut display = MockDisplay::new();
+            star.into_styled(style).draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                star.into_styled(style).bounding_box(),
+                "{line_join:?}"
+            );
+
+            display.affected_area()
+        };
+
+        let miter = bounding_box(LineJoin::Miter);
+        let round = bounding_box(LineJoin::Round);
+        let bevel = bounding_box(LineJoin::Bevel);
+
+        // Sharp miter joins extend further beyond the outer vertices than round or bevel joins.
+        assert!(miter.top_left.y < round.top_left.y);
+        assert!(round.top_left.y <= bevel.top_left.y);
+        assert!(star.bounding_box().top_left.y > round.top_left.y);
+    }
+
+    #[test]
+    fn too_few_points() {
+        let star = Star::new(Point::new(10, 10), 1, 10, 5, 0.0.deg());
+        let style = PrimitiveStyle::with_fill(BinaryColor::On);
+
+        let mut display = MockDisplay::new();
+        star.into_styled(style).draw(&mut display).unwrap();
+        assert!(display.affected_area().is_zero_sized());
+
+        assert_eq!(
+            star.into_styled(style).bounding_box(),
+            Rectangle::new(Point::new(10, 10), Size::zero())
+        );
+    }
+}
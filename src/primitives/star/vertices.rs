@@ -0,0 +1,63 @@
+use core::f32::consts::TAU;
+
+use crate::{
+    geometry::{Angle, Point},
+    primitives::{common::polygon::polar_point, Star},
+};
+
+/// Iterator over the vertices of a star.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Vertices {
+    star: Star,
+    index: u32,
+    len: u32,
+}
+
+impl Vertices {
+    pub(super) fn new(star: &Star) -> Self {
+        // Stars with fewer than two points are treated as empty.
+        let len = if star.points < 2 {
+            0
+        } else {
+            star.points.saturating_mul(2)
+        };
+
+        Self {
+            star: *star,
+            index: 0,
+            len,
+        }
+    }
+}
+
+impl Iterator for Vertices {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let step = TAU * self.index as f32 / self.len as f32;
+        let angle = self.star.rotation + Angle::from_radians(step);
+
+        let radius = if self.index % 2 == 0 {
+            self.star.outer_radius
+        } else {
+            self.star.inner_radius
+        };
+
+        self.index += 1;
+
+        Some(polar_point(self.star.center, radius, angle))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.len - self.index) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Vertices {}
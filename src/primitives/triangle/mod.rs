@@ -5,10 +5,10 @@ use core::cmp::{max, min, Ordering};
 use crate::{
     geometry::{Dimensions, Point},
     primitives::{
-        common::{LineJoin, LineSide, LinearEquation, Scanline, StrokeOffset},
+        common::{LineJoin, LineSide, LinearEquation, Scanline, ThickStroke},
         ContainsPoint, Line, PointsIter, Primitive, Rectangle,
     },
-    transform::Transform,
+    transform::{Affine2, AffineTransform, Transform},
 };
 
 mod points;
@@ -232,13 +232,13 @@ impl Triangle {
     }
 
     /// Generate a line join for each corner of the triangle.
-    fn joins(&self, stroke_width: u32, stroke_offset: StrokeOffset) -> [LineJoin; 3] {
+    fn joins(&self, stroke: ThickStroke) -> [LineJoin; 3] {
         let [p1, p2, p3] = self.vertices;
 
         [
-            LineJoin::from_points(p3, p1, p2, stroke_width, stroke_offset),
-            LineJoin::from_points(p1, p2, p3, stroke_width, stroke_offset),
-            LineJoin::from_points(p2, p3, p1, stroke_width, stroke_offset),
+            LineJoin::from_points(p3, p1, p2, stroke),
+            LineJoin::from_points(p1, p2, p3, stroke),
+            LineJoin::from_points(p2, p3, p1, stroke),
         ]
     }
 
@@ -246,12 +246,8 @@ impl Triangle {
     /// filled by stroke.
     // PERF: This doesn't need to compute the entire join, much like how `thick_stroke_inset`
     // doesn't
-    pub(in crate::primitives::triangle) fn is_collapsed(
-        &self,
-        stroke_width: u32,
-        stroke_offset: StrokeOffset,
-    ) -> bool {
-        let joins = self.joins(stroke_width, stroke_offset);
+    pub(in crate::primitives::triangle) fn is_collapsed(&self, stroke: ThickStroke) -> bool {
+        let joins = self.joins(stroke);
 
         joins.iter().enumerate().any(|(i, join)| {
             // Quick check: if the join is degenerate, no hole can occur.
@@ -271,7 +267,7 @@ impl Triangle {
                 let end = self.vertices[(i + 2) % 3];
 
                 // Get right side extent (triangle is sorted clockwise, remember)
-                Line::new(start, end).extents(stroke_width, stroke_offset).1
+                Line::new(start, end).extents(stroke.width, stroke.offset).1
             };
 
             // If the inner point is to the left of the opposite side line, the triangle edges self-
@@ -332,9 +328,35 @@ const fn sort_two_yx(p1: Point, p2: Point) -> (Point, Point) {
     }
 }
 
+impl AffineTransform for Triangle {
+    type Output = Self;
+
+    /// Transforms the vertices of the triangle.
+    ///
+    /// ```
+    /// # use embedded_graphics::primitives::Triangle;
+    /// # use embedded_graphics::prelude::*;
+    /// use embedded_graphics::transform::{Affine2, AffineTransform};
+    ///
+    /// let tri = Triangle::new(Point::new(0, 0), Point::new(10, 0), Point::new(0, 5));
+    /// let scaled = tri.transform(&Affine2::scale(2.0, 3.0));
+    ///
+    /// assert_eq!(
+    ///     scaled,
+    ///     Triangle::new(Point::new(0, 0), Point::new(20, 0), Point::new(0, 15))
+    /// );
+    /// ```
+    fn transform(&self, affine: &Affine2) -> Self {
+        Self {
+            vertices: self.vertices.map(|vertex| affine.transform_point(vertex)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::primitives::common::StrokeOffset;
     use crate::{geometry::Size, mock_display::MockDisplay, pixelcolor::BinaryColor};
 
     #[test]
@@ -441,6 +463,6 @@ mod tests {
     fn check_collapsed() {
         let triangle = Triangle::new(Point::new(10, 10), Point::new(30, 20), Point::new(20, 25));
 
-        assert!(triangle.is_collapsed(20, StrokeOffset::None));
+        assert!(triangle.is_collapsed(ThickStroke::new(20, StrokeOffset::None)));
     }
 }
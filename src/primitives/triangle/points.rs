@@ -1,7 +1,7 @@
 use crate::{
     geometry::{Dimensions, Point},
     primitives::{
-        common::{Scanline, StrokeOffset},
+        common::{Scanline, StrokeOffset, ThickStroke},
         triangle::{scanline_iterator::ScanlineIterator, Triangle},
     },
 };
@@ -18,8 +18,7 @@ impl Points {
     pub(in crate::primitives) fn new(triangle: &Triangle) -> Self {
         let scanline_iter = ScanlineIterator::new(
             triangle,
-            0,
-            StrokeOffset::None,
+            ThickStroke::new(0, StrokeOffset::None),
             true,
             &triangle.bounding_box(),
         );
@@ -3,7 +3,7 @@
 use crate::{
     geometry::Point,
     primitives::{
-        common::{LineJoin, PointType, Scanline, StrokeOffset, ThickSegment},
+        common::{LineJoin, PointType, Scanline, StrokeOffset, ThickSegment, ThickStroke},
         Triangle,
     },
 };
@@ -23,32 +23,23 @@ struct LineConfig {
 pub struct ScanlineIntersections {
     lines: LineConfig,
     triangle: Triangle,
-    stroke_width: u32,
-    stroke_offset: StrokeOffset,
+    stroke: ThickStroke,
     has_fill: bool,
     is_collapsed: bool,
 }
 
 impl ScanlineIntersections {
     /// Create a new thick segments iterator.
-    pub fn new(
-        triangle: &Triangle,
-        stroke_width: u32,
-        stroke_offset: StrokeOffset,
-        has_fill: bool,
-        scanline_y: i32,
-    ) -> Self {
+    pub fn new(triangle: &Triangle, stroke: ThickStroke, has_fill: bool, scanline_y: i32) -> Self {
         // Special case: If thick strokes completely fill the triangle interior and the stroke is
         // inside the triangle, the normal triangle shape can be used to detect the intersection,
         // with the line type being marked as Border so, when rendered, the correct color is used.
-        let is_collapsed = triangle.is_collapsed(stroke_width, stroke_offset)
-            && stroke_offset == StrokeOffset::Right;
+        let is_collapsed = triangle.is_collapsed(stroke) && stroke.offset == StrokeOffset::Right;
 
         let mut self_ = Self {
             has_fill,
             triangle: *triangle,
-            stroke_offset,
-            stroke_width,
+            stroke,
             is_collapsed,
             ..Self::empty()
         };
@@ -69,8 +60,7 @@ impl ScanlineIntersections {
             },
             has_fill: false,
             triangle: Triangle::new(Point::zero(), Point::zero(), Point::zero()),
-            stroke_width: 0,
-            stroke_offset: StrokeOffset::None,
+            stroke: ThickStroke::new(0, StrokeOffset::None),
             is_collapsed: false,
         }
     }
@@ -88,7 +78,7 @@ impl ScanlineIntersections {
         let mut right = Scanline::new_empty(scanline_y);
 
         core::iter::from_fn(move || {
-            if self.stroke_width == 0 {
+            if self.stroke.width == 0 {
                 return None;
             }
 
@@ -97,15 +87,13 @@ impl ScanlineIntersections {
                     self.triangle.vertices[idx % 3],
                     self.triangle.vertices[(idx + 1) % 3],
                     self.triangle.vertices[(idx + 2) % 3],
-                    self.stroke_width,
-                    self.stroke_offset,
+                    self.stroke,
                 );
                 let end = LineJoin::from_points(
                     self.triangle.vertices[(idx + 1) % 3],
                     self.triangle.vertices[(idx + 2) % 3],
                     self.triangle.vertices[(idx + 3) % 3],
-                    self.stroke_width,
-                    self.stroke_offset,
+                    self.stroke,
                 );
 
                 idx += 1;
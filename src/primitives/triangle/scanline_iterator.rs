@@ -1,7 +1,7 @@
 //! Scanline iterator.
 
 use crate::primitives::{
-    common::{PointType, Scanline, StrokeOffset},
+    common::{PointType, Scanline, ThickStroke},
     triangle::scanline_intersections::ScanlineIntersections,
     Rectangle, Triangle,
 };
@@ -20,8 +20,7 @@ impl ScanlineIterator {
     /// New.
     pub fn new(
         triangle: &Triangle,
-        stroke_width: u32,
-        stroke_offset: StrokeOffset,
+        stroke: ThickStroke,
         has_fill: bool,
         bounding_box: &Rectangle,
     ) -> Self {
@@ -30,13 +29,7 @@ impl ScanlineIterator {
         let mut rows = bounding_box.rows();
 
         if let Some(scanline_y) = rows.next() {
-            let intersections = ScanlineIntersections::new(
-                &triangle,
-                stroke_width,
-                stroke_offset,
-                has_fill,
-                scanline_y,
-            );
+            let intersections = ScanlineIntersections::new(&triangle, stroke, has_fill, scanline_y);
 
             Self {
                 rows,
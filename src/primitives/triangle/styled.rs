@@ -3,7 +3,7 @@ use crate::{
     geometry::{Dimensions, Point},
     pixelcolor::PixelColor,
     primitives::{
-        common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset},
+        common::{ClosedThickSegmentIter, PointType, Scanline, StrokeOffset, ThickStroke},
         styled::{StyledDimensions, StyledDrawable, StyledPixels},
         triangle::{scanline_iterator::ScanlineIterator, Triangle},
         PrimitiveStyle, Rectangle, StrokeAlignment,
@@ -26,8 +26,7 @@ impl<C: PixelColor> StyledPixelsIterator<C> {
     pub(in crate::primitives) fn new(primitive: &Triangle, style: &PrimitiveStyle<C>) -> Self {
         let mut lines_iter = ScanlineIterator::new(
             primitive,
-            style.stroke_width,
-            StrokeOffset::from(style.stroke_alignment),
+            ThickStroke::from_style(style, StrokeOffset::from(style.stroke_alignment)),
             style.fill_color.is_some(),
             &primitive.styled_bounding_box(style),
         );
@@ -98,8 +97,7 @@ impl<C: PixelColor> StyledDrawable<PrimitiveStyle<C>> for Triangle {
 
         for (line, kind) in ScanlineIterator::new(
             self,
-            style.stroke_width,
-            StrokeOffset::from(style.stroke_alignment),
+            ThickStroke::from_style(style, StrokeOffset::from(style.stroke_alignment)),
             style.fill_color.is_some(),
             &self.styled_bounding_box(style),
         ) {
@@ -132,8 +130,7 @@ impl<C: PixelColor> StyledDimensions<PrimitiveStyle<C>> for Triangle {
 
         let (min, max) = ClosedThickSegmentIter::new(
             &t.vertices,
-            style.stroke_width,
-            StrokeOffset::from(style.stroke_alignment),
+            ThickStroke::from_style(style, StrokeOffset::from(style.stroke_alignment)),
         )
         .fold(
             (Point::new_equal(i32::MAX), Point::new_equal(i32::MIN)),
@@ -158,7 +155,7 @@ mod tests {
         geometry::Point,
         mock_display::MockDisplay,
         pixelcolor::{BinaryColor, Rgb565, Rgb888, RgbColor},
-        primitives::{Line, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
+        primitives::{Line, LineJoin, Primitive, PrimitiveStyleBuilder, StrokeAlignment},
         transform::Transform,
         Drawable,
     };
@@ -323,6 +320,51 @@ mod tests {
         assert_eq!(display.affected_area(), styled.bounding_box());
     }
 
+    #[test]
+    fn round_join() {
+        let triangle = Triangle::new(Point::new(10, 10), Point::new(40, 17), Point::new(17, 35));
+
+        let style = |line_join, stroke_alignment| {
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .fill_color(BinaryColor::Off)
+                .stroke_width(7)
+                .stroke_alignment(stroke_alignment)
+                .line_join(line_join)
+                .build()
+        };
+
+        let styled = triangle.into_styled(style(LineJoin::Round, StrokeAlignment::Center));
+
+        let mut display = MockDisplay::new();
+        styled.draw(&mut display).unwrap();
+        assert_eq!(display.affected_area(), styled.bounding_box());
+
+        let mut bevel = MockDisplay::new();
+        triangle
+            .into_styled(style(LineJoin::Bevel, StrokeAlignment::Center))
+            .draw(&mut bevel)
+            .unwrap();
+        assert_ne!(display, bevel);
+
+        // Round joins are drawn as bevel joins if the stroke isn't centered.
+        for alignment in [StrokeAlignment::Inside, StrokeAlignment::Outside] {
+            let mut display = MockDisplay::new();
+            triangle
+                .into_styled(style(LineJoin::Round, alignment))
+                .draw(&mut display)
+                .unwrap();
+
+            let mut expected = MockDisplay::new();
+            triangle
+                .into_styled(style(LineJoin::Bevel, alignment))
+                .draw(&mut expected)
+                .unwrap();
+
+            display.assert_eq(&expected);
+        }
+    }
+
     #[test]
     fn bounding_box_is_independent_of_colors() {
         let triangle = Triangle::new(Point::new(10, 10), Point::new(30, 20), Point::new(20, 25));
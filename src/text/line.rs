@@ -0,0 +1,141 @@
+//! Helper functions to measure and draw a single line of text.
+
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    primitives::Rectangle,
+    text::{
+        renderer::{TextMetrics, TextRenderer},
+        TextStyle,
+    },
+};
+use az::SaturatingAs;
+
+/// Removes a trailing `'\r'` from a line that was terminated by `"\r\n"`.
+pub(super) fn strip_cr(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Returns the absolute tab width in pixels.
+fn tab_width<S: TextRenderer>(character_style: &S, text_style: &TextStyle) -> u32 {
+    let space_width = character_style
+        .measure_string(" ", Point::zero(), text_style.baseline)
+        .next_position
+        .x;
+
+    text_style
+        .tab_width
+        .to_absolute(space_width.saturating_as())
+}
+
+/// Returns the position of the next tab stop after `x`.
+fn next_tab_stop(x: i32, line_start: i32, tab_width: u32) -> i32 {
+    if tab_width == 0 {
+        return x;
+    }
+
+    let tab_width = tab_width.saturating_as::<i32>();
+    let offset = x.saturating_sub(line_start).max(0);
+
+    line_start.saturating_add((offset / tab_width + 1).saturating_mul(tab_width))
+}
+
+/// Measures a part of a single line of text, which may contain tab characters.
+///
+/// Tab stops are placed relative to `line_start`.
+pub(super) fn measure_line<S: TextRenderer>(
+    character_style: &S,
+    text_style: &TextStyle,
+    line: &str,
+    position: Point,
+    line_start: i32,
+) -> TextMetrics {
+    let baseline = text_style.baseline;
+
+    let mut segments = line.split('\t');
+    let first = segments.next().unwrap_or_default();
+    let mut metrics = character_style.measure_string(first, position, baseline);
+
+    let tab_width = tab_width(character_style, text_style);
+    let mut min_max = None;
+    update_min_max(&mut min_max, &metrics);
+
+    for segment in segments {
+        let start = metrics.next_position;
+        let end_x = next_tab_stop(start.x, line_start, tab_width);
+
+        let whitespace = character_style.measure_string(" ", start, baseline);
+        let whitespace = TextMetrics {
+            bounding_box: Rectangle::new(
+                whitespace.bounding_box.top_left,
+                Size::new(
+                    (end_x - start.x).saturating_as(),
+                    whitespace.bounding_box.size.height,
+                ),
+            ),
+            next_position: Point::new(end_x, start.y),
+        };
+        update_min_max(&mut min_max, &whitespace);
+
+        metrics = character_style.measure_string(segment, whitespace.next_position, baseline);
+        update_min_max(&mut min_max, &metrics);
+    }
+
+    let bounding_box = if let Some((min, max)) = min_max {
+        Rectangle::with_corners(min, max)
+    } else {
+        Rectangle::new(metrics.bounding_box.top_left, Size::zero())
+    };
+
+    TextMetrics {
+        bounding_box,
+        next_position: metrics.next_position,
+    }
+}
+
+/// Draws a part of a single line of text, which may contain tab characters.
+///
+/// Tab stops are placed relative to `line_start`.
+pub(super) fn draw_line<S, D>(
+    character_style: &S,
+    text_style: &TextStyle,
+    line: &str,
+    position: Point,
+    line_start: i32,
+    target: &mut D,
+) -> Result<Point, D::Error>
+where
+    S: TextRenderer,
+    D: DrawTarget<Color = S::Color>,
+{
+    let baseline = text_style.baseline;
+
+    let mut segments = line.split('\t');
+    let first = segments.next().unwrap_or_default();
+    let mut next_position = character_style.draw_string(first, position, baseline, target)?;
+
+    let tab_width = tab_width(character_style, text_style);
+
+    for segment in segments {
+        let end_x = next_tab_stop(next_position.x, line_start, tab_width);
+        let width = (end_x - next_position.x).saturating_as();
+
+        next_position = character_style.draw_whitespace(width, next_position, baseline, target)?;
+        next_position = character_style.draw_string(segment, next_position, baseline, target)?;
+    }
+
+    Ok(next_position)
+}
+
+pub(super) fn update_min_max(min_max: &mut Option<(Point, Point)>, metrics: &TextMetrics) {
+    if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
+        if let Some((min, max)) = min_max {
+            min.x = min.x.min(metrics.bounding_box.top_left.x);
+            min.y = min.y.min(metrics.bounding_box.top_left.y);
+            max.x = max.x.max(bottom_right.x);
+            max.y = max.y.max(bottom_right.y);
+        } else {
+            *min_max = Some((metrics.bounding_box.top_left, bottom_right));
+        }
+    }
+}
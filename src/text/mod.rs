@@ -37,6 +37,9 @@
 //! If the text contains multiple lines only the first line will be vertically aligned based on the
 //! baseline setting. All following lines will be spaced relative to the first line, according to the [`line_height`] setting.
 //!
+//! Tab characters (`\t`) advance the text position to the next tab stop. The distance between tab
+//! stops is set by the [`tab_width`] setting, which defaults to the width of four spaces.
+//!
 //! # Examples
 //!
 //! ## Draw basic text
@@ -153,23 +156,62 @@
 //! # Ok::<(), core::convert::Infallible>(())
 //! ```
 //!
+//! ## Draw rich text
+//!
+//! Texts that use different character styles on multiple lines can be drawn using the
+//! [`RichText`] drawable. Unlike chained [`Text`] drawables, [`RichText`] applies the text style
+//! settings, like the alignment, to each line as a whole.
+//!
+//! ```
+//! use embedded_graphics::{
+//!     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+//!     pixelcolor::Rgb565,
+//!     prelude::*,
+//!     text::{Alignment, RichText},
+//! };
+//! # use embedded_graphics::mock_display::MockDisplay;
+//! # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+//! # display.set_allow_out_of_bounds_drawing(true);
+//!
+//! // Create a normal and a highlighted character style.
+//! let normal_style = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+//! let highlight_style = MonoTextStyle::new(&FONT_6X10, Rgb565::RED);
+//!
+//! // Draw centered text with a highlighted word.
+//! RichText::with_alignment(
+//!     &[
+//!         ("Press ", normal_style),
+//!         ("OK", highlight_style),
+//!         ("\nto continue", normal_style),
+//!     ],
+//!     Point::new(32, 20),
+//!     Alignment::Center,
+//! )
+//! .draw(&mut display)?;
+//! # Ok::<(), core::convert::Infallible>(())
+//! ```
+//!
 //! [`with_alignment`]: Text::with_alignment()
 //! [`with_baseline`]: Text::with_baseline()
 //! [`with_text_style`]: Text::with_text_style()
 //! [`alignment`]: TextStyle::alignment
 //! [`baseline`]: TextStyle::baseline
 //! [`line_height`]: TextStyle::line_height
+//! [`tab_width`]: TextStyle::tab_width
 //! [`mono_font`]: super::mono_font
 //! [`MonoTextStyle`]: super::mono_font::MonoTextStyle
 //! [`renderer` module]: renderer
 //! [external crates list]: super#additional-functions-provided-by-external-crates
 
+mod line;
 pub mod renderer;
+mod rich_text;
 #[allow(clippy::module_inception)]
 mod text;
 mod text_style;
 
 use embedded_graphics_core::prelude::PixelColor;
+pub use rich_text::RichText;
 pub use text::Text;
 pub use text_style::{TextStyle, TextStyleBuilder};
 
@@ -275,6 +317,45 @@ impl Default for LineHeight {
     }
 }
 
+/// Tab width.
+///
+/// Tab characters (`\t`) in a text advance the position to the next tab stop. Tab stops are
+/// placed at multiples of the tab width, measured from the start of the current line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub enum TabWidth {
+    /// Absolute tab width in pixels.
+    Pixels(u32),
+
+    /// Tab width as a multiple of the width of a space character.
+    Spaces(u32),
+}
+
+impl TabWidth {
+    /// Converts the tab width to an absolute pixel distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::text::TabWidth;
+    ///
+    /// let tab_width = TabWidth::Spaces(4);
+    /// assert_eq!(tab_width.to_absolute(6), 24);
+    /// ```
+    pub const fn to_absolute(self, space_width: u32) -> u32 {
+        match self {
+            Self::Pixels(px) => px,
+            Self::Spaces(spaces) => space_width.saturating_mul(spaces),
+        }
+    }
+}
+
+impl Default for TabWidth {
+    fn default() -> Self {
+        Self::Spaces(4)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,4 +385,11 @@ mod tests {
         assert_eq!(LineHeight::Percent(100).to_absolute(20), 20);
         assert_eq!(LineHeight::Percent(150).to_absolute(20), 30);
     }
+
+    #[test]
+    fn tab_width_to_absolute() {
+        assert_eq!(TabWidth::Pixels(10).to_absolute(6), 10);
+        assert_eq!(TabWidth::Spaces(4).to_absolute(6), 24);
+        assert_eq!(TabWidth::Spaces(0).to_absolute(6), 0);
+    }
 }
@@ -0,0 +1,515 @@
+use crate::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    primitives::Rectangle,
+    text::{
+        line::{draw_line, measure_line, strip_cr, update_min_max},
+        renderer::TextRenderer,
+        Alignment, Baseline, TextStyle, TextStyleBuilder,
+    },
+    transform::Transform,
+    Drawable,
+};
+use az::SaturatingAs;
+
+/// Rich text drawable.
+///
+/// A rich text drawable can be used to draw text which uses different character styles for parts
+/// of the text. The text is defined by a slice of spans, each consisting of a string and the
+/// character style that is used to draw it.
+///
+/// The spans are laid out consecutively and can contain line breaks. All spans on a line share the
+/// same baseline, which is set by the [`baseline`] setting of the [`TextStyle`]. The
+/// [`alignment`] setting is applied to each line as a whole.
+///
+/// The distance between two lines is calculated by applying the [`line_height`] setting to the
+/// largest default line height of all character styles that are used on the lower line.
+///
+/// See the [module-level documentation](super) for more information about text drawables.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     mono_font::{ascii::FONT_6X10, MonoTextStyle},
+///     pixelcolor::Rgb565,
+///     prelude::*,
+///     text::RichText,
+/// };
+/// # use embedded_graphics::mock_display::MockDisplay;
+/// # let mut display: MockDisplay<Rgb565> = MockDisplay::default();
+/// # display.set_allow_out_of_bounds_drawing(true);
+///
+/// let normal = MonoTextStyle::new(&FONT_6X10, Rgb565::WHITE);
+/// let highlight = MonoTextStyle::new(&FONT_6X10, Rgb565::RED);
+///
+/// let spans = [
+///     ("Status: ", normal),
+///     ("ERROR", highlight),
+///     ("\nSee log for details", normal),
+/// ];
+///
+/// RichText::new(&spans, Point::new(10, 20)).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`baseline`]: TextStyle::baseline
+/// [`alignment`]: TextStyle::alignment
+/// [`line_height`]: TextStyle::line_height
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct RichText<'a, S> {
+    /// The text spans.
+    pub spans: &'a [(&'a str, S)],
+
+    /// The position.
+    pub position: Point,
+
+    /// The text style.
+    pub text_style: TextStyle,
+}
+
+impl<'a, S> RichText<'a, S> {
+    /// Creates a rich text drawable with the default text style.
+    pub const fn new(spans: &'a [(&'a str, S)], position: Point) -> Self {
+        Self {
+            spans,
+            position,
+            text_style: TextStyleBuilder::new().build(),
+        }
+    }
+
+    /// Creates a rich text drawable with the given text style.
+    pub const fn with_text_style(
+        spans: &'a [(&'a str, S)],
+        position: Point,
+        text_style: TextStyle,
+    ) -> Self {
+        Self {
+            spans,
+            position,
+            text_style,
+        }
+    }
+
+    /// Creates a rich text drawable with the given baseline.
+    pub const fn with_baseline(
+        spans: &'a [(&'a str, S)],
+        position: Point,
+        baseline: Baseline,
+    ) -> Self {
+        Self {
+            spans,
+            position,
+            text_style: TextStyle::with_baseline(baseline),
+        }
+    }
+
+    /// Creates a rich text drawable with the given alignment.
+    pub const fn with_alignment(
+        spans: &'a [(&'a str, S)],
+        position: Point,
+        alignment: Alignment,
+    ) -> Self {
+        Self {
+            spans,
+            position,
+            text_style: TextStyle::with_alignment(alignment),
+        }
+    }
+}
+
+impl<S> Transform for RichText<'_, S> {
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            spans: self.spans,
+            position: self.position + by,
+            text_style: self.text_style,
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
+
+impl<S: TextRenderer> RichText<'_, S> {
+    /// Returns an iterator over the lines of text.
+    ///
+    /// Each line is returned as an iterator over the parts of the spans that are part of the line,
+    /// together with the start position of the line.
+    fn lines(&self) -> impl Iterator<Item = (LineSpans<'_, S>, Point)> {
+        let mut next_line = Some(SpanPosition::default());
+        let mut position = self.position;
+        let mut first_line = true;
+
+        core::iter::from_fn(move || {
+            let line = LineSpans::new(self.spans, next_line?);
+            next_line = line.clone().next_line();
+
+            if first_line {
+                first_line = false;
+            } else {
+                let line_height = line
+                    .clone()
+                    .map(|(_, character_style)| character_style.line_height())
+                    .max()
+                    .unwrap_or_default();
+
+                position.y += self
+                    .text_style
+                    .line_height
+                    .to_absolute(line_height)
+                    .saturating_as::<i32>();
+            }
+
+            let p = match self.text_style.alignment {
+                Alignment::Left => position,
+                Alignment::Right => {
+                    let width = self.line_width(line.clone());
+                    position - (width - Point::new(1, 0))
+                }
+                Alignment::Center => {
+                    let width = self.line_width(line.clone());
+                    position - (width - Point::new(1, 0)) / 2
+                }
+            };
+
+            Some((line, p))
+        })
+    }
+
+    /// Returns the offset of the next position after the line, relative to the line start.
+    fn line_width(&self, line: LineSpans<'_, S>) -> Point {
+        line.fold(Point::zero(), |position, (text, character_style)| {
+            measure_line(character_style, &self.text_style, text, position, 0).next_position
+        })
+    }
+}
+
+impl<S: TextRenderer> Drawable for RichText<'_, S> {
+    type Color = S::Color;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let mut next_position = self.position;
+
+        for (line, position) in self.lines() {
+            next_position = position;
+
+            for (text, character_style) in line {
+                next_position = draw_line(
+                    character_style,
+                    &self.text_style,
+                    text,
+                    next_position,
+                    position.x,
+                    target,
+                )?;
+            }
+        }
+
+        Ok(next_position)
+    }
+}
+
+impl<S: TextRenderer> Dimensions for RichText<'_, S> {
+    fn bounding_box(&self) -> Rectangle {
+        let mut min_max: Option<(Point, Point)> = None;
+
+        for (line, position) in self.lines() {
+            let mut next_position = position;
+
+            for (text, character_style) in line {
+                let metrics = measure_line(
+                    character_style,
+                    &self.text_style,
+                    text,
+                    next_position,
+                    position.x,
+                );
+                update_min_max(&mut min_max, &metrics);
+
+                next_position = metrics.next_position;
+            }
+        }
+
+        if let Some((min, max)) = min_max {
+            Rectangle::with_corners(min, max)
+        } else {
+            Rectangle::new(self.position, Size::zero())
+        }
+    }
+}
+
+/// Position inside the spans of a rich text.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct SpanPosition {
+    /// Span index.
+    span: usize,
+
+    /// Byte offset inside the span text.
+    offset: usize,
+}
+
+/// Iterator over the parts of the spans that make up a single line of text.
+#[derive(Debug)]
+struct LineSpans<'a, S> {
+    spans: &'a [(&'a str, S)],
+    position: SpanPosition,
+    end_of_line: bool,
+}
+
+// `Clone` is implemented manually to not require `S: Clone`.
+impl<S> Clone for LineSpans<'_, S> {
+    fn clone(&self) -> Self {
+        Self {
+            spans: self.spans,
+            position: self.position,
+            end_of_line: self.end_of_line,
+        }
+    }
+}
+
+impl<'a, S> LineSpans<'a, S> {
+    const fn new(spans: &'a [(&'a str, S)], position: SpanPosition) -> Self {
+        Self {
+            spans,
+            position,
+            end_of_line: false,
+        }
+    }
+
+    /// Consumes the iterator and returns the start position of the next line.
+    ///
+    /// Returns `None` if this is the last line.
+    fn next_line(mut self) -> Option<SpanPosition> {
+        while self.next().is_some() {}
+
+        if self.position.span < self.spans.len() {
+            Some(self.position)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, S> Iterator for LineSpans<'a, S> {
+    type Item = (&'a str, &'a S);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.end_of_line {
+            return None;
+        }
+
+        let (text, character_style) = self.spans.get(self.position.span)?;
+        let remaining = &text[self.position.offset..];
+
+        if let Some(index) = remaining.find('\n') {
+            self.position.offset += index + 1;
+            self.end_of_line = true;
+
+            Some((strip_cr(&remaining[..index]), character_style))
+        } else {
+            self.position = SpanPosition {
+                span: self.position.span + 1,
+                offset: 0,
+            };
+
+            Some((remaining, character_style))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mock_display::MockDisplay,
+        mono_font::{
+            ascii::{FONT_6X13, FONT_6X9},
+            MonoTextStyle, MonoTextStyleBuilder,
+        },
+        pixelcolor::BinaryColor,
+        text::{LineHeight, Text},
+    };
+
+    #[test]
+    fn constructor() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let spans = [("Hello", character_style)];
+
+        let text = RichText::new(&spans, Point::new(10, 11));
+
+        assert_eq!(
+            text,
+            RichText {
+                spans: &spans,
+                position: Point::new(10, 11),
+                text_style: TextStyle::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn spans_share_baseline() {
+        let style1 = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let style2 = MonoTextStyle::new(&FONT_6X13, BinaryColor::Off);
+
+        let mut display = MockDisplay::new();
+        let next = RichText::new(&[("AB", style1), ("C", style2)], Point::new(0, 8))
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(next, Point::new(18, 8));
+
+        display.assert_pattern(&[
+            "             ...  ",
+            "            .   . ",
+            "            .     ",
+            "  #   ####  .     ",
+            " # #  #   # .     ",
+            "#   # ####  .     ",
+            "##### #   # .     ",
+            "#   # #   # .   . ",
+            "#   # ####   ...  ",
+        ]);
+    }
+
+    #[test]
+    fn multiline_spans() {
+        let style1 = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let style2 = MonoTextStyle::new(&FONT_6X9, BinaryColor::Off);
+
+        let mut rich_display = MockDisplay::new();
+        RichText::with_baseline(
+            &[("A", style1), ("B\r\nC", style2), ("D\n", style1)],
+            Point::zero(),
+            Baseline::Top,
+        )
+        .draw(&mut rich_display)
+        .unwrap();
+
+        let mut expected_display = MockDisplay::new();
+        for (text, position, style) in [
+            ("A", Point::new(0, 0), style1),
+            ("B", Point::new(6, 0), style2),
+            ("C", Point::new(0, 9), style2),
+            ("D", Point::new(6, 9), style1),
+        ] {
+            Text::with_baseline(text, position, style, Baseline::Top)
+                .draw(&mut expected_display)
+                .unwrap();
+        }
+
+        rich_display.assert_eq(&expected_display);
+    }
+
+    #[test]
+    fn line_height_of_largest_style() {
+        let small = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let large = MonoTextStyle::new(&FONT_6X13, BinaryColor::On);
+
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .line_height(LineHeight::Percent(150))
+            .build();
+
+        let spans = [("A\nB", small), ("C\nD", large), ("\nE", small)];
+        let text = RichText::with_text_style(&spans, Point::zero(), text_style);
+
+        let mut display = MockDisplay::new();
+        let next = text.draw(&mut display).unwrap();
+
+        assert_eq!(next, Point::new(6, 19 + 19 + 13));
+    }
+
+    #[test]
+    fn empty() {
+        let text = RichText::<MonoTextStyle<BinaryColor>>::new(&[], Point::new(1, 2));
+
+        let mut display = MockDisplay::new();
+        assert_eq!(text.draw(&mut display).unwrap(), Point::new(1, 2));
+        display.assert_eq(&MockDisplay::new());
+
+        assert_eq!(
+            text.bounding_box(),
+            Rectangle::new(Point::new(1, 2), Size::zero())
+        );
+    }
+
+    #[test]
+    fn alignment_and_bounding_box() {
+        let style1 = MonoTextStyleBuilder::new()
+            .font(&FONT_6X9)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build();
+        let style2 = MonoTextStyleBuilder::new()
+            .font(&FONT_6X13)
+            .text_color(BinaryColor::Off)
+            .background_color(BinaryColor::On)
+            .build();
+
+        for &baseline in &[
+            Baseline::Top,
+            Baseline::Middle,
+            Baseline::Bottom,
+            Baseline::Alphabetic,
+        ] {
+            for &alignment in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+                let text_style = TextStyleBuilder::new()
+                    .alignment(alignment)
+                    .baseline(baseline)
+                    .build();
+
+                let spans = [("1\n2", style1), ("34\n", style2), ("5", style1)];
+                let text = RichText::with_text_style(&spans, Point::new(20, 20), text_style);
+
+                let mut display = MockDisplay::new();
+                text.draw(&mut display).unwrap();
+
+                assert_eq!(
+                    display.affected_area(),
+                    text.bounding_box(),
+                    "alignment: {alignment:?}, baseline: {baseline:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn alignment_right() {
+        let style1 = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+        let style2 = MonoTextStyle::new(&FONT_6X9, BinaryColor::Off);
+
+        let mut rich_display = MockDisplay::new();
+        RichText::with_text_style(
+            &[("A", style1), ("B\nC", style2)],
+            Point::new(17, 0),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(&mut rich_display)
+        .unwrap();
+
+        let mut expected_display = MockDisplay::new();
+        for (text, position, style) in [
+            ("A", Point::new(6, 0), style1),
+            ("B", Point::new(12, 0), style2),
+            ("C", Point::new(12, 9), style2),
+        ] {
+            Text::with_baseline(text, position, style, Baseline::Top)
+                .draw(&mut expected_display)
+                .unwrap();
+        }
+
+        rich_display.assert_eq(&expected_display);
+    }
+}
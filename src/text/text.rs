@@ -3,7 +3,8 @@ use crate::{
     geometry::{Dimensions, Point, Size},
     primitives::Rectangle,
     text::{
-        renderer::{TextMetrics, TextRenderer},
+        line::{draw_line, measure_line, strip_cr, update_min_max},
+        renderer::TextRenderer,
         Alignment, Baseline, TextStyle,
     },
     transform::Transform,
@@ -117,21 +118,27 @@ impl<S: TextRenderer> Text<'_, S> {
         let mut position = self.position;
 
         self.text.split('\n').map(move |line| {
+            let line = strip_cr(line);
+
             let p = match self.text_style.alignment {
                 Alignment::Left => position,
                 Alignment::Right => {
-                    let metrics = self.character_style.measure_string(
+                    let metrics = measure_line(
+                        &self.character_style,
+                        &self.text_style,
                         line,
                         Point::zero(),
-                        self.text_style.baseline,
+                        0,
                     );
                     position - (metrics.next_position - Point::new(1, 0))
                 }
                 Alignment::Center => {
-                    let metrics = self.character_style.measure_string(
+                    let metrics = measure_line(
+                        &self.character_style,
+                        &self.text_style,
                         line,
                         Point::zero(),
-                        self.text_style.baseline,
+                        0,
                     );
                     position - (metrics.next_position - Point::new(1, 0)) / 2
                 }
@@ -139,13 +146,7 @@ impl<S: TextRenderer> Text<'_, S> {
 
             position.y += self.line_height();
 
-            // remove trailing '\r' for '\r\n' line endings
-            let len = line.len();
-            if len > 0 && line.as_bytes()[len - 1] == b'\r' {
-                (&line[0..len - 1], p)
-            } else {
-                (line, p)
-            }
+            (line, p)
         })
     }
 }
@@ -161,10 +162,12 @@ impl<S: TextRenderer> Drawable for Text<'_, S> {
         let mut next_position = self.position;
 
         for (line, position) in self.lines() {
-            next_position = self.character_style.draw_string(
+            next_position = draw_line(
+                &self.character_style,
+                &self.text_style,
                 line,
                 position,
-                self.text_style.baseline,
+                position.x,
                 target,
             )?;
         }
@@ -173,27 +176,18 @@ impl<S: TextRenderer> Drawable for Text<'_, S> {
     }
 }
 
-fn update_min_max(min_max: &mut Option<(Point, Point)>, metrics: &TextMetrics) {
-    if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
-        if let Some((min, max)) = min_max {
-            min.x = min.x.min(metrics.bounding_box.top_left.x);
-            min.y = min.y.min(metrics.bounding_box.top_left.y);
-            max.x = max.x.max(bottom_right.x);
-            max.y = max.y.max(bottom_right.y);
-        } else {
-            *min_max = Some((metrics.bounding_box.top_left, bottom_right));
-        }
-    }
-}
-
 impl<S: TextRenderer> Dimensions for Text<'_, S> {
     fn bounding_box(&self) -> Rectangle {
         let mut min_max: Option<(Point, Point)> = None;
 
         for (line, position) in self.lines() {
-            let metrics =
-                self.character_style
-                    .measure_string(line, position, self.text_style.baseline);
+            let metrics = measure_line(
+                &self.character_style,
+                &self.text_style,
+                line,
+                position,
+                position.x,
+            );
             update_min_max(&mut min_max, &metrics);
         }
 
@@ -218,7 +212,7 @@ mod tests {
         },
         pixelcolor::BinaryColor,
         primitives::{Primitive, PrimitiveStyle},
-        text::{Alignment, Baseline, LineHeight, TextStyleBuilder},
+        text::{Alignment, Baseline, LineHeight, TabWidth, TextStyleBuilder},
     };
 
     const HELLO_WORLD: &str = "Hello World!";
@@ -777,4 +771,109 @@ mod tests {
             "####    ##  ",
         ]);
     }
+
+    #[test]
+    fn tab() {
+        assert_text_from_pattern(
+            "A\tB",
+            &FONT_6X9,
+            &[
+                "                              ",
+                "  #                     ####  ",
+                " # #                    #   # ",
+                "#   #                   ####  ",
+                "#####                   #   # ",
+                "#   #                   #   # ",
+                "#   #                   ####  ",
+            ],
+        );
+    }
+
+    #[test]
+    fn tab_stops_are_relative_to_line_start() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .tab_width(TabWidth::Spaces(2))
+            .build();
+
+        let mut tab_display = MockDisplay::new();
+        Text::with_text_style("A\tB\nAB\tC", Point::new(3, 0), character_style, text_style)
+            .draw(&mut tab_display)
+            .unwrap();
+
+        let mut expected_display = MockDisplay::new();
+        for (text, position) in [
+            ("A", Point::new(3, 0)),
+            ("B", Point::new(3 + 12, 0)),
+            ("AB", Point::new(3, 9)),
+            ("C", Point::new(3 + 24, 9)),
+        ] {
+            Text::with_baseline(text, position, character_style, Baseline::Top)
+                .draw(&mut expected_display)
+                .unwrap();
+        }
+
+        tab_display.assert_eq(&expected_display);
+    }
+
+    #[test]
+    fn tab_width_pixels() {
+        let character_style = MonoTextStyle::new(&FONT_6X9, BinaryColor::On);
+
+        let text_style = TextStyleBuilder::new()
+            .baseline(Baseline::Top)
+            .tab_width(TabWidth::Pixels(10))
+            .build();
+
+        let text = Text::with_text_style("A\t\tB", Point::zero(), character_style, text_style);
+
+        let mut tab_display = MockDisplay::new();
+        let next = text.draw(&mut tab_display).unwrap();
+        assert_eq!(next, Point::new(26, 0));
+
+        let mut expected_display = MockDisplay::new();
+        Text::with_baseline("B", Point::new(20, 0), character_style, Baseline::Top)
+            .draw(&mut expected_display)
+            .unwrap();
+        Text::with_baseline("A", Point::zero(), character_style, Baseline::Top)
+            .draw(&mut expected_display)
+            .unwrap();
+
+        tab_display.assert_eq(&expected_display);
+    }
+
+    #[test]
+    fn tab_bounding_box() {
+        for &alignment in &[Alignment::Left, Alignment::Center, Alignment::Right] {
+            let character_style = MonoTextStyleBuilder::new()
+                .font(&FONT_6X9)
+                .text_color(BinaryColor::On)
+                .background_color(BinaryColor::Off)
+                .build();
+
+            let text_style = TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(Baseline::Top)
+                .tab_width(TabWidth::Spaces(2))
+                .build();
+
+            let text = Text::with_text_style(
+                "1\t2\n\t34",
+                Point::new(30, 20),
+                character_style,
+                text_style,
+            );
+
+            let mut display = MockDisplay::new();
+            text.draw(&mut display).unwrap();
+
+            assert_eq!(
+                display.affected_area(),
+                text.bounding_box(),
+                "alignment: {alignment:?}"
+            );
+        }
+    }
 }
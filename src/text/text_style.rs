@@ -1,4 +1,4 @@
-use crate::text::{Alignment, Baseline, LineHeight};
+use crate::text::{Alignment, Baseline, LineHeight, TabWidth};
 
 /// Text style.
 ///
@@ -19,6 +19,9 @@ pub struct TextStyle {
 
     /// Line height.
     pub line_height: LineHeight,
+
+    /// Tab width.
+    pub tab_width: TabWidth,
 }
 
 impl TextStyle {
@@ -60,6 +63,7 @@ impl TextStyleBuilder {
                 alignment: Alignment::Left,
                 baseline: Baseline::Alphabetic,
                 line_height: LineHeight::Percent(100),
+                tab_width: TabWidth::Spaces(4),
             },
         }
     }
@@ -87,6 +91,13 @@ impl TextStyleBuilder {
         self
     }
 
+    /// Sets the tab width.
+    pub const fn tab_width(mut self, tab_width: TabWidth) -> Self {
+        self.style.tab_width = tab_width;
+
+        self
+    }
+
     /// Builds the text style.
     pub const fn build(self) -> TextStyle {
         self.style
@@ -109,11 +120,13 @@ mod tests {
             .alignment(Alignment::Right)
             .baseline(Baseline::Top)
             .line_height(LineHeight::Pixels(123))
+            .tab_width(TabWidth::Pixels(32))
             .build();
 
         assert_eq!(text_style.alignment, Alignment::Right);
         assert_eq!(text_style.baseline, Baseline::Top);
         assert_eq!(text_style.line_height, LineHeight::Pixels(123));
+        assert_eq!(text_style.tab_width, TabWidth::Pixels(32));
     }
 
     #[test]
@@ -123,5 +136,6 @@ mod tests {
         assert_eq!(text_style.alignment, Alignment::Left);
         assert_eq!(text_style.baseline, Baseline::Alphabetic);
         assert_eq!(text_style.line_height, LineHeight::Percent(100));
+        assert_eq!(text_style.tab_width, TabWidth::Spaces(4));
     }
 }
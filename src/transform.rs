@@ -1,6 +1,6 @@
 //! Transformations for graphics objects
 
-use crate::geometry::Point;
+use crate::geometry::{Angle, Point, Real, Trigonometry};
 
 /// Transform operations
 pub trait Transform {
@@ -11,3 +11,443 @@ pub trait Transform {
     /// in place
     fn translate_mut(&mut self, by: Point) -> &mut Self;
 }
+
+/// Affine transform operations
+///
+/// This trait is implemented for shapes whose transformed version can be represented by a
+/// primitive. Shapes which are defined by a fixed number of points, like [`Line`], [`Triangle`]
+/// and the Bézier curves, transform each point. Circles and ellipses are converted into a
+/// [`RotatedEllipse`], because scaling and skewing can change their aspect ratio.
+///
+/// Shapes which borrow their points, like [`Polyline`], [`Polygon`] and [`Path`], can't return
+/// a transformed copy without a buffer. They provide a `transform_into` method instead, which
+/// writes the result into a caller provided buffer. [`RegularPolygon`], [`Star`] and
+/// [`RotatedRectangle`] can't represent the result of an arbitrary transformation and are
+/// converted into a [`Polygon`] by their `transform_into` methods. A [`Rectangle`] can be
+/// transformed by converting it into a `RotatedRectangle` with a rotation of 0° first.
+///
+/// [`RoundedRectangle`] and [`RotatedRoundedRectangle`] aren't supported, because skewed or
+/// unevenly scaled corners can't be represented by any of the existing primitives.
+///
+/// [`Line`]: crate::primitives::Line
+/// [`Path`]: crate::primitives::Path
+/// [`Polygon`]: crate::primitives::Polygon
+/// [`Polyline`]: crate::primitives::Polyline
+/// [`Rectangle`]: crate::primitives::Rectangle
+/// [`RegularPolygon`]: crate::primitives::RegularPolygon
+/// [`RotatedRectangle`]: crate::primitives::RotatedRectangle
+/// [`RotatedRoundedRectangle`]: crate::primitives::RotatedRoundedRectangle
+/// [`RoundedRectangle`]: crate::primitives::RoundedRectangle
+/// [`Star`]: crate::primitives::Star
+/// [`Triangle`]: crate::primitives::Triangle
+/// [`RotatedEllipse`]: crate::primitives::RotatedEllipse
+pub trait AffineTransform {
+    /// The type of the transformed object.
+    type Output;
+
+    /// Applies an affine transformation to the object.
+    fn transform(&self, affine: &Affine2) -> Self::Output;
+}
+
+/// 2D affine transformation.
+///
+/// An affine transformation is a combination of translation, scaling, rotation, skewing and
+/// mirroring. Transformations can be combined with [`then`] and applied around an arbitrary
+/// point with [`around`]. The result of a transformation is rounded to the nearest integer
+/// coordinate.
+///
+/// Angles follow the same convention as the rest of the library: positive angles rotate
+/// clockwise, because the y axis points down.
+///
+/// # Examples
+///
+/// ## Rotate a triangle around its centroid
+///
+/// ```
+/// use embedded_graphics::{
+///     prelude::*,
+///     primitives::Triangle,
+///     transform::{Affine2, AffineTransform},
+/// };
+///
+/// let triangle = Triangle::new(Point::new(10, 10), Point::new(20, 10), Point::new(10, 20));
+///
+/// let rotate = Affine2::rotation(90.0.deg()).around(Point::new(10, 10));
+///
+/// assert_eq!(
+///     triangle.transform(&rotate),
+///     Triangle::new(Point::new(10, 10), Point::new(10, 20), Point::new(0, 10))
+/// );
+/// ```
+///
+/// ## Combine multiple transformations
+///
+/// ```
+/// use embedded_graphics::{prelude::*, transform::Affine2};
+///
+/// let affine = Affine2::scale(2.0, 2.0)
+///     .then(Affine2::flip_horizontal())
+///     .then(Affine2::translation(Point::new(100, 0)));
+///
+/// assert_eq!(affine.transform_point(Point::new(10, 5)), Point::new(80, 10));
+/// ```
+///
+/// [`then`]: Affine2::then
+/// [`around`]: Affine2::around
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "defmt", derive(::defmt::Format))]
+pub struct Affine2 {
+    /// Linear part of the transformation in row major order.
+    matrix: [[Real; 2]; 2],
+
+    /// Translation that is applied after the linear part.
+    translation: [Real; 2],
+}
+
+impl Affine2 {
+    /// Creates an affine transformation from the matrix coefficients.
+    ///
+    /// A point `(x, y)` is transformed into `(a * x + b * y + tx, c * x + d * y + ty)`.
+    pub fn new(a: f32, b: f32, c: f32, d: f32, tx: f32, ty: f32) -> Self {
+        Self {
+            matrix: [[a.into(), b.into()], [c.into(), d.into()]],
+            translation: [tx.into(), ty.into()],
+        }
+    }
+
+    /// Returns the identity transformation.
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// Creates a translation.
+    pub fn translation(by: Point) -> Self {
+        Self {
+            translation: [by.x.into(), by.y.into()],
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a scaling transformation.
+    ///
+    /// Negative scaling factors mirror along the corresponding axis.
+    pub fn scale(x: f32, y: f32) -> Self {
+        Self::new(x, 0.0, 0.0, y, 0.0, 0.0)
+    }
+
+    /// Creates a rotation around the origin.
+    pub fn rotation(angle: Angle) -> Self {
+        let cos = angle.cos();
+        let sin = angle.sin();
+
+        Self {
+            matrix: [[cos, -sin], [sin, cos]],
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a skew transformation.
+    ///
+    /// The x coordinate is shifted proportional to the y coordinate by the tangent of `x` and the
+    /// y coordinate is shifted proportional to the x coordinate by the tangent of `y`.
+    ///
+    /// The tangent is limited to ±256, which corresponds to angles of about ±89.8°. Angles closer
+    /// to ±90°, where the tangent is undefined, use the limit instead. If the `fixed_point`
+    /// feature is enabled, the tangent is limited to ±64, or about ±89.1°, to keep the skewed
+    /// coordinates of points on a display inside the supported range.
+    pub fn skew(x: Angle, y: Angle) -> Self {
+        let one = Real::from(1);
+
+        Self {
+            matrix: [[one, skew_factor(x)], [skew_factor(y), one]],
+            ..Self::identity()
+        }
+    }
+
+    /// Creates a transformation that mirrors along the y axis.
+    pub fn flip_horizontal() -> Self {
+        Self::scale(-1.0, 1.0)
+    }
+
+    /// Creates a transformation that mirrors along the x axis.
+    pub fn flip_vertical() -> Self {
+        Self::scale(1.0, -1.0)
+    }
+
+    /// Returns a transformation that first applies `self` and then `other`.
+    pub fn then(self, other: Self) -> Self {
+        let [[a, b], [c, d]] = other.matrix;
+        let [[e, f], [g, h]] = self.matrix;
+        let [x, y] = self.translation;
+
+        Self {
+            matrix: [
+                [dot(a, e, b, g), dot(a, f, b, h)],
+                [dot(c, e, d, g), dot(c, f, d, h)],
+            ],
+            translation: [
+                dot(a, x, b, y).saturating_add(other.translation[0]),
+                dot(c, x, d, y).saturating_add(other.translation[1]),
+            ],
+        }
+    }
+
+    /// Returns a transformation that applies `self` relative to the given point.
+    ///
+    /// This can be used to rotate, scale, skew or mirror around an arbitrary point instead of the
+    /// origin.
+    pub fn around(self, point: Point) -> Self {
+        Self::translation(-point)
+            .then(self)
+            .then(Self::translation(point))
+    }
+
+    /// Transforms a point.
+    ///
+    /// The transformed coordinates are rounded to the nearest integer. If the `fixed_point`
+    /// feature is enabled, coordinates outside the range of about ±32767 are limited to this
+    /// range.
+    pub fn transform_point(&self, point: Point) -> Point {
+        let (x, y) = self.transform_real(Real::from(point.x), Real::from(point.y));
+
+        Point::new(
+            i32::from(x.saturating_round()),
+            i32::from(y.saturating_round()),
+        )
+    }
+
+    /// Transforms a point with non integer coordinates.
+    pub(crate) fn transform_real(&self, x: Real, y: Real) -> (Real, Real) {
+        let [[a, b], [c, d]] = self.matrix;
+
+        (
+            dot(a, x, b, y).saturating_add(self.translation[0]),
+            dot(c, x, d, y).saturating_add(self.translation[1]),
+        )
+    }
+
+    /// Returns the linear part of the transformation.
+    pub(crate) const fn matrix(&self) -> [[Real; 2]; 2] {
+        self.matrix
+    }
+}
+
+/// Returns `a * b + c * d`, saturating instead of overflowing when fixed point math is used.
+fn dot(a: Real, b: Real, c: Real, d: Real) -> Real {
+    a.saturating_mul(b).saturating_add(c.saturating_mul(d))
+}
+
+/// Largest absolute skew factor used by [`Affine2::skew`].
+#[cfg(not(feature = "fixed_point"))]
+const MAX_SKEW_FACTOR: i32 = 256;
+
+/// Largest absolute skew factor used by [`Affine2::skew`].
+///
+/// The limit is lower for fixed point numbers to keep skewed display coordinates inside the
+/// range of `I16F16`.
+#[cfg(feature = "fixed_point")]
+const MAX_SKEW_FACTOR: i32 = 64;
+
+/// Returns the tangent of the angle, limited to [`MAX_SKEW_FACTOR`].
+fn skew_factor(angle: Angle) -> Real {
+    let sin = angle.sin();
+    let cos = angle.cos();
+    let max = Real::from(MAX_SKEW_FACTOR);
+
+    // The division is skipped if the result would exceed the limit, which also prevents a
+    // division by zero for angles of ±90°.
+    if sin.abs() >= cos.abs() * max {
+        if (sin < Real::from(0)) == (cos < Real::from(0)) {
+            max
+        } else {
+            -max
+        }
+    } else {
+        sin / cos
+    }
+}
+
+impl Default for Affine2 {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::AngleUnit;
+
+    #[test]
+    fn identity() {
+        let point = Point::new(-3, 7);
+
+        assert_eq!(Affine2::identity().transform_point(point), point);
+        assert_eq!(Affine2::default(), Affine2::identity());
+    }
+
+    #[test]
+    fn translation() {
+        let affine = Affine2::translation(Point::new(5, -2));
+
+        assert_eq!(affine.transform_point(Point::new(1, 1)), Point::new(6, -1));
+    }
+
+    #[test]
+    fn scale() {
+        let affine = Affine2::scale(2.0, 0.5);
+
+        assert_eq!(affine.transform_point(Point::new(3, 8)), Point::new(6, 4));
+    }
+
+    #[test]
+    fn rotation() {
+        let point = Point::new(10, 0);
+
+        assert_eq!(
+            Affine2::rotation(90.0.deg()).transform_point(point),
+            Point::new(0, 10)
+        );
+        assert_eq!(
+            Affine2::rotation(180.0.deg()).transform_point(point),
+            Point::new(-10, 0)
+        );
+        assert_eq!(
+            Affine2::rotation(-90.0.deg()).transform_point(point),
+            Point::new(0, -10)
+        );
+    }
+
+    #[test]
+    fn skew() {
+        let affine = Affine2::skew(45.0.deg(), 0.0.deg());
+
+        assert_eq!(
+            affine.transform_point(Point::new(0, 10)),
+            Point::new(10, 10)
+        );
+        assert_eq!(affine.transform_point(Point::new(10, 0)), Point::new(10, 0));
+
+        let affine = Affine2::skew(0.0.deg(), 45.0.deg());
+
+        assert_eq!(
+            affine.transform_point(Point::new(10, 0)),
+            Point::new(10, 10)
+        );
+    }
+
+    #[test]
+    fn skew_limit() {
+        for angle in [90.0, -90.0, 270.0, 450.0] {
+            let point = Point::new(1, 10);
+
+            let affine = Affine2::skew(angle.deg(), 0.0.deg());
+            let transformed = affine.transform_point(point);
+            assert_eq!((transformed.x - 1).abs(), MAX_SKEW_FACTOR * 10, "{angle}");
+            assert_eq!(transformed.y, 10, "{angle}");
+
+            let affine = Affine2::skew(0.0.deg(), angle.deg());
+            let transformed = affine.transform_point(point);
+            assert_eq!(transformed.x, 1, "{angle}");
+            assert_eq!((transformed.y - 10).abs(), MAX_SKEW_FACTOR, "{angle}");
+        }
+
+        let affine = Affine2::skew(89.0.deg(), 0.0.deg());
+        assert_eq!(
+            affine.transform_point(Point::new(0, 10)),
+            Point::new(573, 10)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "fixed_point")]
+    fn skew_limit_fixed_point() {
+        let affine = Affine2::skew(90.0.deg(), 0.0.deg());
+        assert_eq!(
+            affine.transform_point(Point::new(0, 200)),
+            Point::new(64 * 200, 200)
+        );
+        assert_eq!(
+            affine.transform_point(Point::new(-10, -479)),
+            Point::new(-10 - 64 * 479, -479)
+        );
+
+        // Skew a 320x240 display around its center.
+        let center = Point::new(160, 120);
+        let affine = Affine2::skew(0.0.deg(), -90.0.deg()).around(center);
+        for point in [Point::zero(), Point::new(319, 0), Point::new(319, 239)] {
+            let delta = point - center;
+            assert_eq!(
+                affine.transform_point(point),
+                Point::new(point.x, center.y + delta.y - 64 * delta.x),
+                "{point:?}"
+            );
+        }
+
+        // Coordinates outside the range of fixed point numbers are saturated.
+        const MAX: i32 = i16::MAX as i32;
+        let affine = Affine2::skew(90.0.deg(), 0.0.deg());
+        assert_eq!(
+            affine.transform_point(Point::new(0, 1000)),
+            Point::new(MAX, 1000)
+        );
+        assert_eq!(
+            affine.transform_point(Point::new(0, -1000)),
+            Point::new(-MAX - 1, -1000)
+        );
+
+        let affine =
+            Affine2::skew(90.0.deg(), 90.0.deg()).then(Affine2::skew(90.0.deg(), 0.0.deg()));
+        assert_eq!(
+            affine.transform_point(Point::new(100, 100)),
+            Point::new(MAX, 65 * 100)
+        );
+    }
+
+    #[test]
+    fn flip() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(
+            Affine2::flip_horizontal().transform_point(point),
+            Point::new(-3, 4)
+        );
+        assert_eq!(
+            Affine2::flip_vertical().transform_point(point),
+            Point::new(3, -4)
+        );
+    }
+
+    #[test]
+    fn then() {
+        let affine = Affine2::translation(Point::new(10, 0)).then(Affine2::rotation(90.0.deg()));
+        assert_eq!(affine.transform_point(Point::zero()), Point::new(0, 10));
+
+        let affine = Affine2::rotation(90.0.deg()).then(Affine2::translation(Point::new(10, 0)));
+        assert_eq!(affine.transform_point(Point::zero()), Point::new(10, 0));
+    }
+
+    #[test]
+    fn around() {
+        let center = Point::new(20, 30);
+
+        let affine = Affine2::rotation(90.0.deg()).around(center);
+        assert_eq!(affine.transform_point(center), center);
+        assert_eq!(
+            affine.transform_point(Point::new(25, 30)),
+            Point::new(20, 35)
+        );
+
+        let affine = Affine2::scale(2.0, 3.0).around(center);
+        assert_eq!(
+            affine.transform_point(Point::new(21, 31)),
+            Point::new(22, 33)
+        );
+
+        let affine = Affine2::flip_horizontal().around(center);
+        assert_eq!(
+            affine.transform_point(Point::new(25, 31)),
+            Point::new(15, 31)
+        );
+    }
+}
@@ -1,145 +1,279 @@
 use anyhow::{anyhow, Result};
 use bdf_parser::BdfFont;
-use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+use embedded_graphics::{
+    pixelcolor::{BinaryColor, Rgb888},
+    prelude::*,
+};
 use png_target::PngTarget;
-use std::{convert::TryFrom, fmt, fs, path::Path};
+use std::{collections::HashSet, convert::TryFrom, fmt, fs, path::Path, str::FromStr};
+
+/// Glyph bitmap and metrics.
+///
+/// This type is used as a common representation for glyphs from different font formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlyphData {
+    /// Offset of the bottom left corner of the bitmap relative to the glyph origin.
+    ///
+    /// The Y axis points upwards, which means that a glyph which extends below the baseline has
+    /// a negative Y offset.
+    pub offset: (i32, i32),
+    /// Bitmap width and height.
+    pub size: (usize, usize),
+    /// Advance width.
+    pub device_width: i32,
+    /// Pixel intensities, row by row starting with the top row.
+    ///
+    /// Each pixel is stored as a value between `0` (background) and `255` (foreground).
+    pub pixels: Vec<u8>,
+}
+
+impl GlyphData {
+    fn is_empty(&self) -> bool {
+        self.size.0 == 0 || self.size.1 == 0
+    }
+}
 
 pub struct MonoFontData {
     data: Vec<u8>,
+    gray: Vec<u8>,
     width: usize,
     height: usize,
     glyph_width: usize,
     glyph_height: usize,
     baseline: usize,
     character_spacing: u32,
-    encoding: Encoding,
+    glyph_widths: Vec<u32>,
+    missing_chars: Vec<char>,
+    chars: Vec<char>,
+    replacement_char: char,
 }
 
 impl MonoFontData {
     pub fn new(font: &BdfFont, encoding: Encoding) -> Result<Self> {
-        let rows: Vec<u32> = match encoding {
-            Encoding::Ascii => (0x20..=0x7F).step_by(16).collect(),
-            Encoding::Latin1 => (0x20..=0x7F).chain(0xA0..=0xFF).step_by(16).collect(),
-        };
-        let chars = rows.iter().flat_map(|start| {
-            std::char::from_u32(*start).unwrap()..std::char::from_u32(*start + 16).unwrap()
-        });
-
-        let fallback_glyph = font
-            .glyphs
-            .get('?')
-            .ok_or_else(|| anyhow!("font doesn't contain the fallback '?' glyph"))?;
-        let mut glyph_bb = font
-            .glyphs
-            .get('A')
-            .ok_or_else(|| anyhow!("font doesn't contain 'A' glyph"))?
-            .bounding_box;
-        // used to optimize the glyph height in case all glyph offsets are e.g. -1
-        let mut max_y_offset = i32::MIN;
-        // We should take the DWIDTH value into account. If all glyph bounding boxes are smaller than
-        // DWIDTH the CHARACTER_SPACING in the MonoFont impl should be set to DWIDTH - (width of the BB)
-        // to maintain the correct kerning.
-        let mut char_spacing = None;
-
-        // find the maximum bounding box that can fit all glyphs taking offsets into account
-        // glyph_bb.offset.y will contain the baseline
-        // additionally build the `selected_glyphs` vector to save on the glyph lookup later in the code
-        let selected_glyphs = chars
-            .into_iter()
-            .enumerate()
-            .map(|(index, c)| {
-                let glyph = font.glyphs.get(c).unwrap_or(&fallback_glyph);
-                let off_x = glyph.bounding_box.offset.x;
-                if off_x < glyph_bb.offset.x {
-                    glyph_bb.offset.x = off_x;
-                }
-                let off_y = glyph.bounding_box.offset.y;
-                if off_y < glyph_bb.offset.y {
-                    glyph_bb.offset.y = off_y;
-                }
-                if off_y > max_y_offset {
-                    max_y_offset = off_y;
-                }
-                let gl_w = glyph.bounding_box.size.x + off_x.abs();
-                if gl_w > glyph_bb.size.x {
-                    glyph_bb.size.x = gl_w;
-                }
-                let gl_h = glyph.bounding_box.size.y + off_y.abs();
-                if gl_h > glyph_bb.size.y {
-                    glyph_bb.size.y = gl_h;
-                }
-                let x_size_diff = glyph.device_width.x - glyph.bounding_box.size.x;
-                if char_spacing.map_or(true, |char_spacing_compensation| {
-                    x_size_diff < char_spacing_compensation
-                }) {
-                    char_spacing = Some(x_size_diff);
-                }
-                (index, glyph)
+        Self::with_chars(font, &encoding.chars(), '?')
+    }
+
+    /// Converts the glyphs for the given characters.
+    ///
+    /// Characters that aren't included in the BDF font are left out of the converted font and are
+    /// reported by [`missing_chars`](Self::missing_chars). At runtime the glyph mapping returns
+    /// the glyph for `replacement_char` for these characters, unless a fallback font contains them.
+    /// The replacement character must be included in the font and is added to the converted glyphs
+    /// if it isn't part of `chars`. The glyphs are stored in the same order as `chars`, which
+    /// allows sparse subsets, like a few hundred CJK characters, to be converted without including
+    /// all glyphs in between.
+    pub fn with_chars(font: &BdfFont, chars: &[char], replacement_char: char) -> Result<Self> {
+        if font.glyphs.get(replacement_char).is_none() {
+            return Err(anyhow!(
+                "font doesn't contain the replacement glyph {:?}",
+                replacement_char
+            ));
+        }
+
+        let (mut present_chars, missing_chars): (Vec<_>, Vec<_>) = chars
+            .iter()
+            .copied()
+            .partition(|c| font.glyphs.get(*c).is_some());
+
+        if !present_chars.contains(&replacement_char) {
+            present_chars.push(replacement_char);
+        }
+
+        let glyphs = present_chars
+            .iter()
+            .map(|c| {
+                let glyph = font.glyphs.get(*c).unwrap();
+                let bb = glyph.bounding_box;
+
+                let width = usize::try_from(bb.size.x)?;
+                let height = usize::try_from(bb.size.y)?;
+                let pixels = (0..height)
+                    .flat_map(|y| (0..width).map(move |x| (x, y)))
+                    .map(|(x, y)| if glyph.pixel(x, y) { 255 } else { 0 })
+                    .collect();
+
+                Ok(GlyphData {
+                    offset: (bb.offset.x, bb.offset.y),
+                    size: (width, height),
+                    device_width: glyph.device_width.x,
+                    pixels,
+                })
             })
-            .collect::<Vec<_>>();
-        let glyph_width = usize::try_from(glyph_bb.size.x)?;
-        let mut glyph_height = usize::try_from(glyph_bb.size.y)?;
-        // compensate the glyph height if case all offsets are either > 0 or < 0
-        let y_offset_compensation;
-        if max_y_offset.signum() == glyph_bb.offset.y.signum() {
-            y_offset_compensation = max_y_offset.abs().min(glyph_bb.offset.y.abs());
-            glyph_height = usize::try_from(i32::try_from(glyph_height)? - y_offset_compensation)?;
-        } else {
-            y_offset_compensation = 0;
-        }
-        let bitmap_width = ((glyph_width * 16 + 7) / 8) * 8;
-        let bitmap_height = glyph_height * rows.len();
-        let mut bitmap = vec![false; bitmap_width * bitmap_height];
-
-        for (index, glyph) in selected_glyphs {
-            let mut bitmap_x = (index % 16) * glyph_width;
-            let mut bitmap_y = (index / 16) * glyph_height;
-
-            let dx = glyph.bounding_box.offset.x - glyph_bb.offset.x;
-            // calculate the baseline and make it an origin for vertical positioning
-            let dy = glyph_bb.size.y + glyph_bb.offset.y
-                - glyph.bounding_box.offset.y
-                - glyph.bounding_box.size.y
-                - y_offset_compensation;
-
-            bitmap_x = usize::try_from(i32::try_from(bitmap_x)? + dx)?;
-            bitmap_y = usize::try_from(i32::try_from(bitmap_y)? + dy)?;
-
-            for y in 0..usize::try_from(glyph.bounding_box.size.y)? {
-                for x in 0..usize::try_from(glyph.bounding_box.size.x)? {
-                    if glyph.pixel(x, y) {
-                        bitmap[bitmap_x + x + (bitmap_y + y) * bitmap_width] = true;
-                    }
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_glyphs(&present_chars, &glyphs, replacement_char, missing_chars, 128)
+    }
+
+    /// Creates a font from glyph bitmaps.
+    ///
+    /// `glyphs` contains the glyph for each character in `chars`. Characters that aren't included
+    /// in the source font must not be part of `chars` and are instead passed in `missing_chars`,
+    /// which is only used to report them. `chars` must include `replacement_char`. Pixels with an
+    /// intensity of at least `threshold` are set in the binary glyph image.
+    pub fn from_glyphs(
+        chars: &[char],
+        glyphs: &[GlyphData],
+        replacement_char: char,
+        missing_chars: Vec<char>,
+        threshold: u8,
+    ) -> Result<Self> {
+        if chars.is_empty() {
+            return Err(anyhow!("no characters selected"));
+        }
+        if chars.len() != glyphs.len() {
+            return Err(anyhow!(
+                "number of glyphs doesn't match number of characters"
+            ));
+        }
+        if !chars.contains(&replacement_char) {
+            return Err(anyhow!(
+                "replacement character {:?} isn't included in the characters",
+                replacement_char
+            ));
+        }
+
+        let rows_count = chars.len().div_ceil(16);
+
+        // Find the bounding box that fits all glyphs, taking the glyph offsets into account. The
+        // glyph bounding boxes can differ from the font bounding box and from each other. The
+        // coordinates are relative to the glyph origin on the baseline, with the Y axis pointing
+        // upwards.
+        let mut min_x = 0;
+        let mut max_x = 0;
+        let mut min_y = i32::MAX;
+        let mut max_y = i32::MIN;
+
+        // Empty glyphs, like the space character, don't affect the bounding box.
+        for glyph in glyphs.iter().filter(|glyph| !glyph.is_empty()) {
+            let (width, height) = (i32::try_from(glyph.size.0)?, i32::try_from(glyph.size.1)?);
+
+            min_x = min_x.min(glyph.offset.0);
+            max_x = max_x.max(glyph.offset.0 + width);
+            min_y = min_y.min(glyph.offset.1);
+            max_y = max_y.max(glyph.offset.1 + height);
+        }
+
+        if min_y > max_y {
+            return Err(anyhow!("all selected glyphs are empty"));
+        }
+
+        let glyph_width = usize::try_from(max_x - min_x)?;
+        let glyph_height = usize::try_from(max_y - min_y)?;
+
+        // If all glyphs are narrower than DWIDTH the difference is used as the character spacing to
+        // maintain the correct advance width.
+        let min_device_width = glyphs
+            .iter()
+            .map(|glyph| glyph.device_width)
+            .min()
+            .unwrap_or(0);
+        let character_spacing = (min_device_width - (max_x - min_x)).max(0);
+
+        let glyph_widths = glyphs
+            .iter()
+            .map(|glyph| u32::try_from(glyph.device_width.max(0)))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let bitmap_width = (glyph_width * 16).div_ceil(8) * 8;
+        let bitmap_height = glyph_height * rows_count;
+        let mut gray = vec![0u8; bitmap_width * bitmap_height];
+
+        for (index, glyph) in glyphs.iter().enumerate() {
+            // Position of the top left corner of the glyph bitmap inside the character cell.
+            let dx = usize::try_from(glyph.offset.0 - min_x)?;
+            let dy = usize::try_from(max_y - (glyph.offset.1 + i32::try_from(glyph.size.1)?))?;
+
+            let bitmap_x = (index % 16) * glyph_width + dx;
+            let bitmap_y = (index / 16) * glyph_height + dy;
+
+            for y in 0..glyph.size.1 {
+                for x in 0..glyph.size.0 {
+                    gray[bitmap_x + x + (bitmap_y + y) * bitmap_width] =
+                        glyph.pixels[x + y * glyph.size.0];
                 }
             }
         }
 
-        let data = bitmap
+        let data = gray
             .chunks_exact(8)
             .map(|byte| {
                 byte.iter()
                     .enumerate()
-                    .filter(|(_, bit)| **bit)
+                    .filter(|(_, value)| **value >= threshold)
                     .map(|(i, _)| 0x80 >> i)
                     .sum()
             })
             .collect::<Vec<_>>();
 
-        assert_eq!(glyph_bb.offset.x, 0);
-        assert!(glyph_bb.offset.y <= 0);
-
         Ok(Self {
             data,
+            gray,
             width: bitmap_width,
             height: bitmap_height,
             glyph_width,
             glyph_height,
-            baseline: glyph_height - -glyph_bb.offset.y as usize - 1,
-            character_spacing: u32::try_from(char_spacing.unwrap_or(0).max(0))?,
-            encoding,
+            baseline: usize::try_from(max_y - 1)?,
+            character_spacing: u32::try_from(character_spacing)?,
+            glyph_widths,
+            missing_chars,
+            chars: chars.to_vec(),
+            replacement_char,
         })
     }
 
+    /// Returns the `StrGlyphMapping` string for the converted characters.
+    pub fn mapping(&self) -> String {
+        glyph_mapping_string(&self.chars)
+    }
+
+    /// Returns the glyph index of the replacement character.
+    pub fn replacement_index(&self) -> usize {
+        self.chars
+            .iter()
+            .position(|c| *c == self.replacement_char)
+            .unwrap()
+    }
+
+    /// Returns the characters that aren't included in the source font.
+    ///
+    /// These characters aren't part of the converted font and its glyph mapping. They are drawn
+    /// using a fallback font or, if no fallback font contains them, the replacement glyph.
+    pub fn missing_chars(&self) -> &[char] {
+        &self.missing_chars
+    }
+
+    /// Returns the advance widths (DWIDTH) of the converted glyphs.
+    ///
+    /// The widths are returned in the same order as the glyphs in the glyph mapping.
+    pub fn glyph_widths(&self) -> &[u32] {
+        &self.glyph_widths
+    }
+
+    /// Returns Rust source code for the proportional font metadata.
+    ///
+    /// The metadata contains the advance width of each glyph, which can be used to draw the
+    /// converted font with variable character widths.
+    pub fn proportional_rust(&self, name: &str) -> String {
+        let widths = self
+            .glyph_widths
+            .iter()
+            .map(|width| width.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            r#"
+            /// Advance widths of the glyphs in `{name}`.
+            ///
+            /// The widths are stored in the same order as the glyphs in the glyph mapping.
+            pub const {name}_GLYPH_WIDTHS: [u32; {count}] = [{widths}];
+            "#,
+            name = name,
+            count = self.glyph_widths.len(),
+            widths = widths,
+        )
+    }
+
     pub fn pixel(&self, x: usize, y: usize) -> bool {
         self.data[x / 8 + y * (self.width / 8)] & (0x80 >> x % 8) != 0
     }
@@ -173,34 +307,96 @@ impl MonoFontData {
         Ok(())
     }
 
+    /// Saves the grayscale glyph image as a PNG file.
+    pub fn save_gray8_png<P: AsRef<Path>>(&self, png_file: P) -> Result<()> {
+        let mut png: PngTarget<Rgb888> =
+            PngTarget::new(Size::new(self.width as u32, self.height as u32), 1);
+
+        png.bounding_box()
+            .points()
+            .map(|p| {
+                let value = self.gray[p.x as usize + p.y as usize * self.width];
+                Pixel(p, Rgb888::new(value, value, value))
+            })
+            .draw(&mut png)
+            .unwrap();
+
+        png.save(png_file)?;
+
+        Ok(())
+    }
+
+    /// Saves the glyph image with 8 bits per pixel.
+    pub fn save_gray8_raw<P: AsRef<Path>>(&self, raw_file: P) -> Result<()> {
+        fs::write(raw_file, &self.gray)?;
+
+        Ok(())
+    }
+
+    /// Returns Rust source code for a grayscale glyph image.
+    ///
+    /// `MonoFont` only supports binary glyph images. The grayscale image is instead written as a
+    /// `Gray8` `ImageRaw` constant, together with the glyph mapping and the character size.
+    pub fn gray8_rust(&self, name: &str, raw_file: &str) -> String {
+        format!(
+            r#"
+            /// Glyph image of the {char_width}x{char_height} pixel `{name}` font.
+            pub const {name}_IMAGE: ImageRaw<Gray8> = ImageRaw::new_const(
+                include_bytes!("{raw_file}"),
+                Size::new({image_width}, {image_height}),
+            );
+
+            /// Glyph mapping of the `{name}` font.
+            pub const {name}_MAPPING: StrGlyphMapping = StrGlyphMapping::new("{mapping}", {replacement_index});
+
+            /// Character size of the `{name}` font.
+            pub const {name}_CHARACTER_SIZE: Size = Size::new({char_width}, {char_height});
+
+            /// Baseline of the `{name}` font.
+            pub const {name}_BASELINE: u32 = {baseline};
+            "#,
+            name = name,
+            raw_file = raw_file,
+            image_width = self.width,
+            image_height = self.height,
+            char_width = self.glyph_width,
+            char_height = self.glyph_height,
+            baseline = self.baseline,
+            mapping = self.mapping(),
+            replacement_index = self.replacement_index(),
+        )
+    }
+
     pub fn rust(&self, name: &str, raw_file: &str) -> String {
         format!(
             r#"
             /// {char_width}x{char_height} pixel monospace font.
             ///
             /// <img src="{png_data}" alt="{name} font">
-            pub const {name}: MonoFont = MonoFontBuilder::new()
-                .image(ImageRaw::new(include_bytes!("{raw_file}"), {image_width}))
-                .glyph_indices(super::{glyph_indices})
-                .character_size(Size::new({char_width}, {char_height}))
-                .character_spacing({character_spacing})
-                .baseline({baseline})
-                .underline({baseline} + 2, 1)
-                .strikethrough({char_height} / 2, 1)
-                .build();
+            pub const {name}: MonoFont = MonoFont {{
+                image: ImageRaw::new_const(
+                    include_bytes!("{raw_file}"),
+                    Size::new({image_width}, {image_height}),
+                ),
+                glyph_mapping: &StrGlyphMapping::new("{mapping}", {replacement_index}),
+                character_size: Size::new({char_width}, {char_height}),
+                character_spacing: {character_spacing},
+                baseline: {baseline},
+                underline: DecorationDimensions::new({baseline} + 2, 1),
+                strikethrough: DecorationDimensions::new({char_height} / 2, 1),
+            }};
             "#,
             name = name,
             raw_file = raw_file,
             image_width = self.width,
+            image_height = self.height,
             char_width = self.glyph_width,
             char_height = self.glyph_height,
             baseline = self.baseline,
             character_spacing = self.character_spacing,
             png_data = self.png_data(),
-            glyph_indices = format!(
-                "{}_GLYPH_INDICES",
-                self.encoding.to_string().to_ascii_uppercase()
-            ),
+            mapping = self.mapping(),
+            replacement_index = self.replacement_index(),
         )
     }
 
@@ -209,12 +405,160 @@ impl MonoFontData {
     }
 }
 
+/// Encodes a list of characters as `StrGlyphMapping` data.
+///
+/// Runs of at least three consecutive characters are encoded as ranges.
+fn glyph_mapping(chars: &[char]) -> String {
+    let mut output = String::new();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let start = chars[i];
+
+        let mut end = i;
+        while end + 1 < chars.len() && chars[end + 1] as u32 == chars[end] as u32 + 1 {
+            end += 1;
+        }
+
+        if end - i >= 2 {
+            output.push('\0');
+            output.push(start);
+            output.push(chars[end]);
+            i = end + 1;
+        } else {
+            output.push(start);
+            i += 1;
+        }
+    }
+
+    output
+}
+
+/// Encodes a list of characters as a `StrGlyphMapping` string.
+///
+/// Runs of at least three consecutive characters are encoded as ranges. The returned string uses
+/// Rust escape sequences and can be inserted into a string literal.
+pub fn glyph_mapping_string(chars: &[char]) -> String {
+    glyph_mapping(chars)
+        .chars()
+        .map(|c| match c {
+            '\0' => "\\0".to_string(),
+            c => format!("\\u{{{:x}}}", c as u32),
+        })
+        .collect()
+}
+
+/// Parses a list of characters and character ranges.
+///
+/// The list contains comma separated Unicode code points in hexadecimal notation, with an optional
+/// `U+` prefix. Inclusive ranges are specified by separating the start and end code point by a
+/// `-`, e.g. `U+AC00-U+D7A3,U+3131-U+318E`. Duplicate characters are removed while preserving the
+/// order of the first occurrence.
+pub fn parse_char_ranges(ranges: &str) -> Result<Vec<char>> {
+    fn parse_code_point(value: &str) -> Result<u32> {
+        let value = value.trim();
+        let hex = value
+            .strip_prefix("U+")
+            .or_else(|| value.strip_prefix("u+"))
+            .unwrap_or(value);
+
+        u32::from_str_radix(hex, 16).map_err(|_| anyhow!("invalid code point: {:?}", value))
+    }
+
+    let mut chars = Vec::new();
+    let mut seen = HashSet::new();
+
+    for item in ranges.split(',').filter(|item| !item.trim().is_empty()) {
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+            None => {
+                let c = parse_code_point(item)?;
+                (c, c)
+            }
+        };
+
+        if start > end {
+            return Err(anyhow!("invalid range: {:?}", item.trim()));
+        }
+
+        for c in (start..=end).filter_map(std::char::from_u32) {
+            if seen.insert(c) {
+                chars.push(c);
+            }
+        }
+    }
+
+    Ok(chars)
+}
+
+/// Returns the characters that should be converted.
+///
+/// The characters are taken from the `ranges` list (see [`parse_char_ranges`]) and from the
+/// `chars_file`. If neither of them contains any characters all characters in `encoding` are
+/// used. The replacement character is appended if it isn't already included.
+pub fn select_chars(
+    ranges: Option<&str>,
+    chars_file: Option<&Path>,
+    encoding: Encoding,
+    replacement_char: char,
+) -> Result<Vec<char>> {
+    let mut chars = Vec::new();
+
+    if let Some(ranges) = ranges {
+        chars.extend(parse_char_ranges(ranges)?);
+    }
+
+    if let Some(chars_file) = chars_file {
+        let mut seen: HashSet<char> = chars.iter().copied().collect();
+
+        let text = fs::read_to_string(chars_file)?;
+        for c in text.chars().filter(|c| *c != '\n' && *c != '\r') {
+            if seen.insert(c) {
+                chars.push(c);
+            }
+        }
+    }
+
+    if chars.is_empty() {
+        chars = encoding.chars();
+    }
+
+    // The replacement glyph needs to be included in the output.
+    if !chars.contains(&replacement_char) {
+        chars.push(replacement_char);
+    }
+
+    Ok(chars)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Encoding {
     Ascii,
     Latin1,
 }
 
+impl Encoding {
+    /// Returns the characters included in this encoding.
+    pub fn chars(self) -> Vec<char> {
+        match self {
+            Self::Ascii => ('\u{20}'..='\u{7F}').collect(),
+            Self::Latin1 => ('\u{20}'..='\u{7F}').chain('\u{A0}'..='\u{FF}').collect(),
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "ascii" => Ok(Self::Ascii),
+            "latin1" => Ok(Self::Latin1),
+            _ => Err(anyhow!("unknown encoding: {:?}", s)),
+        }
+    }
+}
+
 impl fmt::Display for Encoding {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
         match self {
@@ -223,3 +567,87 @@ impl fmt::Display for Encoding {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::mapping::{GlyphMapping, StrGlyphMapping};
+
+    const BDF: &str = "\
+STARTFONT 2.1
+FONT -test-font
+SIZE 6 75 75
+FONTBOUNDINGBOX 4 6 0 -1
+STARTPROPERTIES 2
+FONT_ASCENT 5
+FONT_DESCENT 1
+ENDPROPERTIES
+CHARS 3
+STARTCHAR question
+ENCODING 63
+SWIDTH 500 0
+DWIDTH 5 0
+BBX 4 6 0 -1
+BITMAP
+60
+90
+20
+40
+00
+40
+ENDCHAR
+STARTCHAR A
+ENCODING 65
+SWIDTH 500 0
+DWIDTH 5 0
+BBX 4 6 0 -1
+BITMAP
+60
+90
+F0
+90
+90
+00
+ENDCHAR
+STARTCHAR B
+ENCODING 66
+SWIDTH 500 0
+DWIDTH 5 0
+BBX 4 6 0 -1
+BITMAP
+E0
+90
+E0
+90
+E0
+00
+ENDCHAR
+ENDFONT
+";
+
+    #[test]
+    fn missing_chars_are_not_mapped() {
+        let font = BdfFont::parse(BDF.as_bytes()).unwrap();
+        let converted = MonoFontData::with_chars(&font, &['A', 'C', 'B'], '?').unwrap();
+
+        assert_eq!(converted.missing_chars(), &['C']);
+        assert_eq!(converted.chars, ['A', 'B', '?']);
+        assert_eq!(converted.glyph_widths().len(), 3);
+
+        let data = glyph_mapping(&converted.chars);
+        let mapping = StrGlyphMapping::new(&data, converted.replacement_index());
+
+        assert!(GlyphMapping::contains(&mapping, 'A'));
+        assert!(GlyphMapping::contains(&mapping, 'B'));
+        assert!(!GlyphMapping::contains(&mapping, 'C'));
+        assert_eq!(mapping.index('C'), converted.replacement_index());
+    }
+
+    #[test]
+    fn mapping_string_escapes_ranges() {
+        assert_eq!(
+            glyph_mapping_string(&['a', 'b', 'c', 'x']),
+            "\\0\\u{61}\\u{63}\\u{78}"
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use std::path::PathBuf;
 
+use anyhow::{anyhow, Result};
 use bdf_parser::BdfFont;
-use bdf_to_mono::{Encoding, MonoFontData};
+use bdf_to_mono::{select_chars, MonoFontData};
 use clap::Parser;
 
 #[derive(clap::Parser)]
@@ -14,29 +15,76 @@ struct Args {
     png: Option<PathBuf>,
     #[clap(long, help = "write RAW file")]
     raw: Option<PathBuf>,
-    #[clap(long, help = "encoding", possible_values = &["ascii", "latin1"], default_value = "ascii")]
+    #[clap(
+        long,
+        help = "encoding, ignored if --chars or --chars-file is used",
+        possible_values = &["ascii", "latin1"],
+        default_value = "ascii"
+    )]
     encoding: String,
+    #[clap(
+        long,
+        help = "comma separated list of code points and ranges, e.g. U+20-U+7E,U+B0"
+    )]
+    chars: Option<String>,
+    #[clap(long, help = "text file that contains all characters to convert")]
+    chars_file: Option<PathBuf>,
+    #[clap(
+        long,
+        help = "character used as a replacement for missing glyphs",
+        default_value = "?"
+    )]
+    replacement: char,
+    #[clap(long, help = "write proportional font metadata to a Rust file")]
+    proportional: Option<PathBuf>,
 }
 
-fn main() {
+impl Args {
+    /// Returns the characters that should be converted.
+    fn chars(&self) -> Result<Vec<char>> {
+        select_chars(
+            self.chars.as_deref(),
+            self.chars_file.as_deref(),
+            self.encoding.parse()?,
+            self.replacement,
+        )
+    }
+}
+
+fn main() -> Result<()> {
     let args: Args = Args::parse();
-    let encoding = match args.encoding.as_str() {
-        "ascii" => Encoding::Ascii,
-        "latin1" => Encoding::Latin1,
-        _ => unreachable!(),
-    };
 
-    let bdf = std::fs::read(args.bdf_file).expect("couldn't open BDF file");
-    let font = BdfFont::parse(&bdf).expect("couldn't parse BDF file");
+    let bdf = std::fs::read(&args.bdf_file)?;
+    let font = BdfFont::parse(&bdf).map_err(|_| anyhow!("couldn't parse BDF file"))?;
 
-    let bitmap = MonoFontData::new(&font, encoding).unwrap();
+    let chars = args.chars()?;
+    let bitmap = MonoFontData::with_chars(&font, &chars, args.replacement)?;
+
+    let missing_chars = bitmap.missing_chars();
+    if !missing_chars.is_empty() {
+        eprintln!(
+            "warning: {} of {} glyphs are missing and weren't converted:",
+            missing_chars.len(),
+            chars.len()
+        );
+        for c in missing_chars {
+            eprintln!("  U+{:04X} {:?}", *c as u32, c);
+        }
+    }
 
     if let Some(png_file) = &args.png {
-        bitmap.save_png(png_file).unwrap();
+        bitmap.save_png(png_file)?;
+    }
+
+    if let Some(proportional_file) = &args.proportional {
+        std::fs::write(
+            proportional_file,
+            bitmap.proportional_rust(&args.mono_font_const),
+        )?;
     }
 
     if let Some(raw_file) = &args.raw {
-        bitmap.save_raw(raw_file).unwrap();
+        bitmap.save_raw(raw_file)?;
         println!(
             "{}",
             bitmap.rust(
@@ -47,4 +95,6 @@ fn main() {
     } else {
         println!("{}", bitmap.rust(&args.mono_font_const, "RAW_FILE"));
     }
+
+    Ok(())
 }
@@ -1,6 +1,6 @@
 use anyhow::{anyhow, Result};
 use bdf_parser::BdfFont;
-use bdf_to_mono::{Encoding, MonoFontData};
+use bdf_to_mono::{parse_char_ranges, Encoding, MonoFontData};
 use std::{
     ffi::{OsStr, OsString},
     fs,
@@ -8,6 +8,11 @@ use std::{
 };
 
 fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("subset") {
+        return convert_subset(&args[1..]);
+    }
+
     let fonts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fonts");
 
     let mut rust_ascii = RUST_HEADER.to_string();
@@ -66,6 +71,80 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Converts a sparse subset of a BDF font.
+///
+/// Usage: `convert-fonts subset <BDF_FILE> <CONSTANT> <CHARS> <OUTPUT_DIR>`
+///
+/// `CHARS` is a comma separated list of code points and code point ranges, e.g.
+/// `U+20-U+7E,U+AC00-U+D7A3` to convert ASCII and all Hangul syllables. The converted glyphs are
+/// stored in the order they are listed and the generated `StrGlyphMapping` uses range encoding for
+/// consecutive characters, which keeps the mapping small even for large CJK subsets.
+///
+/// The output directory will contain a `.raw` and `.png` file with the glyph image and a `.rs`
+/// file with the `MonoFont` constant.
+fn convert_subset(args: &[String]) -> Result<()> {
+    let (bdf_file, constant, chars, output_dir) = match args {
+        [bdf_file, constant, chars, output_dir] => (bdf_file, constant, chars, output_dir),
+        _ => {
+            return Err(anyhow!(
+                "usage: convert-fonts subset <BDF_FILE> <CONSTANT> <CHARS> <OUTPUT_DIR>"
+            ))
+        }
+    };
+
+    let bdf_data = fs::read(bdf_file)?;
+    let bdf = BdfFont::parse(&bdf_data).map_err(|_| anyhow!("couldn't parse BDF file"))?;
+
+    let mut chars = parse_char_ranges(chars)?;
+
+    // Use the Unicode replacement character for missing glyphs, if it is included in the font.
+    let replacement_char = ['\u{FFFD}', '?']
+        .iter()
+        .copied()
+        .find(|c| bdf.glyphs.get(*c).is_some())
+        .ok_or_else(|| anyhow!("font doesn't contain a replacement glyph"))?;
+    if !chars.contains(&replacement_char) {
+        chars.push(replacement_char);
+    }
+
+    let missing = chars
+        .iter()
+        .filter(|c| bdf.glyphs.get(**c).is_none())
+        .count();
+    if missing > 0 {
+        println!(
+            "{} of {} characters are missing in the BDF font",
+            missing,
+            chars.len()
+        );
+    }
+
+    let data = MonoFontData::with_chars(&bdf, &chars, replacement_char)?;
+
+    let output_dir = Path::new(output_dir);
+    fs::create_dir_all(output_dir)?;
+
+    let file_stem = constant.to_ascii_lowercase();
+    let raw_file = output_dir.join(&file_stem).with_extension("raw");
+    data.save_raw(&raw_file)?;
+    data.save_png(output_dir.join(&file_stem).with_extension("png"))?;
+
+    let rust = format!(
+        "{}{}",
+        SUBSET_RUST_HEADER,
+        data.rust(constant, &format!("{}.raw", file_stem))
+    );
+    fs::write(output_dir.join(&file_stem).with_extension("rs"), rust)?;
+
+    println!(
+        "Converted {} characters to {}",
+        chars.len(),
+        raw_file.to_string_lossy()
+    );
+
+    Ok(())
+}
+
 fn update_font_table(file: &str, encoding: Encoding, fonts: &[Font]) -> Result<()> {
     let start_tag = match encoding {
         Encoding::Ascii => "//START-FONT-TABLE-ASCII",
@@ -149,7 +228,22 @@ const RUST_HEADER: &str = r#"
     // GENERATED CODE DO NOT MODIFY!
     // Any manual changes to this file will be overwritten!
 
-    use crate::{mono_font::{MonoFont, MonoFontBuilder}, geometry::Size, image::ImageRaw};
+    use crate::{
+        geometry::Size,
+        image::ImageRaw,
+        mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont},
+    };
+"#;
+
+const SUBSET_RUST_HEADER: &str = r#"
+    // GENERATED CODE DO NOT MODIFY!
+    // Any manual changes to this file will be overwritten!
+
+    use embedded_graphics::{
+        geometry::Size,
+        image::ImageRaw,
+        mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont},
+    };
 "#;
 
 struct Font {
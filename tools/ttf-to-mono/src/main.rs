@@ -0,0 +1,166 @@
+//! Converts TrueType and OpenType fonts into `MonoFont`s.
+//!
+//! The glyphs are rasterized at the given pixel size and are stored in the same glyph image format
+//! that is used by `bdf-to-mono`. By default the antialiased glyphs are converted to a binary image
+//! by applying a threshold. Alternatively, the `--grayscale` option can be used to output an 8 bit
+//! grayscale glyph image.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use bdf_to_mono::{select_chars, GlyphData, MonoFontData};
+use clap::Parser;
+use fontdue::{Font, FontSettings};
+
+#[derive(clap::Parser)]
+struct Args {
+    #[clap(help = "TTF or OTF input")]
+    font_file: PathBuf,
+    #[clap(help = "Name of MonoFont constant")]
+    mono_font_const: String,
+    #[clap(long, help = "font size in pixels")]
+    size: f32,
+    #[clap(long, help = "write PNG file")]
+    png: Option<PathBuf>,
+    #[clap(long, help = "write RAW file")]
+    raw: Option<PathBuf>,
+    #[clap(
+        long,
+        help = "encoding, ignored if --chars or --chars-file is used",
+        possible_values = &["ascii", "latin1"],
+        default_value = "ascii"
+    )]
+    encoding: String,
+    #[clap(
+        long,
+        help = "comma separated list of code points and ranges, e.g. U+20-U+7E,U+B0"
+    )]
+    chars: Option<String>,
+    #[clap(long, help = "text file that contains all characters to convert")]
+    chars_file: Option<PathBuf>,
+    #[clap(
+        long,
+        help = "character used as a replacement for missing glyphs",
+        default_value = "?"
+    )]
+    replacement: char,
+    #[clap(
+        long,
+        help = "minimum coverage (0-255) for a pixel to be set in the binary image",
+        default_value = "128"
+    )]
+    threshold: u8,
+    #[clap(
+        long,
+        help = "output an 8 bit grayscale glyph image instead of a MonoFont"
+    )]
+    grayscale: bool,
+}
+
+impl Args {
+    /// Returns the characters that should be converted.
+    fn chars(&self) -> Result<Vec<char>> {
+        select_chars(
+            self.chars.as_deref(),
+            self.chars_file.as_deref(),
+            self.encoding.parse()?,
+            self.replacement,
+        )
+    }
+}
+
+/// Returns `true` if the font contains a glyph for the given character.
+fn has_glyph(font: &Font, c: char) -> bool {
+    font.lookup_glyph_index(c) != 0
+}
+
+/// Rasterizes a single glyph.
+fn rasterize(font: &Font, c: char, size: f32) -> GlyphData {
+    let (metrics, pixels) = font.rasterize(c, size);
+
+    GlyphData {
+        offset: (metrics.xmin, metrics.ymin),
+        size: (metrics.width, metrics.height),
+        device_width: metrics.advance_width.round() as i32,
+        pixels,
+    }
+}
+
+fn main() -> Result<()> {
+    let args: Args = Args::parse();
+
+    if args.size.is_nan() || args.size <= 0.0 {
+        return Err(anyhow!("font size must be positive"));
+    }
+
+    let data = std::fs::read(&args.font_file)?;
+    let font = Font::from_bytes(data, FontSettings::default())
+        .map_err(|e| anyhow!("couldn't parse font file: {}", e))?;
+
+    if !has_glyph(&font, args.replacement) {
+        return Err(anyhow!(
+            "font doesn't contain the replacement glyph {:?}",
+            args.replacement
+        ));
+    }
+
+    // Missing characters are left out of the converted font, which allows them to be drawn using
+    // a fallback font.
+    let (chars, missing_chars): (Vec<_>, Vec<_>) = args
+        .chars()?
+        .into_iter()
+        .partition(|c| has_glyph(&font, *c));
+
+    let glyphs = chars
+        .iter()
+        .map(|c| rasterize(&font, *c, args.size))
+        .collect::<Vec<_>>();
+
+    let bitmap = MonoFontData::from_glyphs(
+        &chars,
+        &glyphs,
+        args.replacement,
+        missing_chars,
+        args.threshold,
+    )?;
+
+    let missing_chars = bitmap.missing_chars();
+    if !missing_chars.is_empty() {
+        eprintln!(
+            "warning: {} of {} glyphs are missing and weren't converted:",
+            missing_chars.len(),
+            chars.len() + missing_chars.len()
+        );
+        for c in missing_chars {
+            eprintln!("  U+{:04X} {:?}", *c as u32, c);
+        }
+    }
+
+    if let Some(png_file) = &args.png {
+        if args.grayscale {
+            bitmap.save_gray8_png(png_file)?;
+        } else {
+            bitmap.save_png(png_file)?;
+        }
+    }
+
+    let raw_file = match &args.raw {
+        Some(raw_file) => {
+            if args.grayscale {
+                bitmap.save_gray8_raw(raw_file)?;
+            } else {
+                bitmap.save_raw(raw_file)?;
+            }
+            raw_file.as_os_str().to_string_lossy().into_owned()
+        }
+        None => "RAW_FILE".to_string(),
+    };
+
+    if args.grayscale {
+        println!("{}", bitmap.gray8_rust(&args.mono_font_const, &raw_file));
+    } else {
+        println!("{}", bitmap.rust(&args.mono_font_const, &raw_file));
+    }
+
+    Ok(())
+}